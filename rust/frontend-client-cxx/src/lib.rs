@@ -42,6 +42,7 @@ pub mod ffi {
         ListCapture,
         PatchCapture,
         GetCapture,
+        GetDistances,
     }
 
     extern "Rust" {
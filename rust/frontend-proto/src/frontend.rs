@@ -323,46 +323,1806 @@ impl ::protobuf::reflect::ProtobufValue for SetLinkLossRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ListLinkLossResponse)
+pub struct ListLinkLossResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ListLinkLossResponse.links)
+    pub links: ::std::vec::Vec<SetLinkLossRequest>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ListLinkLossResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ListLinkLossResponse {
+    fn default() -> &'a ListLinkLossResponse {
+        <ListLinkLossResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListLinkLossResponse {
+    pub fn new() -> ListLinkLossResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "links",
+            |m: &ListLinkLossResponse| { &m.links },
+            |m: &mut ListLinkLossResponse| { &mut m.links },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListLinkLossResponse>(
+            "ListLinkLossResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ListLinkLossResponse {
+    const NAME: &'static str = "ListLinkLossResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.links.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.links {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.links {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ListLinkLossResponse {
+        ListLinkLossResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.links.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ListLinkLossResponse {
+        static instance: ListLinkLossResponse = ListLinkLossResponse {
+            links: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ListLinkLossResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListLinkLossResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ListLinkLossResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListLinkLossResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.PatchZoneRequest)
+pub struct PatchZoneRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.PatchZoneRequest.zone)
+    pub zone: ::protobuf::MessageField<super::model::Zone>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.PatchZoneRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PatchZoneRequest {
+    fn default() -> &'a PatchZoneRequest {
+        <PatchZoneRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PatchZoneRequest {
+    pub fn new() -> PatchZoneRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::model::Zone>(
+            "zone",
+            |m: &PatchZoneRequest| { &m.zone },
+            |m: &mut PatchZoneRequest| { &mut m.zone },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PatchZoneRequest>(
+            "PatchZoneRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PatchZoneRequest {
+    const NAME: &'static str = "PatchZoneRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.zone)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.zone.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.zone.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PatchZoneRequest {
+        PatchZoneRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.zone.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PatchZoneRequest {
+        static instance: PatchZoneRequest = PatchZoneRequest {
+            zone: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PatchZoneRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PatchZoneRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PatchZoneRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PatchZoneRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.ListZonesResponse)
+pub struct ListZonesResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.ListZonesResponse.zones)
+    pub zones: ::std::vec::Vec<super::model::Zone>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.ListZonesResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ListZonesResponse {
+    fn default() -> &'a ListZonesResponse {
+        <ListZonesResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListZonesResponse {
+    pub fn new() -> ListZonesResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "zones",
+            |m: &ListZonesResponse| { &m.zones },
+            |m: &mut ListZonesResponse| { &mut m.zones },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListZonesResponse>(
+            "ListZonesResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ListZonesResponse {
+    const NAME: &'static str = "ListZonesResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.zones.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.zones {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.zones {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ListZonesResponse {
+        ListZonesResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.zones.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ListZonesResponse {
+        static instance: ListZonesResponse = ListZonesResponse {
+            zones: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ListZonesResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListZonesResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ListZonesResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListZonesResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.DeleteZoneRequest)
+pub struct DeleteZoneRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.DeleteZoneRequest.name)
+    pub name: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.DeleteZoneRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteZoneRequest {
+    fn default() -> &'a DeleteZoneRequest {
+        <DeleteZoneRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteZoneRequest {
+    pub fn new() -> DeleteZoneRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &DeleteZoneRequest| { &m.name },
+            |m: &mut DeleteZoneRequest| { &mut m.name },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeleteZoneRequest>(
+            "DeleteZoneRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DeleteZoneRequest {
+    const NAME: &'static str = "DeleteZoneRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DeleteZoneRequest {
+        DeleteZoneRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DeleteZoneRequest {
+        static instance: DeleteZoneRequest = DeleteZoneRequest {
+            name: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DeleteZoneRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DeleteZoneRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DeleteZoneRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteZoneRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.AssignZoneRequest)
+pub struct AssignZoneRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.AssignZoneRequest.device_name)
+    pub device_name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.AssignZoneRequest.zone)
+    pub zone: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.AssignZoneRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AssignZoneRequest {
+    fn default() -> &'a AssignZoneRequest {
+        <AssignZoneRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AssignZoneRequest {
+    pub fn new() -> AssignZoneRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_name",
+            |m: &AssignZoneRequest| { &m.device_name },
+            |m: &mut AssignZoneRequest| { &mut m.device_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "zone",
+            |m: &AssignZoneRequest| { &m.zone },
+            |m: &mut AssignZoneRequest| { &mut m.zone },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AssignZoneRequest>(
+            "AssignZoneRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AssignZoneRequest {
+    const NAME: &'static str = "AssignZoneRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.device_name = is.read_string()?;
+                },
+                18 => {
+                    self.zone = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.device_name);
+        }
+        if !self.zone.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.zone);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.device_name.is_empty() {
+            os.write_string(1, &self.device_name)?;
+        }
+        if !self.zone.is_empty() {
+            os.write_string(2, &self.zone)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AssignZoneRequest {
+        AssignZoneRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.device_name.clear();
+        self.zone.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AssignZoneRequest {
+        static instance: AssignZoneRequest = AssignZoneRequest {
+            device_name: ::std::string::String::new(),
+            zone: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AssignZoneRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AssignZoneRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AssignZoneRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AssignZoneRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.DeviceDistance)
+pub struct DeviceDistance {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.DeviceDistance.device_name)
+    pub device_name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.DeviceDistance.other_device)
+    pub other_device: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.DeviceDistance.distance)
+    pub distance: f32,
+    // @@protoc_insertion_point(field:netsim.frontend.DeviceDistance.rssi)
+    pub rssi: ::std::vec::Vec<device_distance::Rssi>,
+    // @@protoc_insertion_point(field:netsim.frontend.DeviceDistance.unreachable)
+    pub unreachable: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.DeviceDistance.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DeviceDistance {
+    fn default() -> &'a DeviceDistance {
+        <DeviceDistance as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeviceDistance {
+    pub fn new() -> DeviceDistance {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_name",
+            |m: &DeviceDistance| { &m.device_name },
+            |m: &mut DeviceDistance| { &mut m.device_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "other_device",
+            |m: &DeviceDistance| { &m.other_device },
+            |m: &mut DeviceDistance| { &mut m.other_device },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "distance",
+            |m: &DeviceDistance| { &m.distance },
+            |m: &mut DeviceDistance| { &mut m.distance },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "rssi",
+            |m: &DeviceDistance| { &m.rssi },
+            |m: &mut DeviceDistance| { &mut m.rssi },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "unreachable",
+            |m: &DeviceDistance| { &m.unreachable },
+            |m: &mut DeviceDistance| { &mut m.unreachable },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeviceDistance>(
+            "DeviceDistance",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DeviceDistance {
+    const NAME: &'static str = "DeviceDistance";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.device_name = is.read_string()?;
+                },
+                18 => {
+                    self.other_device = is.read_string()?;
+                },
+                29 => {
+                    self.distance = is.read_float()?;
+                },
+                34 => {
+                    self.rssi.push(is.read_message()?);
+                },
+                42 => {
+                    self.unreachable = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.device_name);
+        }
+        if !self.other_device.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.other_device);
+        }
+        if self.distance != 0. {
+            my_size += 1 + 4;
+        }
+        for value in &self.rssi {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if !self.unreachable.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.unreachable);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.device_name.is_empty() {
+            os.write_string(1, &self.device_name)?;
+        }
+        if !self.other_device.is_empty() {
+            os.write_string(2, &self.other_device)?;
+        }
+        if self.distance != 0. {
+            os.write_float(3, self.distance)?;
+        }
+        for v in &self.rssi {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        if !self.unreachable.is_empty() {
+            os.write_string(5, &self.unreachable)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DeviceDistance {
+        DeviceDistance::new()
+    }
+
+    fn clear(&mut self) {
+        self.device_name.clear();
+        self.other_device.clear();
+        self.distance = 0.;
+        self.rssi.clear();
+        self.unreachable.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DeviceDistance {
+        static instance: DeviceDistance = DeviceDistance {
+            device_name: ::std::string::String::new(),
+            other_device: ::std::string::String::new(),
+            distance: 0.,
+            rssi: ::std::vec::Vec::new(),
+            unreachable: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DeviceDistance {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DeviceDistance").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DeviceDistance {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeviceDistance {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `DeviceDistance`
+pub mod device_distance {
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.frontend.DeviceDistance.Rssi)
+    pub struct Rssi {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.frontend.DeviceDistance.Rssi.radio)
+        pub radio: ::protobuf::EnumOrUnknown<super::super::model::PhyKind>,
+        // @@protoc_insertion_point(field:netsim.frontend.DeviceDistance.Rssi.tx_power)
+        pub tx_power: i32,
+        // @@protoc_insertion_point(field:netsim.frontend.DeviceDistance.Rssi.rssi)
+        pub rssi: i32,
+        // @@protoc_insertion_point(field:netsim.frontend.DeviceDistance.Rssi.link_loss)
+        pub link_loss: f32,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.frontend.DeviceDistance.Rssi.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a Rssi {
+        fn default() -> &'a Rssi {
+            <Rssi as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl Rssi {
+        pub fn new() -> Rssi {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(4);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "radio",
+                |m: &Rssi| { &m.radio },
+                |m: &mut Rssi| { &mut m.radio },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "tx_power",
+                |m: &Rssi| { &m.tx_power },
+                |m: &mut Rssi| { &mut m.tx_power },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "rssi",
+                |m: &Rssi| { &m.rssi },
+                |m: &mut Rssi| { &mut m.rssi },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "link_loss",
+                |m: &Rssi| { &m.link_loss },
+                |m: &mut Rssi| { &mut m.link_loss },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Rssi>(
+                "DeviceDistance.Rssi",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for Rssi {
+        const NAME: &'static str = "Rssi";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    8 => {
+                        self.radio = is.read_enum_or_unknown()?;
+                    },
+                    16 => {
+                        self.tx_power = is.read_int32()?;
+                    },
+                    24 => {
+                        self.rssi = is.read_int32()?;
+                    },
+                    37 => {
+                        self.link_loss = is.read_float()?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if self.radio != ::protobuf::EnumOrUnknown::new(super::super::model::PhyKind::NONE) {
+                my_size += ::protobuf::rt::int32_size(1, self.radio.value());
+            }
+            if self.tx_power != 0 {
+                my_size += ::protobuf::rt::int32_size(2, self.tx_power);
+            }
+            if self.rssi != 0 {
+                my_size += ::protobuf::rt::int32_size(3, self.rssi);
+            }
+            if self.link_loss != 0. {
+                my_size += 1 + 4;
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if self.radio != ::protobuf::EnumOrUnknown::new(super::super::model::PhyKind::NONE) {
+                os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.radio))?;
+            }
+            if self.tx_power != 0 {
+                os.write_int32(2, self.tx_power)?;
+            }
+            if self.rssi != 0 {
+                os.write_int32(3, self.rssi)?;
+            }
+            if self.link_loss != 0. {
+                os.write_float(4, self.link_loss)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Rssi {
+            Rssi::new()
+        }
+
+        fn clear(&mut self) {
+            self.radio = ::protobuf::EnumOrUnknown::new(super::super::model::PhyKind::NONE);
+            self.tx_power = 0;
+            self.rssi = 0;
+            self.link_loss = 0.;
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static Rssi {
+            static instance: Rssi = Rssi {
+                radio: ::protobuf::EnumOrUnknown::from_i32(0),
+                tx_power: 0,
+                rssi: 0,
+                link_loss: 0.,
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for Rssi {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("DeviceDistance.Rssi").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for Rssi {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for Rssi {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.GetDistancesResponse)
+pub struct GetDistancesResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.GetDistancesResponse.distances)
+    pub distances: ::std::vec::Vec<DeviceDistance>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.GetDistancesResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetDistancesResponse {
+    fn default() -> &'a GetDistancesResponse {
+        <GetDistancesResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetDistancesResponse {
+    pub fn new() -> GetDistancesResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "distances",
+            |m: &GetDistancesResponse| { &m.distances },
+            |m: &mut GetDistancesResponse| { &mut m.distances },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetDistancesResponse>(
+            "GetDistancesResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetDistancesResponse {
+    const NAME: &'static str = "GetDistancesResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.distances.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.distances {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.distances {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetDistancesResponse {
+        GetDistancesResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.distances.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetDistancesResponse {
+        static instance: GetDistancesResponse = GetDistancesResponse {
+            distances: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetDistancesResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetDistancesResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetDistancesResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetDistancesResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:netsim.frontend.PatchDeviceRequest)
 pub struct PatchDeviceRequest {
     // message fields
-    // @@protoc_insertion_point(field:netsim.frontend.PatchDeviceRequest.device)
-    pub device: ::protobuf::MessageField<super::model::Device>,
+    // @@protoc_insertion_point(field:netsim.frontend.PatchDeviceRequest.device)
+    pub device: ::protobuf::MessageField<super::model::Device>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.PatchDeviceRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PatchDeviceRequest {
+    fn default() -> &'a PatchDeviceRequest {
+        <PatchDeviceRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PatchDeviceRequest {
+    pub fn new() -> PatchDeviceRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::model::Device>(
+            "device",
+            |m: &PatchDeviceRequest| { &m.device },
+            |m: &mut PatchDeviceRequest| { &mut m.device },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PatchDeviceRequest>(
+            "PatchDeviceRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PatchDeviceRequest {
+    const NAME: &'static str = "PatchDeviceRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.device)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.device.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.device.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PatchDeviceRequest {
+        PatchDeviceRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.device.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PatchDeviceRequest {
+        static instance: PatchDeviceRequest = PatchDeviceRequest {
+            device: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PatchDeviceRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PatchDeviceRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PatchDeviceRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PatchDeviceRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.CreateDeviceRequest)
+pub struct CreateDeviceRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.CreateDeviceRequest.device)
+    pub device: ::protobuf::MessageField<super::model::Device>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.CreateDeviceRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CreateDeviceRequest {
+    fn default() -> &'a CreateDeviceRequest {
+        <CreateDeviceRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateDeviceRequest {
+    pub fn new() -> CreateDeviceRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::model::Device>(
+            "device",
+            |m: &CreateDeviceRequest| { &m.device },
+            |m: &mut CreateDeviceRequest| { &mut m.device },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CreateDeviceRequest>(
+            "CreateDeviceRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CreateDeviceRequest {
+    const NAME: &'static str = "CreateDeviceRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.device)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.device.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.device.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CreateDeviceRequest {
+        CreateDeviceRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.device.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CreateDeviceRequest {
+        static instance: CreateDeviceRequest = CreateDeviceRequest {
+            device: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CreateDeviceRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CreateDeviceRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CreateDeviceRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateDeviceRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.CreateDeviceResponse)
+pub struct CreateDeviceResponse {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.CreateDeviceResponse.device)
+    pub device: ::protobuf::MessageField<super::model::Device>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.CreateDeviceResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CreateDeviceResponse {
+    fn default() -> &'a CreateDeviceResponse {
+        <CreateDeviceResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateDeviceResponse {
+    pub fn new() -> CreateDeviceResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::model::Device>(
+            "device",
+            |m: &CreateDeviceResponse| { &m.device },
+            |m: &mut CreateDeviceResponse| { &mut m.device },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CreateDeviceResponse>(
+            "CreateDeviceResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CreateDeviceResponse {
+    const NAME: &'static str = "CreateDeviceResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.device)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.device.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.device.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CreateDeviceResponse {
+        CreateDeviceResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.device.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CreateDeviceResponse {
+        static instance: CreateDeviceResponse = CreateDeviceResponse {
+            device: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CreateDeviceResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CreateDeviceResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CreateDeviceResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateDeviceResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.DeleteDeviceRequest)
+pub struct DeleteDeviceRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.DeleteDeviceRequest.id)
+    pub id: i32,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.DeleteDeviceRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteDeviceRequest {
+    fn default() -> &'a DeleteDeviceRequest {
+        <DeleteDeviceRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteDeviceRequest {
+    pub fn new() -> DeleteDeviceRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &DeleteDeviceRequest| { &m.id },
+            |m: &mut DeleteDeviceRequest| { &mut m.id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeleteDeviceRequest>(
+            "DeleteDeviceRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DeleteDeviceRequest {
+    const NAME: &'static str = "DeleteDeviceRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_int32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::int32_size(1, self.id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_int32(1, self.id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DeleteDeviceRequest {
+        DeleteDeviceRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DeleteDeviceRequest {
+        static instance: DeleteDeviceRequest = DeleteDeviceRequest {
+            id: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DeleteDeviceRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DeleteDeviceRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DeleteDeviceRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteDeviceRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.SetTrajectoryRequest)
+pub struct SetTrajectoryRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.SetTrajectoryRequest.device_name)
+    pub device_name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.SetTrajectoryRequest.trajectory)
+    pub trajectory: ::protobuf::MessageField<super::model::Trajectory>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.SetTrajectoryRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SetTrajectoryRequest {
+    fn default() -> &'a SetTrajectoryRequest {
+        <SetTrajectoryRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetTrajectoryRequest {
+    pub fn new() -> SetTrajectoryRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_name",
+            |m: &SetTrajectoryRequest| { &m.device_name },
+            |m: &mut SetTrajectoryRequest| { &mut m.device_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::model::Trajectory>(
+            "trajectory",
+            |m: &SetTrajectoryRequest| { &m.trajectory },
+            |m: &mut SetTrajectoryRequest| { &mut m.trajectory },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SetTrajectoryRequest>(
+            "SetTrajectoryRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SetTrajectoryRequest {
+    const NAME: &'static str = "SetTrajectoryRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.device_name = is.read_string()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.trajectory)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.device_name);
+        }
+        if let Some(v) = self.trajectory.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.device_name.is_empty() {
+            os.write_string(1, &self.device_name)?;
+        }
+        if let Some(v) = self.trajectory.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SetTrajectoryRequest {
+        SetTrajectoryRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.device_name.clear();
+        self.trajectory.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SetTrajectoryRequest {
+        static instance: SetTrajectoryRequest = SetTrajectoryRequest {
+            device_name: ::std::string::String::new(),
+            trajectory: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SetTrajectoryRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SetTrajectoryRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SetTrajectoryRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetTrajectoryRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.SceneFileRequest)
+pub struct SceneFileRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.SceneFileRequest.file_name)
+    pub file_name: ::std::string::String,
     // special fields
-    // @@protoc_insertion_point(special_field:netsim.frontend.PatchDeviceRequest.special_fields)
+    // @@protoc_insertion_point(special_field:netsim.frontend.SceneFileRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a PatchDeviceRequest {
-    fn default() -> &'a PatchDeviceRequest {
-        <PatchDeviceRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a SceneFileRequest {
+    fn default() -> &'a SceneFileRequest {
+        <SceneFileRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl PatchDeviceRequest {
-    pub fn new() -> PatchDeviceRequest {
+impl SceneFileRequest {
+    pub fn new() -> SceneFileRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::model::Device>(
-            "device",
-            |m: &PatchDeviceRequest| { &m.device },
-            |m: &mut PatchDeviceRequest| { &mut m.device },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "file_name",
+            |m: &SceneFileRequest| { &m.file_name },
+            |m: &mut SceneFileRequest| { &mut m.file_name },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PatchDeviceRequest>(
-            "PatchDeviceRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SceneFileRequest>(
+            "SceneFileRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for PatchDeviceRequest {
-    const NAME: &'static str = "PatchDeviceRequest";
+impl ::protobuf::Message for SceneFileRequest {
+    const NAME: &'static str = "SceneFileRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -371,8 +2131,8 @@ impl ::protobuf::Message for PatchDeviceRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                18 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.device)?;
+                10 => {
+                    self.file_name = is.read_string()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -386,9 +2146,8 @@ impl ::protobuf::Message for PatchDeviceRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if let Some(v) = self.device.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if !self.file_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.file_name);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -396,8 +2155,8 @@ impl ::protobuf::Message for PatchDeviceRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let Some(v) = self.device.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        if !self.file_name.is_empty() {
+            os.write_string(1, &self.file_name)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -411,38 +2170,38 @@ impl ::protobuf::Message for PatchDeviceRequest {
         &mut self.special_fields
     }
 
-    fn new() -> PatchDeviceRequest {
-        PatchDeviceRequest::new()
+    fn new() -> SceneFileRequest {
+        SceneFileRequest::new()
     }
 
     fn clear(&mut self) {
-        self.device.clear();
+        self.file_name.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static PatchDeviceRequest {
-        static instance: PatchDeviceRequest = PatchDeviceRequest {
-            device: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static SceneFileRequest {
+        static instance: SceneFileRequest = SceneFileRequest {
+            file_name: ::std::string::String::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for PatchDeviceRequest {
+impl ::protobuf::MessageFull for SceneFileRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("PatchDeviceRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SceneFileRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for PatchDeviceRequest {
+impl ::std::fmt::Display for SceneFileRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for PatchDeviceRequest {
+impl ::protobuf::reflect::ProtobufValue for SceneFileRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
@@ -569,6 +2328,164 @@ impl ::protobuf::reflect::ProtobufValue for Event {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.frontend.GetDevicesRequest)
+pub struct GetDevicesRequest {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.frontend.GetDevicesRequest.label_selector)
+    pub label_selector: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.GetDevicesRequest.name_pattern)
+    pub name_pattern: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.GetDevicesRequest.chip_kind)
+    pub chip_kind: ::protobuf::EnumOrUnknown<super::common::ChipKind>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.frontend.GetDevicesRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetDevicesRequest {
+    fn default() -> &'a GetDevicesRequest {
+        <GetDevicesRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetDevicesRequest {
+    pub fn new() -> GetDevicesRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "label_selector",
+            |m: &GetDevicesRequest| { &m.label_selector },
+            |m: &mut GetDevicesRequest| { &mut m.label_selector },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name_pattern",
+            |m: &GetDevicesRequest| { &m.name_pattern },
+            |m: &mut GetDevicesRequest| { &mut m.name_pattern },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_kind",
+            |m: &GetDevicesRequest| { &m.chip_kind },
+            |m: &mut GetDevicesRequest| { &mut m.chip_kind },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetDevicesRequest>(
+            "GetDevicesRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetDevicesRequest {
+    const NAME: &'static str = "GetDevicesRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.label_selector = is.read_string()?;
+                },
+                18 => {
+                    self.name_pattern = is.read_string()?;
+                },
+                24 => {
+                    self.chip_kind = is.read_enum_or_unknown()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.label_selector.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.label_selector);
+        }
+        if !self.name_pattern.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name_pattern);
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            my_size += ::protobuf::rt::int32_size(3, self.chip_kind.value());
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.label_selector.is_empty() {
+            os.write_string(1, &self.label_selector)?;
+        }
+        if !self.name_pattern.is_empty() {
+            os.write_string(2, &self.name_pattern)?;
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.chip_kind))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetDevicesRequest {
+        GetDevicesRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.label_selector.clear();
+        self.name_pattern.clear();
+        self.chip_kind = ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED);
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetDevicesRequest {
+        static instance: GetDevicesRequest = GetDevicesRequest {
+            label_selector: ::std::string::String::new(),
+            name_pattern: ::std::string::String::new(),
+            chip_kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetDevicesRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetDevicesRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetDevicesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetDevicesRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:netsim.frontend.GetDevicesResponse)
 pub struct GetDevicesResponse {
@@ -1103,6 +3020,12 @@ pub mod patch_capture_request {
         // message fields
         // @@protoc_insertion_point(field:netsim.frontend.PatchCaptureRequest.PatchCapture.state)
         pub state: ::protobuf::EnumOrUnknown<super::super::model::State>,
+        // @@protoc_insertion_point(field:netsim.frontend.PatchCaptureRequest.PatchCapture.format)
+        pub format: ::protobuf::EnumOrUnknown<super::super::model::capture::Format>,
+        // @@protoc_insertion_point(field:netsim.frontend.PatchCaptureRequest.PatchCapture.limits)
+        pub limits: ::protobuf::MessageField<super::super::model::capture::Limits>,
+        // @@protoc_insertion_point(field:netsim.frontend.PatchCaptureRequest.PatchCapture.filter)
+        pub filter: ::protobuf::MessageField<super::super::model::capture::Filter>,
         // special fields
         // @@protoc_insertion_point(special_field:netsim.frontend.PatchCaptureRequest.PatchCapture.special_fields)
         pub special_fields: ::protobuf::SpecialFields,
@@ -1120,13 +3043,28 @@ pub mod patch_capture_request {
         }
 
         pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-            let mut fields = ::std::vec::Vec::with_capacity(1);
+            let mut fields = ::std::vec::Vec::with_capacity(4);
             let mut oneofs = ::std::vec::Vec::with_capacity(0);
             fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
                 "state",
                 |m: &PatchCapture| { &m.state },
                 |m: &mut PatchCapture| { &mut m.state },
             ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "format",
+                |m: &PatchCapture| { &m.format },
+                |m: &mut PatchCapture| { &mut m.format },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::super::model::capture::Limits>(
+                "limits",
+                |m: &PatchCapture| { &m.limits },
+                |m: &mut PatchCapture| { &mut m.limits },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::super::model::capture::Filter>(
+                "filter",
+                |m: &PatchCapture| { &m.filter },
+                |m: &mut PatchCapture| { &mut m.filter },
+            ));
             ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PatchCapture>(
                 "PatchCaptureRequest.PatchCapture",
                 fields,
@@ -1148,6 +3086,15 @@ pub mod patch_capture_request {
                     8 => {
                         self.state = is.read_enum_or_unknown()?;
                     },
+                    16 => {
+                        self.format = is.read_enum_or_unknown()?;
+                    },
+                    26 => {
+                        ::protobuf::rt::read_singular_message_into_field(is, &mut self.limits)?;
+                    },
+                    34 => {
+                        ::protobuf::rt::read_singular_message_into_field(is, &mut self.filter)?;
+                    },
                     tag => {
                         ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                     },
@@ -1163,6 +3110,17 @@ pub mod patch_capture_request {
             if self.state != ::protobuf::EnumOrUnknown::new(super::super::model::State::UNKNOWN) {
                 my_size += ::protobuf::rt::int32_size(1, self.state.value());
             }
+            if self.format != ::protobuf::EnumOrUnknown::new(super::super::model::capture::Format::PCAP) {
+                my_size += ::protobuf::rt::int32_size(2, self.format.value());
+            }
+            if let Some(v) = self.limits.as_ref() {
+                let len = v.compute_size();
+                my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            }
+            if let Some(v) = self.filter.as_ref() {
+                let len = v.compute_size();
+                my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            }
             my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
             self.special_fields.cached_size().set(my_size as u32);
             my_size
@@ -1172,6 +3130,15 @@ pub mod patch_capture_request {
             if self.state != ::protobuf::EnumOrUnknown::new(super::super::model::State::UNKNOWN) {
                 os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.state))?;
             }
+            if self.format != ::protobuf::EnumOrUnknown::new(super::super::model::capture::Format::PCAP) {
+                os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.format))?;
+            }
+            if let Some(v) = self.limits.as_ref() {
+                ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+            }
+            if let Some(v) = self.filter.as_ref() {
+                ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+            }
             os.write_unknown_fields(self.special_fields.unknown_fields())?;
             ::std::result::Result::Ok(())
         }
@@ -1190,12 +3157,18 @@ pub mod patch_capture_request {
 
         fn clear(&mut self) {
             self.state = ::protobuf::EnumOrUnknown::new(super::super::model::State::UNKNOWN);
+            self.format = ::protobuf::EnumOrUnknown::new(super::super::model::capture::Format::PCAP);
+            self.limits.clear();
+            self.filter.clear();
             self.special_fields.clear();
         }
 
         fn default_instance() -> &'static PatchCapture {
             static instance: PatchCapture = PatchCapture {
                 state: ::protobuf::EnumOrUnknown::from_i32(0),
+                format: ::protobuf::EnumOrUnknown::from_i32(0),
+                limits: ::protobuf::MessageField::none(),
+                filter: ::protobuf::MessageField::none(),
                 special_fields: ::protobuf::SpecialFields::new(),
             };
             &instance
@@ -1349,6 +3322,10 @@ pub struct GetCaptureRequest {
     // message fields
     // @@protoc_insertion_point(field:netsim.frontend.GetCaptureRequest.id)
     pub id: i32,
+    // @@protoc_insertion_point(field:netsim.frontend.GetCaptureRequest.format)
+    pub format: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.frontend.GetCaptureRequest.follow)
+    pub follow: bool,
     // special fields
     // @@protoc_insertion_point(special_field:netsim.frontend.GetCaptureRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -1366,13 +3343,23 @@ impl GetCaptureRequest {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "id",
             |m: &GetCaptureRequest| { &m.id },
             |m: &mut GetCaptureRequest| { &mut m.id },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "format",
+            |m: &GetCaptureRequest| { &m.format },
+            |m: &mut GetCaptureRequest| { &mut m.format },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "follow",
+            |m: &GetCaptureRequest| { &m.follow },
+            |m: &mut GetCaptureRequest| { &mut m.follow },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetCaptureRequest>(
             "GetCaptureRequest",
             fields,
@@ -1394,6 +3381,12 @@ impl ::protobuf::Message for GetCaptureRequest {
                 8 => {
                     self.id = is.read_int32()?;
                 },
+                18 => {
+                    self.format = is.read_string()?;
+                },
+                24 => {
+                    self.follow = is.read_bool()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -1409,6 +3402,12 @@ impl ::protobuf::Message for GetCaptureRequest {
         if self.id != 0 {
             my_size += ::protobuf::rt::int32_size(1, self.id);
         }
+        if !self.format.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.format);
+        }
+        if self.follow != false {
+            my_size += 1 + 1;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -1418,6 +3417,12 @@ impl ::protobuf::Message for GetCaptureRequest {
         if self.id != 0 {
             os.write_int32(1, self.id)?;
         }
+        if !self.format.is_empty() {
+            os.write_string(2, &self.format)?;
+        }
+        if self.follow != false {
+            os.write_bool(3, self.follow)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1436,12 +3441,16 @@ impl ::protobuf::Message for GetCaptureRequest {
 
     fn clear(&mut self) {
         self.id = 0;
+        self.format.clear();
+        self.follow = false;
         self.special_fields.clear();
     }
 
     fn default_instance() -> &'static GetCaptureRequest {
         static instance: GetCaptureRequest = GetCaptureRequest {
             id: 0,
+            format: ::std::string::String::new(),
+            follow: false,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -1746,43 +3755,91 @@ impl ::protobuf::reflect::ProtobufValue for ErrorResponse {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0efrontend.proto\x12\x0fnetsim.frontend\x1a\x1bgoogle/protobuf/empty\
-    .proto\x1a\x0bmodel.proto\"+\n\x0fVersionResponse\x12\x18\n\x07version\
-    \x18\x01\x20\x01(\tR\x07version\"\xa2\x01\n\x12SetLinkLossRequest\x12\
-    \x1f\n\x0bdevice_name\x18\x01\x20\x01(\tR\ndeviceName\x12!\n\x0cother_de\
-    vice\x18\x02\x20\x01(\tR\x0botherDevice\x12+\n\x05radio\x18\x03\x20\x01(\
-    \x0e2\x15.netsim.model.PhyKindR\x05radio\x12\x1b\n\tlink_loss\x18\x04\
-    \x20\x01(\x02R\x08linkLoss\"B\n\x12PatchDeviceRequest\x12,\n\x06device\
-    \x18\x02\x20\x01(\x0b2\x14.netsim.model.DeviceR\x06device\"7\n\x05Event\
-    \x12.\n\x07devices\x18\x01\x20\x03(\x0b2\x14.netsim.model.DeviceR\x07dev\
-    ices\"D\n\x12GetDevicesResponse\x12.\n\x07devices\x18\x01\x20\x03(\x0b2\
-    \x14.netsim.model.DeviceR\x07devices\"&\n\x0cNetCatStream\x12\x16\n\x06r\
-    esult\x18\x01\x20\x03(\tR\x06result\"X\n\x17SetPacketCaptureRequest\x12\
-    \x18\n\x07capture\x18\x01\x20\x01(\x08R\x07capture\x12#\n\rdevice_serial\
-    \x18\x02\x20\x01(\tR\x0cdeviceSerial\"\xa9\x01\n\x13PatchCaptureRequest\
-    \x12\x0e\n\x02id\x18\x01\x20\x01(\x05R\x02id\x12G\n\x05patch\x18\x02\x20\
-    \x01(\x0b21.netsim.frontend.PatchCaptureRequest.PatchCaptureR\x05patch\
-    \x1a9\n\x0cPatchCapture\x12)\n\x05state\x18\x01\x20\x01(\x0e2\x13.netsim\
-    .model.StateR\x05state\"H\n\x13ListCaptureResponse\x121\n\x08captures\
-    \x18\x01\x20\x03(\x0b2\x15.netsim.model.CaptureR\x08captures\"#\n\x11Get\
-    CaptureRequest\x12\x0e\n\x02id\x18\x01\x20\x01(\x05R\x02id\";\n\x12GetCa\
-    ptureResponse\x12%\n\x0ecapture_stream\x18\x01\x20\x01(\x0cR\rcaptureStr\
-    eam\"`\n\rErrorResponse\x12\x12\n\x04code\x18\x01\x20\x01(\x05R\x04code\
-    \x12#\n\rerror_message\x18\x02\x20\x01(\tR\x0cerrorMessage\x12\x16\n\x06\
-    status\x18\x03\x20\x01(\tR\x06status2\xf0\x05\n\x0fFrontendService\x12F\
-    \n\nGetVersion\x12\x16.google.protobuf.Empty\x1a\x20.netsim.frontend.Ver\
-    sionResponse\x12B\n\x0eRegisterEvents\x12\x16.google.protobuf.Empty\x1a\
-    \x16.netsim.frontend.Event0\x01\x12I\n\nGetDevices\x12\x16.google.protob\
-    uf.Empty\x1a#.netsim.frontend.GetDevicesResponse\x12J\n\x0bPatchDevice\
-    \x12#.netsim.frontend.PatchDeviceRequest\x1a\x16.google.protobuf.Empty\
+    \n\x0efrontend.proto\x12\x0fnetsim.frontend\x1a\x0ccommon.proto\x1a\x1bg\
+    oogle/protobuf/empty.proto\x1a\x0bmodel.proto\"+\n\x0fVersionResponse\
+    \x12\x18\n\x07version\x18\x01\x20\x01(\tR\x07version\"\xa2\x01\n\x12SetL\
+    inkLossRequest\x12\x1f\n\x0bdevice_name\x18\x01\x20\x01(\tR\ndeviceName\
+    \x12!\n\x0cother_device\x18\x02\x20\x01(\tR\x0botherDevice\x12+\n\x05rad\
+    io\x18\x03\x20\x01(\x0e2\x15.netsim.model.PhyKindR\x05radio\x12\x1b\n\tl\
+    ink_loss\x18\x04\x20\x01(\x02R\x08linkLoss\"Q\n\x14ListLinkLossResponse\
+    \x129\n\x05links\x18\x01\x20\x03(\x0b2#.netsim.frontend.SetLinkLossReque\
+    stR\x05links\":\n\x10PatchZoneRequest\x12&\n\x04zone\x18\x01\x20\x01(\
+    \x0b2\x12.netsim.model.ZoneR\x04zone\"=\n\x11ListZonesResponse\x12(\n\
+    \x05zones\x18\x01\x20\x03(\x0b2\x12.netsim.model.ZoneR\x05zones\"'\n\x11\
+    DeleteZoneRequest\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\"H\n\x11\
+    AssignZoneRequest\x12\x1f\n\x0bdevice_name\x18\x01\x20\x01(\tR\ndeviceNa\
+    me\x12\x12\n\x04zone\x18\x02\x20\x01(\tR\x04zone\"\xcd\x02\n\x0eDeviceDi\
+    stance\x12\x1f\n\x0bdevice_name\x18\x01\x20\x01(\tR\ndeviceName\x12!\n\
+    \x0cother_device\x18\x02\x20\x01(\tR\x0botherDevice\x12\x1a\n\x08distanc\
+    e\x18\x03\x20\x01(\x02R\x08distance\x128\n\x04rssi\x18\x04\x20\x03(\x0b2\
+    $.netsim.frontend.DeviceDistance.RssiR\x04rssi\x12\x20\n\x0bunreachable\
+    \x18\x05\x20\x01(\tR\x0bunreachable\x1a\x7f\n\x04Rssi\x12+\n\x05radio\
+    \x18\x01\x20\x01(\x0e2\x15.netsim.model.PhyKindR\x05radio\x12\x19\n\x08t\
+    x_power\x18\x02\x20\x01(\x05R\x07txPower\x12\x12\n\x04rssi\x18\x03\x20\
+    \x01(\x05R\x04rssi\x12\x1b\n\tlink_loss\x18\x04\x20\x01(\x02R\x08linkLos\
+    s\"U\n\x14GetDistancesResponse\x12=\n\tdistances\x18\x01\x20\x03(\x0b2\
+    \x1f.netsim.frontend.DeviceDistanceR\tdistances\"B\n\x12PatchDeviceReque\
+    st\x12,\n\x06device\x18\x02\x20\x01(\x0b2\x14.netsim.model.DeviceR\x06de\
+    vice\"C\n\x13CreateDeviceRequest\x12,\n\x06device\x18\x01\x20\x01(\x0b2\
+    \x14.netsim.model.DeviceR\x06device\"D\n\x14CreateDeviceResponse\x12,\n\
+    \x06device\x18\x01\x20\x01(\x0b2\x14.netsim.model.DeviceR\x06device\"%\n\
+    \x13DeleteDeviceRequest\x12\x0e\n\x02id\x18\x01\x20\x01(\x05R\x02id\"q\n\
+    \x14SetTrajectoryRequest\x12\x1f\n\x0bdevice_name\x18\x01\x20\x01(\tR\nd\
+    eviceName\x128\n\ntrajectory\x18\x02\x20\x01(\x0b2\x18.netsim.model.Traj\
+    ectoryR\ntrajectory\"/\n\x10SceneFileRequest\x12\x1b\n\tfile_name\x18\
+    \x01\x20\x01(\tR\x08fileName\"7\n\x05Event\x12.\n\x07devices\x18\x01\x20\
+    \x03(\x0b2\x14.netsim.model.DeviceR\x07devices\"\x93\x01\n\x11GetDevices\
+    Request\x12%\n\x0elabel_selector\x18\x01\x20\x01(\tR\rlabelSelector\x12!\
+    \n\x0cname_pattern\x18\x02\x20\x01(\tR\x0bnamePattern\x124\n\tchip_kind\
+    \x18\x03\x20\x01(\x0e2\x17.netsim.common.ChipKindR\x08chipKind\"D\n\x12G\
+    etDevicesResponse\x12.\n\x07devices\x18\x01\x20\x03(\x0b2\x14.netsim.mod\
+    el.DeviceR\x07devices\"&\n\x0cNetCatStream\x12\x16\n\x06result\x18\x01\
+    \x20\x03(\tR\x06result\"X\n\x17SetPacketCaptureRequest\x12\x18\n\x07capt\
+    ure\x18\x01\x20\x01(\x08R\x07capture\x12#\n\rdevice_serial\x18\x02\x20\
+    \x01(\tR\x0cdeviceSerial\"\xcc\x02\n\x13PatchCaptureRequest\x12\x0e\n\
+    \x02id\x18\x01\x20\x01(\x05R\x02id\x12G\n\x05patch\x18\x02\x20\x01(\x0b2\
+    1.netsim.frontend.PatchCaptureRequest.PatchCaptureR\x05patch\x1a\xdb\x01\
+    \n\x0cPatchCapture\x12)\n\x05state\x18\x01\x20\x01(\x0e2\x13.netsim.mode\
+    l.StateR\x05state\x124\n\x06format\x18\x02\x20\x01(\x0e2\x1c.netsim.mode\
+    l.Capture.FormatR\x06format\x124\n\x06limits\x18\x03\x20\x01(\x0b2\x1c.n\
+    etsim.model.Capture.LimitsR\x06limits\x124\n\x06filter\x18\x04\x20\x01(\
+    \x0b2\x1c.netsim.model.Capture.FilterR\x06filter\"H\n\x13ListCaptureResp\
+    onse\x121\n\x08captures\x18\x01\x20\x03(\x0b2\x15.netsim.model.CaptureR\
+    \x08captures\"S\n\x11GetCaptureRequest\x12\x0e\n\x02id\x18\x01\x20\x01(\
+    \x05R\x02id\x12\x16\n\x06format\x18\x02\x20\x01(\tR\x06format\x12\x16\n\
+    \x06follow\x18\x03\x20\x01(\x08R\x06follow\";\n\x12GetCaptureResponse\
+    \x12%\n\x0ecapture_stream\x18\x01\x20\x01(\x0cR\rcaptureStream\"`\n\rErr\
+    orResponse\x12\x12\n\x04code\x18\x01\x20\x01(\x05R\x04code\x12#\n\rerror\
+    _message\x18\x02\x20\x01(\tR\x0cerrorMessage\x12\x16\n\x06status\x18\x03\
+    \x20\x01(\tR\x06status2\x8b\r\n\x0fFrontendService\x12F\n\nGetVersion\
+    \x12\x16.google.protobuf.Empty\x1a\x20.netsim.frontend.VersionResponse\
+    \x12B\n\x0eRegisterEvents\x12\x16.google.protobuf.Empty\x1a\x16.netsim.f\
+    rontend.Event0\x01\x12U\n\nGetDevices\x12\".netsim.frontend.GetDevicesRe\
+    quest\x1a#.netsim.frontend.GetDevicesResponse\x12J\n\x0bPatchDevice\x12#\
+    .netsim.frontend.PatchDeviceRequest\x1a\x16.google.protobuf.Empty\x12[\n\
+    \x0cCreateDevice\x12$.netsim.frontend.CreateDeviceRequest\x1a%.netsim.fr\
+    ontend.CreateDeviceResponse\x12L\n\x0cDeleteDevice\x12$.netsim.frontend.\
+    DeleteDeviceRequest\x1a\x16.google.protobuf.Empty\x12N\n\rSetTrajectory\
+    \x12%.netsim.frontend.SetTrajectoryRequest\x1a\x16.google.protobuf.Empty\
     \x127\n\x05Reset\x12\x16.google.protobuf.Empty\x1a\x16.google.protobuf.E\
-    mpty\x12J\n\x0bSetLinkLoss\x12#.netsim.frontend.SetLinkLossRequest\x1a\
-    \x16.google.protobuf.Empty\x12A\n\x06NetCat\x12\x16.google.protobuf.Empt\
-    y\x1a\x1d.netsim.frontend.NetCatStream0\x01\x12L\n\x0cPatchCapture\x12$.\
-    netsim.frontend.PatchCaptureRequest\x1a\x16.google.protobuf.Empty\x12K\n\
-    \x0bListCapture\x12\x16.google.protobuf.Empty\x1a$.netsim.frontend.ListC\
-    aptureResponse\x12W\n\nGetCapture\x12\".netsim.frontend.GetCaptureReques\
-    t\x1a#.netsim.frontend.GetCaptureResponse0\x01b\x06proto3\
+    mpty\x12F\n\tSaveScene\x12!.netsim.frontend.SceneFileRequest\x1a\x16.goo\
+    gle.protobuf.Empty\x12F\n\tLoadScene\x12!.netsim.frontend.SceneFileReque\
+    st\x1a\x16.google.protobuf.Empty\x12J\n\x0bSetLinkLoss\x12#.netsim.front\
+    end.SetLinkLossRequest\x1a\x16.google.protobuf.Empty\x12M\n\x0cListLinkL\
+    oss\x12\x16.google.protobuf.Empty\x1a%.netsim.frontend.ListLinkLossRespo\
+    nse\x12?\n\rClearLinkLoss\x12\x16.google.protobuf.Empty\x1a\x16.google.p\
+    rotobuf.Empty\x12F\n\tPatchZone\x12!.netsim.frontend.PatchZoneRequest\
+    \x1a\x16.google.protobuf.Empty\x12G\n\tListZones\x12\x16.google.protobuf\
+    .Empty\x1a\".netsim.frontend.ListZonesResponse\x12H\n\nDeleteZone\x12\".\
+    netsim.frontend.DeleteZoneRequest\x1a\x16.google.protobuf.Empty\x12H\n\n\
+    AssignZone\x12\".netsim.frontend.AssignZoneRequest\x1a\x16.google.protob\
+    uf.Empty\x12M\n\x0cGetDistances\x12\x16.google.protobuf.Empty\x1a%.netsi\
+    m.frontend.GetDistancesResponse\x12A\n\x06NetCat\x12\x16.google.protobuf\
+    .Empty\x1a\x1d.netsim.frontend.NetCatStream0\x01\x12L\n\x0cPatchCapture\
+    \x12$.netsim.frontend.PatchCaptureRequest\x1a\x16.google.protobuf.Empty\
+    \x12K\n\x0bListCapture\x12\x16.google.protobuf.Empty\x1a$.netsim.fronten\
+    d.ListCaptureResponse\x12W\n\nGetCapture\x12\".netsim.frontend.GetCaptur\
+    eRequest\x1a#.netsim.frontend.GetCaptureResponse0\x01b\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -1799,14 +3856,28 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
     static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
     file_descriptor.get(|| {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
-            let mut deps = ::std::vec::Vec::with_capacity(2);
+            let mut deps = ::std::vec::Vec::with_capacity(3);
+            deps.push(super::common::file_descriptor().clone());
             deps.push(::protobuf::well_known_types::empty::file_descriptor().clone());
             deps.push(super::model::file_descriptor().clone());
-            let mut messages = ::std::vec::Vec::with_capacity(13);
+            let mut messages = ::std::vec::Vec::with_capacity(27);
             messages.push(VersionResponse::generated_message_descriptor_data());
             messages.push(SetLinkLossRequest::generated_message_descriptor_data());
+            messages.push(ListLinkLossResponse::generated_message_descriptor_data());
+            messages.push(PatchZoneRequest::generated_message_descriptor_data());
+            messages.push(ListZonesResponse::generated_message_descriptor_data());
+            messages.push(DeleteZoneRequest::generated_message_descriptor_data());
+            messages.push(AssignZoneRequest::generated_message_descriptor_data());
+            messages.push(DeviceDistance::generated_message_descriptor_data());
+            messages.push(GetDistancesResponse::generated_message_descriptor_data());
             messages.push(PatchDeviceRequest::generated_message_descriptor_data());
+            messages.push(CreateDeviceRequest::generated_message_descriptor_data());
+            messages.push(CreateDeviceResponse::generated_message_descriptor_data());
+            messages.push(DeleteDeviceRequest::generated_message_descriptor_data());
+            messages.push(SetTrajectoryRequest::generated_message_descriptor_data());
+            messages.push(SceneFileRequest::generated_message_descriptor_data());
             messages.push(Event::generated_message_descriptor_data());
+            messages.push(GetDevicesRequest::generated_message_descriptor_data());
             messages.push(GetDevicesResponse::generated_message_descriptor_data());
             messages.push(NetCatStream::generated_message_descriptor_data());
             messages.push(SetPacketCaptureRequest::generated_message_descriptor_data());
@@ -1815,6 +3886,7 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             messages.push(GetCaptureRequest::generated_message_descriptor_data());
             messages.push(GetCaptureResponse::generated_message_descriptor_data());
             messages.push(ErrorResponse::generated_message_descriptor_data());
+            messages.push(device_distance::Rssi::generated_message_descriptor_data());
             messages.push(patch_capture_request::PatchCapture::generated_message_descriptor_data());
             let mut enums = ::std::vec::Vec::with_capacity(0);
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
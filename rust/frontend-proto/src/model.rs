@@ -357,6 +357,10 @@ pub struct Chip {
     pub product_name: ::std::string::String,
     // @@protoc_insertion_point(field:netsim.model.Chip.capture)
     pub capture: ::protobuf::EnumOrUnknown<State>,
+    // @@protoc_insertion_point(field:netsim.model.Chip.antenna)
+    pub antenna: ::protobuf::EnumOrUnknown<chip::AntennaPattern>,
+    // @@protoc_insertion_point(field:netsim.model.Chip.traffic)
+    pub traffic: ::protobuf::MessageField<TrafficStats>,
     // message oneof groups
     pub chip: ::std::option::Option<chip::Chip>,
     // special fields
@@ -523,7 +527,7 @@ impl Chip {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(9);
+        let mut fields = ::std::vec::Vec::with_capacity(11);
         let mut oneofs = ::std::vec::Vec::with_capacity(1);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "kind",
@@ -576,6 +580,16 @@ impl Chip {
             Chip::mut_wifi,
             Chip::set_wifi,
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "antenna",
+            |m: &Chip| { &m.antenna },
+            |m: &mut Chip| { &mut m.antenna },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, TrafficStats>(
+            "traffic",
+            |m: &Chip| { &m.traffic },
+            |m: &mut Chip| { &mut m.traffic },
+        ));
         oneofs.push(chip::Chip::generated_oneof_descriptor_data());
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Chip>(
             "Chip",
@@ -622,6 +636,12 @@ impl ::protobuf::Message for Chip {
                 74 => {
                     self.chip = ::std::option::Option::Some(chip::Chip::Wifi(is.read_message()?));
                 },
+                80 => {
+                    self.antenna = is.read_enum_or_unknown()?;
+                },
+                90 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.traffic)?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -652,6 +672,13 @@ impl ::protobuf::Message for Chip {
         if self.capture != ::protobuf::EnumOrUnknown::new(State::UNKNOWN) {
             my_size += ::protobuf::rt::int32_size(6, self.capture.value());
         }
+        if self.antenna != ::protobuf::EnumOrUnknown::new(chip::AntennaPattern::ANTENNA_UNSPECIFIED) {
+            my_size += ::protobuf::rt::int32_size(10, self.antenna.value());
+        }
+        if let Some(v) = self.traffic.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
         if let ::std::option::Option::Some(ref v) = self.chip {
             match v {
                 &chip::Chip::Bt(ref v) => {
@@ -692,6 +719,12 @@ impl ::protobuf::Message for Chip {
         if self.capture != ::protobuf::EnumOrUnknown::new(State::UNKNOWN) {
             os.write_enum(6, ::protobuf::EnumOrUnknown::value(&self.capture))?;
         }
+        if self.antenna != ::protobuf::EnumOrUnknown::new(chip::AntennaPattern::ANTENNA_UNSPECIFIED) {
+            os.write_enum(10, ::protobuf::EnumOrUnknown::value(&self.antenna))?;
+        }
+        if let Some(v) = self.traffic.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(11, v, os)?;
+        }
         if let ::std::option::Option::Some(ref v) = self.chip {
             match v {
                 &chip::Chip::Bt(ref v) => {
@@ -731,6 +764,8 @@ impl ::protobuf::Message for Chip {
         self.chip = ::std::option::Option::None;
         self.chip = ::std::option::Option::None;
         self.chip = ::std::option::Option::None;
+        self.antenna = ::protobuf::EnumOrUnknown::new(chip::AntennaPattern::ANTENNA_UNSPECIFIED);
+        self.traffic.clear();
         self.special_fields.clear();
     }
 
@@ -742,6 +777,8 @@ impl ::protobuf::Message for Chip {
             manufacturer: ::std::string::String::new(),
             product_name: ::std::string::String::new(),
             capture: ::protobuf::EnumOrUnknown::from_i32(0),
+            antenna: ::protobuf::EnumOrUnknown::from_i32(0),
+            traffic: ::protobuf::MessageField::none(),
             chip: ::std::option::Option::None,
             special_fields: ::protobuf::SpecialFields::new(),
         };
@@ -1113,83 +1150,117 @@ pub mod chip {
     impl ::protobuf::reflect::ProtobufValue for Bluetooth {
         type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
     }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:netsim.model.Chip.AntennaPattern)
+    pub enum AntennaPattern {
+        // @@protoc_insertion_point(enum_value:netsim.model.Chip.AntennaPattern.ANTENNA_UNSPECIFIED)
+        ANTENNA_UNSPECIFIED = 0,
+        // @@protoc_insertion_point(enum_value:netsim.model.Chip.AntennaPattern.ISOTROPIC)
+        ISOTROPIC = 1,
+        // @@protoc_insertion_point(enum_value:netsim.model.Chip.AntennaPattern.DIPOLE)
+        DIPOLE = 2,
+        // @@protoc_insertion_point(enum_value:netsim.model.Chip.AntennaPattern.PATCH)
+        PATCH = 3,
+    }
+
+    impl ::protobuf::Enum for AntennaPattern {
+        const NAME: &'static str = "AntennaPattern";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<AntennaPattern> {
+            match value {
+                0 => ::std::option::Option::Some(AntennaPattern::ANTENNA_UNSPECIFIED),
+                1 => ::std::option::Option::Some(AntennaPattern::ISOTROPIC),
+                2 => ::std::option::Option::Some(AntennaPattern::DIPOLE),
+                3 => ::std::option::Option::Some(AntennaPattern::PATCH),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [AntennaPattern] = &[
+            AntennaPattern::ANTENNA_UNSPECIFIED,
+            AntennaPattern::ISOTROPIC,
+            AntennaPattern::DIPOLE,
+            AntennaPattern::PATCH,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for AntennaPattern {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("Chip.AntennaPattern").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for AntennaPattern {
+        fn default() -> Self {
+            AntennaPattern::ANTENNA_UNSPECIFIED
+        }
+    }
+
+    impl AntennaPattern {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<AntennaPattern>("Chip.AntennaPattern")
+        }
+    }
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:netsim.model.Device)
-pub struct Device {
+// @@protoc_insertion_point(message:netsim.model.TrafficStats)
+pub struct TrafficStats {
     // message fields
-    // @@protoc_insertion_point(field:netsim.model.Device.id)
-    pub id: i32,
-    // @@protoc_insertion_point(field:netsim.model.Device.name)
-    pub name: ::std::string::String,
-    // @@protoc_insertion_point(field:netsim.model.Device.visible)
-    pub visible: bool,
-    // @@protoc_insertion_point(field:netsim.model.Device.position)
-    pub position: ::protobuf::MessageField<Position>,
-    // @@protoc_insertion_point(field:netsim.model.Device.orientation)
-    pub orientation: ::protobuf::MessageField<Orientation>,
-    // @@protoc_insertion_point(field:netsim.model.Device.chips)
-    pub chips: ::std::vec::Vec<Chip>,
+    // @@protoc_insertion_point(field:netsim.model.TrafficStats.tx)
+    pub tx: ::protobuf::MessageField<traffic_stats::Direction>,
+    // @@protoc_insertion_point(field:netsim.model.TrafficStats.rx)
+    pub rx: ::protobuf::MessageField<traffic_stats::Direction>,
     // special fields
-    // @@protoc_insertion_point(special_field:netsim.model.Device.special_fields)
+    // @@protoc_insertion_point(special_field:netsim.model.TrafficStats.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a Device {
-    fn default() -> &'a Device {
-        <Device as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a TrafficStats {
+    fn default() -> &'a TrafficStats {
+        <TrafficStats as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Device {
-    pub fn new() -> Device {
+impl TrafficStats {
+    pub fn new() -> TrafficStats {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "id",
-            |m: &Device| { &m.id },
-            |m: &mut Device| { &mut m.id },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "name",
-            |m: &Device| { &m.name },
-            |m: &mut Device| { &mut m.name },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "visible",
-            |m: &Device| { &m.visible },
-            |m: &mut Device| { &mut m.visible },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Position>(
-            "position",
-            |m: &Device| { &m.position },
-            |m: &mut Device| { &mut m.position },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Orientation>(
-            "orientation",
-            |m: &Device| { &m.orientation },
-            |m: &mut Device| { &mut m.orientation },
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, traffic_stats::Direction>(
+            "tx",
+            |m: &TrafficStats| { &m.tx },
+            |m: &mut TrafficStats| { &mut m.tx },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "chips",
-            |m: &Device| { &m.chips },
-            |m: &mut Device| { &mut m.chips },
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, traffic_stats::Direction>(
+            "rx",
+            |m: &TrafficStats| { &m.rx },
+            |m: &mut TrafficStats| { &mut m.rx },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Device>(
-            "Device",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TrafficStats>(
+            "TrafficStats",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for Device {
-    const NAME: &'static str = "Device";
+impl ::protobuf::Message for TrafficStats {
+    const NAME: &'static str = "TrafficStats";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1198,23 +1269,11 @@ impl ::protobuf::Message for Device {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                8 => {
-                    self.id = is.read_int32()?;
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.tx)?;
                 },
                 18 => {
-                    self.name = is.read_string()?;
-                },
-                24 => {
-                    self.visible = is.read_bool()?;
-                },
-                34 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.position)?;
-                },
-                42 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.orientation)?;
-                },
-                50 => {
-                    self.chips.push(is.read_message()?);
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.rx)?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1228,51 +1287,26 @@ impl ::protobuf::Message for Device {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.id != 0 {
-            my_size += ::protobuf::rt::int32_size(1, self.id);
-        }
-        if !self.name.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.name);
-        }
-        if self.visible != false {
-            my_size += 1 + 1;
-        }
-        if let Some(v) = self.position.as_ref() {
+        if let Some(v) = self.tx.as_ref() {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
-        if let Some(v) = self.orientation.as_ref() {
+        if let Some(v) = self.rx.as_ref() {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
-        for value in &self.chips {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.id != 0 {
-            os.write_int32(1, self.id)?;
-        }
-        if !self.name.is_empty() {
-            os.write_string(2, &self.name)?;
-        }
-        if self.visible != false {
-            os.write_bool(3, self.visible)?;
-        }
-        if let Some(v) = self.position.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        if let Some(v) = self.tx.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
         }
-        if let Some(v) = self.orientation.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        if let Some(v) = self.rx.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
         }
-        for v in &self.chips {
-            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
-        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1285,263 +1319,410 @@ impl ::protobuf::Message for Device {
         &mut self.special_fields
     }
 
-    fn new() -> Device {
-        Device::new()
+    fn new() -> TrafficStats {
+        TrafficStats::new()
     }
 
     fn clear(&mut self) {
-        self.id = 0;
-        self.name.clear();
-        self.visible = false;
-        self.position.clear();
-        self.orientation.clear();
-        self.chips.clear();
+        self.tx.clear();
+        self.rx.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static Device {
-        static instance: Device = Device {
-            id: 0,
-            name: ::std::string::String::new(),
-            visible: false,
-            position: ::protobuf::MessageField::none(),
-            orientation: ::protobuf::MessageField::none(),
-            chips: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static TrafficStats {
+        static instance: TrafficStats = TrafficStats {
+            tx: ::protobuf::MessageField::none(),
+            rx: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for Device {
+impl ::protobuf::MessageFull for TrafficStats {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("Device").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TrafficStats").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for Device {
+impl ::std::fmt::Display for TrafficStats {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Device {
+impl ::protobuf::reflect::ProtobufValue for TrafficStats {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-#[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:netsim.model.Scene)
-pub struct Scene {
-    // message fields
-    // @@protoc_insertion_point(field:netsim.model.Scene.devices)
-    pub devices: ::std::vec::Vec<Device>,
-    // special fields
-    // @@protoc_insertion_point(special_field:netsim.model.Scene.special_fields)
-    pub special_fields: ::protobuf::SpecialFields,
-}
-
-impl<'a> ::std::default::Default for &'a Scene {
-    fn default() -> &'a Scene {
-        <Scene as ::protobuf::Message>::default_instance()
-    }
-}
-
-impl Scene {
-    pub fn new() -> Scene {
-        ::std::default::Default::default()
-    }
-
-    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
-        let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "devices",
-            |m: &Scene| { &m.devices },
-            |m: &mut Scene| { &mut m.devices },
-        ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Scene>(
-            "Scene",
-            fields,
-            oneofs,
-        )
+/// Nested message and enums of message `TrafficStats`
+pub mod traffic_stats {
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.model.TrafficStats.Rate)
+    pub struct Rate {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.model.TrafficStats.Rate.window_secs)
+        pub window_secs: i32,
+        // @@protoc_insertion_point(field:netsim.model.TrafficStats.Rate.packets_per_sec)
+        pub packets_per_sec: f32,
+        // @@protoc_insertion_point(field:netsim.model.TrafficStats.Rate.bytes_per_sec)
+        pub bytes_per_sec: f32,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.model.TrafficStats.Rate.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
     }
-}
-
-impl ::protobuf::Message for Scene {
-    const NAME: &'static str = "Scene";
 
-    fn is_initialized(&self) -> bool {
-        true
+    impl<'a> ::std::default::Default for &'a Rate {
+        fn default() -> &'a Rate {
+            <Rate as ::protobuf::Message>::default_instance()
+        }
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
-        while let Some(tag) = is.read_raw_tag_or_eof()? {
-            match tag {
-                10 => {
-                    self.devices.push(is.read_message()?);
-                },
-                tag => {
-                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
-                },
-            };
+    impl Rate {
+        pub fn new() -> Rate {
+            ::std::default::Default::default()
         }
-        ::std::result::Result::Ok(())
-    }
 
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u64 {
-        let mut my_size = 0;
-        for value in &self.devices {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        };
-        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
-        self.special_fields.cached_size().set(my_size as u32);
-        my_size
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(3);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "window_secs",
+                |m: &Rate| { &m.window_secs },
+                |m: &mut Rate| { &mut m.window_secs },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "packets_per_sec",
+                |m: &Rate| { &m.packets_per_sec },
+                |m: &mut Rate| { &mut m.packets_per_sec },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "bytes_per_sec",
+                |m: &Rate| { &m.bytes_per_sec },
+                |m: &mut Rate| { &mut m.bytes_per_sec },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Rate>(
+                "TrafficStats.Rate",
+                fields,
+                oneofs,
+            )
+        }
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        for v in &self.devices {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
-        };
-        os.write_unknown_fields(self.special_fields.unknown_fields())?;
-        ::std::result::Result::Ok(())
-    }
+    impl ::protobuf::Message for Rate {
+        const NAME: &'static str = "Rate";
 
-    fn special_fields(&self) -> &::protobuf::SpecialFields {
-        &self.special_fields
-    }
+        fn is_initialized(&self) -> bool {
+            true
+        }
 
-    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
-        &mut self.special_fields
-    }
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    8 => {
+                        self.window_secs = is.read_int32()?;
+                    },
+                    21 => {
+                        self.packets_per_sec = is.read_float()?;
+                    },
+                    29 => {
+                        self.bytes_per_sec = is.read_float()?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
 
-    fn new() -> Scene {
-        Scene::new()
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if self.window_secs != 0 {
+                my_size += ::protobuf::rt::int32_size(1, self.window_secs);
+            }
+            if self.packets_per_sec != 0. {
+                my_size += 1 + 4;
+            }
+            if self.bytes_per_sec != 0. {
+                my_size += 1 + 4;
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if self.window_secs != 0 {
+                os.write_int32(1, self.window_secs)?;
+            }
+            if self.packets_per_sec != 0. {
+                os.write_float(2, self.packets_per_sec)?;
+            }
+            if self.bytes_per_sec != 0. {
+                os.write_float(3, self.bytes_per_sec)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Rate {
+            Rate::new()
+        }
+
+        fn clear(&mut self) {
+            self.window_secs = 0;
+            self.packets_per_sec = 0.;
+            self.bytes_per_sec = 0.;
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static Rate {
+            static instance: Rate = Rate {
+                window_secs: 0,
+                packets_per_sec: 0.,
+                bytes_per_sec: 0.,
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
     }
 
-    fn clear(&mut self) {
-        self.devices.clear();
-        self.special_fields.clear();
+    impl ::protobuf::MessageFull for Rate {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("TrafficStats.Rate").unwrap()).clone()
+        }
     }
 
-    fn default_instance() -> &'static Scene {
-        static instance: Scene = Scene {
-            devices: ::std::vec::Vec::new(),
-            special_fields: ::protobuf::SpecialFields::new(),
-        };
-        &instance
+    impl ::std::fmt::Display for Rate {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
     }
-}
 
-impl ::protobuf::MessageFull for Scene {
-    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
-        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("Scene").unwrap()).clone()
+    impl ::protobuf::reflect::ProtobufValue for Rate {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
     }
-}
 
-impl ::std::fmt::Display for Scene {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.model.TrafficStats.Direction)
+    pub struct Direction {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.model.TrafficStats.Direction.packets)
+        pub packets: i64,
+        // @@protoc_insertion_point(field:netsim.model.TrafficStats.Direction.bytes)
+        pub bytes: i64,
+        // @@protoc_insertion_point(field:netsim.model.TrafficStats.Direction.rates)
+        pub rates: ::std::vec::Vec<Rate>,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.model.TrafficStats.Direction.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
     }
-}
 
-impl ::protobuf::reflect::ProtobufValue for Scene {
-    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    impl<'a> ::std::default::Default for &'a Direction {
+        fn default() -> &'a Direction {
+            <Direction as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl Direction {
+        pub fn new() -> Direction {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(3);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "packets",
+                |m: &Direction| { &m.packets },
+                |m: &mut Direction| { &mut m.packets },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "bytes",
+                |m: &Direction| { &m.bytes },
+                |m: &mut Direction| { &mut m.bytes },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+                "rates",
+                |m: &Direction| { &m.rates },
+                |m: &mut Direction| { &mut m.rates },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Direction>(
+                "TrafficStats.Direction",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for Direction {
+        const NAME: &'static str = "Direction";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    8 => {
+                        self.packets = is.read_int64()?;
+                    },
+                    16 => {
+                        self.bytes = is.read_int64()?;
+                    },
+                    26 => {
+                        self.rates.push(is.read_message()?);
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if self.packets != 0 {
+                my_size += ::protobuf::rt::int64_size(1, self.packets);
+            }
+            if self.bytes != 0 {
+                my_size += ::protobuf::rt::int64_size(2, self.bytes);
+            }
+            for value in &self.rates {
+                let len = value.compute_size();
+                my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            };
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if self.packets != 0 {
+                os.write_int64(1, self.packets)?;
+            }
+            if self.bytes != 0 {
+                os.write_int64(2, self.bytes)?;
+            }
+            for v in &self.rates {
+                ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+            };
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Direction {
+            Direction::new()
+        }
+
+        fn clear(&mut self) {
+            self.packets = 0;
+            self.bytes = 0;
+            self.rates.clear();
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static Direction {
+            static instance: Direction = Direction {
+                packets: 0,
+                bytes: 0,
+                rates: ::std::vec::Vec::new(),
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for Direction {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("TrafficStats.Direction").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for Direction {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for Direction {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:netsim.model.Capture)
-pub struct Capture {
+// @@protoc_insertion_point(message:netsim.model.RadioTraffic)
+pub struct RadioTraffic {
     // message fields
-    // @@protoc_insertion_point(field:netsim.model.Capture.id)
-    pub id: i32,
-    // @@protoc_insertion_point(field:netsim.model.Capture.chip_kind)
-    pub chip_kind: ::protobuf::EnumOrUnknown<super::common::ChipKind>,
-    // @@protoc_insertion_point(field:netsim.model.Capture.device_name)
-    pub device_name: ::std::string::String,
-    // @@protoc_insertion_point(field:netsim.model.Capture.state)
-    pub state: ::protobuf::EnumOrUnknown<State>,
-    // @@protoc_insertion_point(field:netsim.model.Capture.size)
-    pub size: i32,
-    // @@protoc_insertion_point(field:netsim.model.Capture.records)
-    pub records: i32,
-    // @@protoc_insertion_point(field:netsim.model.Capture.timestamp)
-    pub timestamp: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
-    // @@protoc_insertion_point(field:netsim.model.Capture.valid)
-    pub valid: bool,
+    // @@protoc_insertion_point(field:netsim.model.RadioTraffic.kind)
+    pub kind: ::protobuf::EnumOrUnknown<super::common::ChipKind>,
+    // @@protoc_insertion_point(field:netsim.model.RadioTraffic.traffic)
+    pub traffic: ::protobuf::MessageField<TrafficStats>,
     // special fields
-    // @@protoc_insertion_point(special_field:netsim.model.Capture.special_fields)
+    // @@protoc_insertion_point(special_field:netsim.model.RadioTraffic.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a Capture {
-    fn default() -> &'a Capture {
-        <Capture as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a RadioTraffic {
+    fn default() -> &'a RadioTraffic {
+        <RadioTraffic as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Capture {
-    pub fn new() -> Capture {
+impl RadioTraffic {
+    pub fn new() -> RadioTraffic {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(8);
+        let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "id",
-            |m: &Capture| { &m.id },
-            |m: &mut Capture| { &mut m.id },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "chip_kind",
-            |m: &Capture| { &m.chip_kind },
-            |m: &mut Capture| { &mut m.chip_kind },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "device_name",
-            |m: &Capture| { &m.device_name },
-            |m: &mut Capture| { &mut m.device_name },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "state",
-            |m: &Capture| { &m.state },
-            |m: &mut Capture| { &mut m.state },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "size",
-            |m: &Capture| { &m.size },
-            |m: &mut Capture| { &mut m.size },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "records",
-            |m: &Capture| { &m.records },
-            |m: &mut Capture| { &mut m.records },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::timestamp::Timestamp>(
-            "timestamp",
-            |m: &Capture| { &m.timestamp },
-            |m: &mut Capture| { &mut m.timestamp },
+            "kind",
+            |m: &RadioTraffic| { &m.kind },
+            |m: &mut RadioTraffic| { &mut m.kind },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "valid",
-            |m: &Capture| { &m.valid },
-            |m: &mut Capture| { &mut m.valid },
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, TrafficStats>(
+            "traffic",
+            |m: &RadioTraffic| { &m.traffic },
+            |m: &mut RadioTraffic| { &mut m.traffic },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Capture>(
-            "Capture",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RadioTraffic>(
+            "RadioTraffic",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for Capture {
-    const NAME: &'static str = "Capture";
+impl ::protobuf::Message for RadioTraffic {
+    const NAME: &'static str = "RadioTraffic";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1551,28 +1732,10 @@ impl ::protobuf::Message for Capture {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
-                    self.id = is.read_int32()?;
-                },
-                16 => {
-                    self.chip_kind = is.read_enum_or_unknown()?;
-                },
-                26 => {
-                    self.device_name = is.read_string()?;
-                },
-                32 => {
-                    self.state = is.read_enum_or_unknown()?;
-                },
-                40 => {
-                    self.size = is.read_int32()?;
-                },
-                48 => {
-                    self.records = is.read_int32()?;
-                },
-                58 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.timestamp)?;
+                    self.kind = is.read_enum_or_unknown()?;
                 },
-                64 => {
-                    self.valid = is.read_bool()?;
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.traffic)?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1586,60 +1749,24 @@ impl ::protobuf::Message for Capture {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.id != 0 {
-            my_size += ::protobuf::rt::int32_size(1, self.id);
-        }
-        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
-            my_size += ::protobuf::rt::int32_size(2, self.chip_kind.value());
-        }
-        if !self.device_name.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.device_name);
-        }
-        if self.state != ::protobuf::EnumOrUnknown::new(State::UNKNOWN) {
-            my_size += ::protobuf::rt::int32_size(4, self.state.value());
-        }
-        if self.size != 0 {
-            my_size += ::protobuf::rt::int32_size(5, self.size);
-        }
-        if self.records != 0 {
-            my_size += ::protobuf::rt::int32_size(6, self.records);
+        if self.kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            my_size += ::protobuf::rt::int32_size(1, self.kind.value());
         }
-        if let Some(v) = self.timestamp.as_ref() {
+        if let Some(v) = self.traffic.as_ref() {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
-        if self.valid != false {
-            my_size += 1 + 1;
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.id != 0 {
-            os.write_int32(1, self.id)?;
-        }
-        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
-            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.chip_kind))?;
-        }
-        if !self.device_name.is_empty() {
-            os.write_string(3, &self.device_name)?;
-        }
-        if self.state != ::protobuf::EnumOrUnknown::new(State::UNKNOWN) {
-            os.write_enum(4, ::protobuf::EnumOrUnknown::value(&self.state))?;
-        }
-        if self.size != 0 {
-            os.write_int32(5, self.size)?;
-        }
-        if self.records != 0 {
-            os.write_int32(6, self.records)?;
-        }
-        if let Some(v) = self.timestamp.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+        if self.kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.kind))?;
         }
-        if self.valid != false {
-            os.write_bool(8, self.valid)?;
+        if let Some(v) = self.traffic.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1653,55 +1780,1842 @@ impl ::protobuf::Message for Capture {
         &mut self.special_fields
     }
 
-    fn new() -> Capture {
-        Capture::new()
+    fn new() -> RadioTraffic {
+        RadioTraffic::new()
     }
 
     fn clear(&mut self) {
-        self.id = 0;
-        self.chip_kind = ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED);
-        self.device_name.clear();
-        self.state = ::protobuf::EnumOrUnknown::new(State::UNKNOWN);
-        self.size = 0;
-        self.records = 0;
-        self.timestamp.clear();
-        self.valid = false;
+        self.kind = ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED);
+        self.traffic.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static Capture {
-        static instance: Capture = Capture {
-            id: 0,
-            chip_kind: ::protobuf::EnumOrUnknown::from_i32(0),
-            device_name: ::std::string::String::new(),
-            state: ::protobuf::EnumOrUnknown::from_i32(0),
-            size: 0,
-            records: 0,
-            timestamp: ::protobuf::MessageField::none(),
-            valid: false,
+    fn default_instance() -> &'static RadioTraffic {
+        static instance: RadioTraffic = RadioTraffic {
+            kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            traffic: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for Capture {
+impl ::protobuf::MessageFull for RadioTraffic {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("Capture").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RadioTraffic").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for Capture {
+impl ::std::fmt::Display for RadioTraffic {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Capture {
+impl ::protobuf::reflect::ProtobufValue for RadioTraffic {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.model.Device)
+pub struct Device {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.model.Device.id)
+    pub id: i32,
+    // @@protoc_insertion_point(field:netsim.model.Device.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.model.Device.visible)
+    pub visible: ::protobuf::EnumOrUnknown<State>,
+    // @@protoc_insertion_point(field:netsim.model.Device.position)
+    pub position: ::protobuf::MessageField<Position>,
+    // @@protoc_insertion_point(field:netsim.model.Device.orientation)
+    pub orientation: ::protobuf::MessageField<Orientation>,
+    // @@protoc_insertion_point(field:netsim.model.Device.chips)
+    pub chips: ::std::vec::Vec<Chip>,
+    // @@protoc_insertion_point(field:netsim.model.Device.zone)
+    pub zone: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.model.Device.behavior)
+    pub behavior: ::protobuf::EnumOrUnknown<device::Behavior>,
+    // @@protoc_insertion_point(field:netsim.model.Device.labels)
+    pub labels: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.model.Device.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Device {
+    fn default() -> &'a Device {
+        <Device as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Device {
+    pub fn new() -> Device {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(9);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &Device| { &m.id },
+            |m: &mut Device| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &Device| { &m.name },
+            |m: &mut Device| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "visible",
+            |m: &Device| { &m.visible },
+            |m: &mut Device| { &mut m.visible },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Position>(
+            "position",
+            |m: &Device| { &m.position },
+            |m: &mut Device| { &mut m.position },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Orientation>(
+            "orientation",
+            |m: &Device| { &m.orientation },
+            |m: &mut Device| { &mut m.orientation },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "chips",
+            |m: &Device| { &m.chips },
+            |m: &mut Device| { &mut m.chips },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "zone",
+            |m: &Device| { &m.zone },
+            |m: &mut Device| { &mut m.zone },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "behavior",
+            |m: &Device| { &m.behavior },
+            |m: &mut Device| { &mut m.behavior },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "labels",
+            |m: &Device| { &m.labels },
+            |m: &mut Device| { &mut m.labels },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Device>(
+            "Device",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Device {
+    const NAME: &'static str = "Device";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_int32()?;
+                },
+                18 => {
+                    self.name = is.read_string()?;
+                },
+                24 => {
+                    self.visible = is.read_enum_or_unknown()?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.position)?;
+                },
+                42 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.orientation)?;
+                },
+                50 => {
+                    self.chips.push(is.read_message()?);
+                },
+                58 => {
+                    self.zone = is.read_string()?;
+                },
+                64 => {
+                    self.behavior = is.read_enum_or_unknown()?;
+                },
+                74 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_string()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.labels.insert(key, value);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::int32_size(1, self.id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if self.visible != ::protobuf::EnumOrUnknown::new(State::UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(3, self.visible.value());
+        }
+        if let Some(v) = self.position.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.orientation.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.chips {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if !self.zone.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.zone);
+        }
+        if self.behavior != ::protobuf::EnumOrUnknown::new(device::Behavior::BEHAVIOR_NONE) {
+            my_size += ::protobuf::rt::int32_size(8, self.behavior.value());
+        }
+        for (k, v) in &self.labels {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_int32(1, self.id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if self.visible != ::protobuf::EnumOrUnknown::new(State::UNKNOWN) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.visible))?;
+        }
+        if let Some(v) = self.position.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if let Some(v) = self.orientation.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        }
+        for v in &self.chips {
+            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+        };
+        if !self.zone.is_empty() {
+            os.write_string(7, &self.zone)?;
+        }
+        if self.behavior != ::protobuf::EnumOrUnknown::new(device::Behavior::BEHAVIOR_NONE) {
+            os.write_enum(8, ::protobuf::EnumOrUnknown::value(&self.behavior))?;
+        }
+        for (k, v) in &self.labels {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            os.write_raw_varint32(74)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Device {
+        Device::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.name.clear();
+        self.visible = ::protobuf::EnumOrUnknown::new(State::UNKNOWN);
+        self.position.clear();
+        self.orientation.clear();
+        self.chips.clear();
+        self.zone.clear();
+        self.behavior = ::protobuf::EnumOrUnknown::new(device::Behavior::BEHAVIOR_NONE);
+        self.labels.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Device {
+        static instance: ::protobuf::rt::Lazy<Device> = ::protobuf::rt::Lazy::new();
+        instance.get(Device::new)
+    }
+}
+
+impl ::protobuf::MessageFull for Device {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Device").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Device {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Device {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `Device`
+pub mod device {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:netsim.model.Device.Behavior)
+    pub enum Behavior {
+        // @@protoc_insertion_point(enum_value:netsim.model.Device.Behavior.BEHAVIOR_NONE)
+        BEHAVIOR_NONE = 0,
+        // @@protoc_insertion_point(enum_value:netsim.model.Device.Behavior.BEACON)
+        BEACON = 1,
+        // @@protoc_insertion_point(enum_value:netsim.model.Device.Behavior.ACCESS_POINT)
+        ACCESS_POINT = 2,
+        // @@protoc_insertion_point(enum_value:netsim.model.Device.Behavior.ANCHOR)
+        ANCHOR = 3,
+    }
+
+    impl ::protobuf::Enum for Behavior {
+        const NAME: &'static str = "Behavior";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Behavior> {
+            match value {
+                0 => ::std::option::Option::Some(Behavior::BEHAVIOR_NONE),
+                1 => ::std::option::Option::Some(Behavior::BEACON),
+                2 => ::std::option::Option::Some(Behavior::ACCESS_POINT),
+                3 => ::std::option::Option::Some(Behavior::ANCHOR),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Behavior] = &[
+            Behavior::BEHAVIOR_NONE,
+            Behavior::BEACON,
+            Behavior::ACCESS_POINT,
+            Behavior::ANCHOR,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Behavior {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("Device.Behavior").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Behavior {
+        fn default() -> Self {
+            Behavior::BEHAVIOR_NONE
+        }
+    }
+
+    impl Behavior {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Behavior>("Device.Behavior")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.model.Zone)
+pub struct Zone {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.model.Zone.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.model.Zone.attenuation)
+    pub attenuation: f32,
+    // @@protoc_insertion_point(field:netsim.model.Zone.isolated)
+    pub isolated: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.model.Zone.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Zone {
+    fn default() -> &'a Zone {
+        <Zone as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Zone {
+    pub fn new() -> Zone {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &Zone| { &m.name },
+            |m: &mut Zone| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "attenuation",
+            |m: &Zone| { &m.attenuation },
+            |m: &mut Zone| { &mut m.attenuation },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "isolated",
+            |m: &Zone| { &m.isolated },
+            |m: &mut Zone| { &mut m.isolated },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Zone>(
+            "Zone",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Zone {
+    const NAME: &'static str = "Zone";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                21 => {
+                    self.attenuation = is.read_float()?;
+                },
+                24 => {
+                    self.isolated = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.attenuation != 0. {
+            my_size += 1 + 4;
+        }
+        if self.isolated != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.attenuation != 0. {
+            os.write_float(2, self.attenuation)?;
+        }
+        if self.isolated != false {
+            os.write_bool(3, self.isolated)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Zone {
+        Zone::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.attenuation = 0.;
+        self.isolated = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Zone {
+        static instance: Zone = Zone {
+            name: ::std::string::String::new(),
+            attenuation: 0.,
+            isolated: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Zone {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Zone").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Zone {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Zone {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.model.Scene)
+pub struct Scene {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.model.Scene.devices)
+    pub devices: ::std::vec::Vec<Device>,
+    // @@protoc_insertion_point(field:netsim.model.Scene.zones)
+    pub zones: ::std::vec::Vec<Zone>,
+    // @@protoc_insertion_point(field:netsim.model.Scene.radios)
+    pub radios: ::std::vec::Vec<RadioTraffic>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.model.Scene.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Scene {
+    fn default() -> &'a Scene {
+        <Scene as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Scene {
+    pub fn new() -> Scene {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "devices",
+            |m: &Scene| { &m.devices },
+            |m: &mut Scene| { &mut m.devices },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "zones",
+            |m: &Scene| { &m.zones },
+            |m: &mut Scene| { &mut m.zones },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "radios",
+            |m: &Scene| { &m.radios },
+            |m: &mut Scene| { &mut m.radios },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Scene>(
+            "Scene",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Scene {
+    const NAME: &'static str = "Scene";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.devices.push(is.read_message()?);
+                },
+                18 => {
+                    self.zones.push(is.read_message()?);
+                },
+                26 => {
+                    self.radios.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.devices {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.zones {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.radios {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.devices {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        for v in &self.zones {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        for v in &self.radios {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Scene {
+        Scene::new()
+    }
+
+    fn clear(&mut self) {
+        self.devices.clear();
+        self.zones.clear();
+        self.radios.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Scene {
+        static instance: Scene = Scene {
+            devices: ::std::vec::Vec::new(),
+            zones: ::std::vec::Vec::new(),
+            radios: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Scene {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Scene").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Scene {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Scene {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.model.Waypoint)
+pub struct Waypoint {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.model.Waypoint.position)
+    pub position: ::protobuf::MessageField<Position>,
+    // @@protoc_insertion_point(field:netsim.model.Waypoint.orientation)
+    pub orientation: ::protobuf::MessageField<Orientation>,
+    // @@protoc_insertion_point(field:netsim.model.Waypoint.time)
+    pub time: f32,
+    // @@protoc_insertion_point(field:netsim.model.Waypoint.speed)
+    pub speed: f32,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.model.Waypoint.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Waypoint {
+    fn default() -> &'a Waypoint {
+        <Waypoint as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Waypoint {
+    pub fn new() -> Waypoint {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Position>(
+            "position",
+            |m: &Waypoint| { &m.position },
+            |m: &mut Waypoint| { &mut m.position },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Orientation>(
+            "orientation",
+            |m: &Waypoint| { &m.orientation },
+            |m: &mut Waypoint| { &mut m.orientation },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "time",
+            |m: &Waypoint| { &m.time },
+            |m: &mut Waypoint| { &mut m.time },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "speed",
+            |m: &Waypoint| { &m.speed },
+            |m: &mut Waypoint| { &mut m.speed },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Waypoint>(
+            "Waypoint",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Waypoint {
+    const NAME: &'static str = "Waypoint";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.position)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.orientation)?;
+                },
+                29 => {
+                    self.time = is.read_float()?;
+                },
+                37 => {
+                    self.speed = is.read_float()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.position.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.orientation.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.time != 0. {
+            my_size += 1 + 4;
+        }
+        if self.speed != 0. {
+            my_size += 1 + 4;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.position.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.orientation.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if self.time != 0. {
+            os.write_float(3, self.time)?;
+        }
+        if self.speed != 0. {
+            os.write_float(4, self.speed)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Waypoint {
+        Waypoint::new()
+    }
+
+    fn clear(&mut self) {
+        self.position.clear();
+        self.orientation.clear();
+        self.time = 0.;
+        self.speed = 0.;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Waypoint {
+        static instance: Waypoint = Waypoint {
+            position: ::protobuf::MessageField::none(),
+            orientation: ::protobuf::MessageField::none(),
+            time: 0.,
+            speed: 0.,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Waypoint {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Waypoint").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Waypoint {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Waypoint {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.model.Trajectory)
+pub struct Trajectory {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.model.Trajectory.waypoints)
+    pub waypoints: ::std::vec::Vec<Waypoint>,
+    // @@protoc_insertion_point(field:netsim.model.Trajectory.loop)
+    pub loop_: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.model.Trajectory.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Trajectory {
+    fn default() -> &'a Trajectory {
+        <Trajectory as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Trajectory {
+    pub fn new() -> Trajectory {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "waypoints",
+            |m: &Trajectory| { &m.waypoints },
+            |m: &mut Trajectory| { &mut m.waypoints },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "loop",
+            |m: &Trajectory| { &m.loop_ },
+            |m: &mut Trajectory| { &mut m.loop_ },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Trajectory>(
+            "Trajectory",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Trajectory {
+    const NAME: &'static str = "Trajectory";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.waypoints.push(is.read_message()?);
+                },
+                16 => {
+                    self.loop_ = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.waypoints {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.loop_ != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.waypoints {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if self.loop_ != false {
+            os.write_bool(2, self.loop_)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Trajectory {
+        Trajectory::new()
+    }
+
+    fn clear(&mut self) {
+        self.waypoints.clear();
+        self.loop_ = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Trajectory {
+        static instance: Trajectory = Trajectory {
+            waypoints: ::std::vec::Vec::new(),
+            loop_: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Trajectory {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Trajectory").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Trajectory {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Trajectory {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:netsim.model.Capture)
+pub struct Capture {
+    // message fields
+    // @@protoc_insertion_point(field:netsim.model.Capture.id)
+    pub id: i32,
+    // @@protoc_insertion_point(field:netsim.model.Capture.chip_kind)
+    pub chip_kind: ::protobuf::EnumOrUnknown<super::common::ChipKind>,
+    // @@protoc_insertion_point(field:netsim.model.Capture.device_name)
+    pub device_name: ::std::string::String,
+    // @@protoc_insertion_point(field:netsim.model.Capture.state)
+    pub state: ::protobuf::EnumOrUnknown<State>,
+    // @@protoc_insertion_point(field:netsim.model.Capture.size)
+    pub size: i32,
+    // @@protoc_insertion_point(field:netsim.model.Capture.records)
+    pub records: i32,
+    // @@protoc_insertion_point(field:netsim.model.Capture.timestamp)
+    pub timestamp: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
+    // @@protoc_insertion_point(field:netsim.model.Capture.valid)
+    pub valid: bool,
+    // @@protoc_insertion_point(field:netsim.model.Capture.format)
+    pub format: ::protobuf::EnumOrUnknown<capture::Format>,
+    // @@protoc_insertion_point(field:netsim.model.Capture.limits)
+    pub limits: ::protobuf::MessageField<capture::Limits>,
+    // @@protoc_insertion_point(field:netsim.model.Capture.segments)
+    pub segments: i32,
+    // @@protoc_insertion_point(field:netsim.model.Capture.filter)
+    pub filter: ::protobuf::MessageField<capture::Filter>,
+    // special fields
+    // @@protoc_insertion_point(special_field:netsim.model.Capture.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Capture {
+    fn default() -> &'a Capture {
+        <Capture as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Capture {
+    pub fn new() -> Capture {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(12);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &Capture| { &m.id },
+            |m: &mut Capture| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chip_kind",
+            |m: &Capture| { &m.chip_kind },
+            |m: &mut Capture| { &mut m.chip_kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device_name",
+            |m: &Capture| { &m.device_name },
+            |m: &mut Capture| { &mut m.device_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "state",
+            |m: &Capture| { &m.state },
+            |m: &mut Capture| { &mut m.state },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "size",
+            |m: &Capture| { &m.size },
+            |m: &mut Capture| { &mut m.size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "records",
+            |m: &Capture| { &m.records },
+            |m: &mut Capture| { &mut m.records },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::timestamp::Timestamp>(
+            "timestamp",
+            |m: &Capture| { &m.timestamp },
+            |m: &mut Capture| { &mut m.timestamp },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "valid",
+            |m: &Capture| { &m.valid },
+            |m: &mut Capture| { &mut m.valid },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "format",
+            |m: &Capture| { &m.format },
+            |m: &mut Capture| { &mut m.format },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, capture::Limits>(
+            "limits",
+            |m: &Capture| { &m.limits },
+            |m: &mut Capture| { &mut m.limits },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "segments",
+            |m: &Capture| { &m.segments },
+            |m: &mut Capture| { &mut m.segments },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, capture::Filter>(
+            "filter",
+            |m: &Capture| { &m.filter },
+            |m: &mut Capture| { &mut m.filter },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Capture>(
+            "Capture",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Capture {
+    const NAME: &'static str = "Capture";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_int32()?;
+                },
+                16 => {
+                    self.chip_kind = is.read_enum_or_unknown()?;
+                },
+                26 => {
+                    self.device_name = is.read_string()?;
+                },
+                32 => {
+                    self.state = is.read_enum_or_unknown()?;
+                },
+                40 => {
+                    self.size = is.read_int32()?;
+                },
+                48 => {
+                    self.records = is.read_int32()?;
+                },
+                58 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.timestamp)?;
+                },
+                64 => {
+                    self.valid = is.read_bool()?;
+                },
+                72 => {
+                    self.format = is.read_enum_or_unknown()?;
+                },
+                82 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.limits)?;
+                },
+                88 => {
+                    self.segments = is.read_int32()?;
+                },
+                98 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.filter)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::int32_size(1, self.id);
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            my_size += ::protobuf::rt::int32_size(2, self.chip_kind.value());
+        }
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.device_name);
+        }
+        if self.state != ::protobuf::EnumOrUnknown::new(State::UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(4, self.state.value());
+        }
+        if self.size != 0 {
+            my_size += ::protobuf::rt::int32_size(5, self.size);
+        }
+        if self.records != 0 {
+            my_size += ::protobuf::rt::int32_size(6, self.records);
+        }
+        if let Some(v) = self.timestamp.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.valid != false {
+            my_size += 1 + 1;
+        }
+        if self.format != ::protobuf::EnumOrUnknown::new(capture::Format::PCAP) {
+            my_size += ::protobuf::rt::int32_size(9, self.format.value());
+        }
+        if let Some(v) = self.limits.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.segments != 0 {
+            my_size += ::protobuf::rt::int32_size(11, self.segments);
+        }
+        if let Some(v) = self.filter.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_int32(1, self.id)?;
+        }
+        if self.chip_kind != ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.chip_kind))?;
+        }
+        if !self.device_name.is_empty() {
+            os.write_string(3, &self.device_name)?;
+        }
+        if self.state != ::protobuf::EnumOrUnknown::new(State::UNKNOWN) {
+            os.write_enum(4, ::protobuf::EnumOrUnknown::value(&self.state))?;
+        }
+        if self.size != 0 {
+            os.write_int32(5, self.size)?;
+        }
+        if self.records != 0 {
+            os.write_int32(6, self.records)?;
+        }
+        if let Some(v) = self.timestamp.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+        }
+        if self.valid != false {
+            os.write_bool(8, self.valid)?;
+        }
+        if self.format != ::protobuf::EnumOrUnknown::new(capture::Format::PCAP) {
+            os.write_enum(9, ::protobuf::EnumOrUnknown::value(&self.format))?;
+        }
+        if let Some(v) = self.limits.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(10, v, os)?;
+        }
+        if self.segments != 0 {
+            os.write_int32(11, self.segments)?;
+        }
+        if let Some(v) = self.filter.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(12, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Capture {
+        Capture::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.chip_kind = ::protobuf::EnumOrUnknown::new(super::common::ChipKind::UNSPECIFIED);
+        self.device_name.clear();
+        self.state = ::protobuf::EnumOrUnknown::new(State::UNKNOWN);
+        self.size = 0;
+        self.records = 0;
+        self.timestamp.clear();
+        self.valid = false;
+        self.format = ::protobuf::EnumOrUnknown::new(capture::Format::PCAP);
+        self.limits.clear();
+        self.segments = 0;
+        self.filter.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Capture {
+        static instance: Capture = Capture {
+            id: 0,
+            chip_kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            device_name: ::std::string::String::new(),
+            state: ::protobuf::EnumOrUnknown::from_i32(0),
+            size: 0,
+            records: 0,
+            timestamp: ::protobuf::MessageField::none(),
+            valid: false,
+            format: ::protobuf::EnumOrUnknown::from_i32(0),
+            limits: ::protobuf::MessageField::none(),
+            segments: 0,
+            filter: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Capture {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Capture").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Capture {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Capture {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `Capture`
+pub mod capture {
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.model.Capture.Limits)
+    pub struct Limits {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.model.Capture.Limits.max_bytes)
+        pub max_bytes: i64,
+        // @@protoc_insertion_point(field:netsim.model.Capture.Limits.max_records)
+        pub max_records: i32,
+        // @@protoc_insertion_point(field:netsim.model.Capture.Limits.max_duration_secs)
+        pub max_duration_secs: i32,
+        // @@protoc_insertion_point(field:netsim.model.Capture.Limits.mode)
+        pub mode: ::protobuf::EnumOrUnknown<Mode>,
+        // @@protoc_insertion_point(field:netsim.model.Capture.Limits.max_segments)
+        pub max_segments: i32,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.model.Capture.Limits.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a Limits {
+        fn default() -> &'a Limits {
+            <Limits as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl Limits {
+        pub fn new() -> Limits {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(5);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "max_bytes",
+                |m: &Limits| { &m.max_bytes },
+                |m: &mut Limits| { &mut m.max_bytes },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "max_records",
+                |m: &Limits| { &m.max_records },
+                |m: &mut Limits| { &mut m.max_records },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "max_duration_secs",
+                |m: &Limits| { &m.max_duration_secs },
+                |m: &mut Limits| { &mut m.max_duration_secs },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "mode",
+                |m: &Limits| { &m.mode },
+                |m: &mut Limits| { &mut m.mode },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "max_segments",
+                |m: &Limits| { &m.max_segments },
+                |m: &mut Limits| { &mut m.max_segments },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Limits>(
+                "Capture.Limits",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for Limits {
+        const NAME: &'static str = "Limits";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    8 => {
+                        self.max_bytes = is.read_int64()?;
+                    },
+                    16 => {
+                        self.max_records = is.read_int32()?;
+                    },
+                    24 => {
+                        self.max_duration_secs = is.read_int32()?;
+                    },
+                    32 => {
+                        self.mode = is.read_enum_or_unknown()?;
+                    },
+                    40 => {
+                        self.max_segments = is.read_int32()?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if self.max_bytes != 0 {
+                my_size += ::protobuf::rt::int64_size(1, self.max_bytes);
+            }
+            if self.max_records != 0 {
+                my_size += ::protobuf::rt::int32_size(2, self.max_records);
+            }
+            if self.max_duration_secs != 0 {
+                my_size += ::protobuf::rt::int32_size(3, self.max_duration_secs);
+            }
+            if self.mode != ::protobuf::EnumOrUnknown::new(Mode::STOP) {
+                my_size += ::protobuf::rt::int32_size(4, self.mode.value());
+            }
+            if self.max_segments != 0 {
+                my_size += ::protobuf::rt::int32_size(5, self.max_segments);
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if self.max_bytes != 0 {
+                os.write_int64(1, self.max_bytes)?;
+            }
+            if self.max_records != 0 {
+                os.write_int32(2, self.max_records)?;
+            }
+            if self.max_duration_secs != 0 {
+                os.write_int32(3, self.max_duration_secs)?;
+            }
+            if self.mode != ::protobuf::EnumOrUnknown::new(Mode::STOP) {
+                os.write_enum(4, ::protobuf::EnumOrUnknown::value(&self.mode))?;
+            }
+            if self.max_segments != 0 {
+                os.write_int32(5, self.max_segments)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Limits {
+            Limits::new()
+        }
+
+        fn clear(&mut self) {
+            self.max_bytes = 0;
+            self.max_records = 0;
+            self.max_duration_secs = 0;
+            self.mode = ::protobuf::EnumOrUnknown::new(Mode::STOP);
+            self.max_segments = 0;
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static Limits {
+            static instance: Limits = Limits {
+                max_bytes: 0,
+                max_records: 0,
+                max_duration_secs: 0,
+                mode: ::protobuf::EnumOrUnknown::from_i32(0),
+                max_segments: 0,
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for Limits {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("Capture.Limits").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for Limits {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for Limits {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:netsim.model.Capture.Filter)
+    pub struct Filter {
+        // message fields
+        // @@protoc_insertion_point(field:netsim.model.Capture.Filter.packet_types)
+        pub packet_types: ::std::vec::Vec<u32>,
+        // @@protoc_insertion_point(field:netsim.model.Capture.Filter.opcodes)
+        pub opcodes: ::std::vec::Vec<u32>,
+        // @@protoc_insertion_point(field:netsim.model.Capture.Filter.event_codes)
+        pub event_codes: ::std::vec::Vec<u32>,
+        // @@protoc_insertion_point(field:netsim.model.Capture.Filter.snap_length)
+        pub snap_length: u32,
+        // special fields
+        // @@protoc_insertion_point(special_field:netsim.model.Capture.Filter.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a Filter {
+        fn default() -> &'a Filter {
+            <Filter as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl Filter {
+        pub fn new() -> Filter {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(4);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+                "packet_types",
+                |m: &Filter| { &m.packet_types },
+                |m: &mut Filter| { &mut m.packet_types },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+                "opcodes",
+                |m: &Filter| { &m.opcodes },
+                |m: &mut Filter| { &mut m.opcodes },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+                "event_codes",
+                |m: &Filter| { &m.event_codes },
+                |m: &mut Filter| { &mut m.event_codes },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "snap_length",
+                |m: &Filter| { &m.snap_length },
+                |m: &mut Filter| { &mut m.snap_length },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Filter>(
+                "Capture.Filter",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for Filter {
+        const NAME: &'static str = "Filter";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        is.read_repeated_packed_uint32_into(&mut self.packet_types)?;
+                    },
+                    8 => {
+                        self.packet_types.push(is.read_uint32()?);
+                    },
+                    18 => {
+                        is.read_repeated_packed_uint32_into(&mut self.opcodes)?;
+                    },
+                    16 => {
+                        self.opcodes.push(is.read_uint32()?);
+                    },
+                    26 => {
+                        is.read_repeated_packed_uint32_into(&mut self.event_codes)?;
+                    },
+                    24 => {
+                        self.event_codes.push(is.read_uint32()?);
+                    },
+                    32 => {
+                        self.snap_length = is.read_uint32()?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            for value in &self.packet_types {
+                my_size += ::protobuf::rt::uint32_size(1, *value);
+            };
+            for value in &self.opcodes {
+                my_size += ::protobuf::rt::uint32_size(2, *value);
+            };
+            for value in &self.event_codes {
+                my_size += ::protobuf::rt::uint32_size(3, *value);
+            };
+            if self.snap_length != 0 {
+                my_size += ::protobuf::rt::uint32_size(4, self.snap_length);
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            for v in &self.packet_types {
+                os.write_uint32(1, *v)?;
+            };
+            for v in &self.opcodes {
+                os.write_uint32(2, *v)?;
+            };
+            for v in &self.event_codes {
+                os.write_uint32(3, *v)?;
+            };
+            if self.snap_length != 0 {
+                os.write_uint32(4, self.snap_length)?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> Filter {
+            Filter::new()
+        }
+
+        fn clear(&mut self) {
+            self.packet_types.clear();
+            self.opcodes.clear();
+            self.event_codes.clear();
+            self.snap_length = 0;
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static Filter {
+            static instance: Filter = Filter {
+                packet_types: ::std::vec::Vec::new(),
+                opcodes: ::std::vec::Vec::new(),
+                event_codes: ::std::vec::Vec::new(),
+                snap_length: 0,
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for Filter {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("Capture.Filter").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for Filter {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for Filter {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:netsim.model.Capture.Format)
+    pub enum Format {
+        // @@protoc_insertion_point(enum_value:netsim.model.Capture.Format.PCAP)
+        PCAP = 0,
+        // @@protoc_insertion_point(enum_value:netsim.model.Capture.Format.PCAPNG)
+        PCAPNG = 1,
+    }
+
+    impl ::protobuf::Enum for Format {
+        const NAME: &'static str = "Format";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Format> {
+            match value {
+                0 => ::std::option::Option::Some(Format::PCAP),
+                1 => ::std::option::Option::Some(Format::PCAPNG),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Format] = &[
+            Format::PCAP,
+            Format::PCAPNG,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Format {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("Capture.Format").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Format {
+        fn default() -> Self {
+            Format::PCAP
+        }
+    }
+
+    impl Format {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Format>("Capture.Format")
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:netsim.model.Capture.Mode)
+    pub enum Mode {
+        // @@protoc_insertion_point(enum_value:netsim.model.Capture.Mode.STOP)
+        STOP = 0,
+        // @@protoc_insertion_point(enum_value:netsim.model.Capture.Mode.RING_BUFFER)
+        RING_BUFFER = 1,
+        // @@protoc_insertion_point(enum_value:netsim.model.Capture.Mode.ROTATE)
+        ROTATE = 2,
+    }
+
+    impl ::protobuf::Enum for Mode {
+        const NAME: &'static str = "Mode";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Mode> {
+            match value {
+                0 => ::std::option::Option::Some(Mode::STOP),
+                1 => ::std::option::Option::Some(Mode::RING_BUFFER),
+                2 => ::std::option::Option::Some(Mode::ROTATE),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Mode] = &[
+            Mode::STOP,
+            Mode::RING_BUFFER,
+            Mode::ROTATE,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Mode {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("Capture.Mode").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Mode {
+        fn default() -> Self {
+            Mode::STOP
+        }
+    }
+
+    impl Mode {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Mode>("Capture.Mode")
+        }
+    }
+}
+
 #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
 // @@protoc_insertion_point(enum:netsim.model.PhyKind)
 pub enum PhyKind {
@@ -1836,7 +3750,7 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     (\x02R\x01x\x12\x0c\n\x01y\x18\x02\x20\x01(\x02R\x01y\x12\x0c\n\x01z\x18\
     \x03\x20\x01(\x02R\x01z\"I\n\x0bOrientation\x12\x10\n\x03yaw\x18\x01\x20\
     \x01(\x02R\x03yaw\x12\x14\n\x05pitch\x18\x02\x20\x01(\x02R\x05pitch\x12\
-    \x12\n\x04roll\x18\x03\x20\x01(\x02R\x04roll\"\xdd\x04\n\x04Chip\x12+\n\
+    \x12\n\x04roll\x18\x03\x20\x01(\x02R\x04roll\"\xa1\x06\n\x04Chip\x12+\n\
     \x04kind\x18\x01\x20\x01(\x0e2\x17.netsim.common.ChipKindR\x04kind\x12\
     \x0e\n\x02id\x18\x02\x20\x01(\x05R\x02id\x12\x12\n\x04name\x18\x03\x20\
     \x01(\tR\x04name\x12\"\n\x0cmanufacturer\x18\x04\x20\x01(\tR\x0cmanufact\
@@ -1845,31 +3759,79 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     .\n\x02bt\x18\x07\x20\x01(\x0b2\x1c.netsim.model.Chip.BluetoothH\0R\x02b\
     t\x12,\n\x03uwb\x18\x08\x20\x01(\x0b2\x18.netsim.model.Chip.RadioH\0R\
     \x03uwb\x12.\n\x04wifi\x18\t\x20\x01(\x0b2\x18.netsim.model.Chip.RadioH\
-    \0R\x04wifi\x1a~\n\x05Radio\x12)\n\x05state\x18\x01\x20\x01(\x0e2\x13.ne\
-    tsim.model.StateR\x05state\x12\x14\n\x05range\x18\x02\x20\x01(\x02R\x05r\
-    ange\x12\x19\n\x08tx_count\x18\x03\x20\x01(\x05R\x07txCount\x12\x19\n\
-    \x08rx_count\x18\x04\x20\x01(\x05R\x07rxCount\x1ax\n\tBluetooth\x127\n\n\
-    low_energy\x18\x01\x20\x01(\x0b2\x18.netsim.model.Chip.RadioR\tlowEnergy\
-    \x122\n\x07classic\x18\x02\x20\x01(\x0b2\x18.netsim.model.Chip.RadioR\
-    \x07classicB\x06\n\x04chip\"\xe1\x01\n\x06Device\x12\x0e\n\x02id\x18\x01\
-    \x20\x01(\x05R\x02id\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\
-    \x18\n\x07visible\x18\x03\x20\x01(\x08R\x07visible\x122\n\x08position\
-    \x18\x04\x20\x01(\x0b2\x16.netsim.model.PositionR\x08position\x12;\n\x0b\
-    orientation\x18\x05\x20\x01(\x0b2\x19.netsim.model.OrientationR\x0borien\
-    tation\x12(\n\x05chips\x18\x06\x20\x03(\x0b2\x12.netsim.model.ChipR\x05c\
-    hips\"7\n\x05Scene\x12.\n\x07devices\x18\x01\x20\x03(\x0b2\x14.netsim.mo\
-    del.DeviceR\x07devices\"\x99\x02\n\x07Capture\x12\x0e\n\x02id\x18\x01\
-    \x20\x01(\x05R\x02id\x124\n\tchip_kind\x18\x02\x20\x01(\x0e2\x17.netsim.\
-    common.ChipKindR\x08chipKind\x12\x1f\n\x0bdevice_name\x18\x03\x20\x01(\t\
-    R\ndeviceName\x12)\n\x05state\x18\x04\x20\x01(\x0e2\x13.netsim.model.Sta\
-    teR\x05state\x12\x12\n\x04size\x18\x05\x20\x01(\x05R\x04size\x12\x18\n\
-    \x07records\x18\x06\x20\x01(\x05R\x07records\x128\n\ttimestamp\x18\x07\
-    \x20\x01(\x0b2\x1a.google.protobuf.TimestampR\ttimestamp\x12\x14\n\x05va\
-    lid\x18\x08\x20\x01(\x08R\x05valid*e\n\x07PhyKind\x12\x08\n\x04NONE\x10\
-    \0\x12\x15\n\x11BLUETOOTH_CLASSIC\x10\x01\x12\x18\n\x14BLUETOOTH_LOW_ENE\
-    RGY\x10\x02\x12\x08\n\x04WIFI\x10\x03\x12\x07\n\x03UWB\x10\x04\x12\x0c\n\
-    \x08WIFI_RTT\x10\x05*%\n\x05State\x12\x0b\n\x07UNKNOWN\x10\0\x12\x06\n\
-    \x02ON\x10\x01\x12\x07\n\x03OFF\x10\x02b\x06proto3\
+    \0R\x04wifi\x12;\n\x07antenna\x18\n\x20\x01(\x0e2!.netsim.model.Chip.Ant\
+    ennaPatternR\x07antenna\x124\n\x07traffic\x18\x0b\x20\x01(\x0b2\x1a.nets\
+    im.model.TrafficStatsR\x07traffic\x1a~\n\x05Radio\x12)\n\x05state\x18\
+    \x01\x20\x01(\x0e2\x13.netsim.model.StateR\x05state\x12\x14\n\x05range\
+    \x18\x02\x20\x01(\x02R\x05range\x12\x19\n\x08tx_count\x18\x03\x20\x01(\
+    \x05R\x07txCount\x12\x19\n\x08rx_count\x18\x04\x20\x01(\x05R\x07rxCount\
+    \x1ax\n\tBluetooth\x127\n\nlow_energy\x18\x01\x20\x01(\x0b2\x18.netsim.m\
+    odel.Chip.RadioR\tlowEnergy\x122\n\x07classic\x18\x02\x20\x01(\x0b2\x18.\
+    netsim.model.Chip.RadioR\x07classic\"O\n\x0eAntennaPattern\x12\x17\n\x13\
+    ANTENNA_UNSPECIFIED\x10\0\x12\r\n\tISOTROPIC\x10\x01\x12\n\n\x06DIPOLE\
+    \x10\x02\x12\t\n\x05PATCH\x10\x03B\x06\n\x04chip\"\xe3\x02\n\x0cTrafficS\
+    tats\x124\n\x02tx\x18\x01\x20\x01(\x0b2$.netsim.model.TrafficStats.Direc\
+    tionR\x02tx\x124\n\x02rx\x18\x02\x20\x01(\x0b2$.netsim.model.TrafficStat\
+    s.DirectionR\x02rx\x1as\n\x04Rate\x12\x1f\n\x0bwindow_secs\x18\x01\x20\
+    \x01(\x05R\nwindowSecs\x12&\n\x0fpackets_per_sec\x18\x02\x20\x01(\x02R\r\
+    packetsPerSec\x12\"\n\rbytes_per_sec\x18\x03\x20\x01(\x02R\x0bbytesPerSe\
+    c\x1ar\n\tDirection\x12\x18\n\x07packets\x18\x01\x20\x01(\x03R\x07packet\
+    s\x12\x14\n\x05bytes\x18\x02\x20\x01(\x03R\x05bytes\x125\n\x05rates\x18\
+    \x03\x20\x03(\x0b2\x1f.netsim.model.TrafficStats.RateR\x05rates\"q\n\x0c\
+    RadioTraffic\x12+\n\x04kind\x18\x01\x20\x01(\x0e2\x17.netsim.common.Chip\
+    KindR\x04kind\x124\n\x07traffic\x18\x02\x20\x01(\x0b2\x1a.netsim.model.T\
+    rafficStatsR\x07traffic\"\x83\x04\n\x06Device\x12\x0e\n\x02id\x18\x01\
+    \x20\x01(\x05R\x02id\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12-\
+    \n\x07visible\x18\x03\x20\x01(\x0e2\x13.netsim.model.StateR\x07visible\
+    \x122\n\x08position\x18\x04\x20\x01(\x0b2\x16.netsim.model.PositionR\x08\
+    position\x12;\n\x0borientation\x18\x05\x20\x01(\x0b2\x19.netsim.model.Or\
+    ientationR\x0borientation\x12(\n\x05chips\x18\x06\x20\x03(\x0b2\x12.nets\
+    im.model.ChipR\x05chips\x12\x12\n\x04zone\x18\x07\x20\x01(\tR\x04zone\
+    \x129\n\x08behavior\x18\x08\x20\x01(\x0e2\x1d.netsim.model.Device.Behavi\
+    orR\x08behavior\x128\n\x06labels\x18\t\x20\x03(\x0b2\x20.netsim.model.De\
+    vice.LabelsEntryR\x06labels\x1a9\n\x0bLabelsEntry\x12\x10\n\x03key\x18\
+    \x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\
+    \x028\x01\"G\n\x08Behavior\x12\x11\n\rBEHAVIOR_NONE\x10\0\x12\n\n\x06BEA\
+    CON\x10\x01\x12\x10\n\x0cACCESS_POINT\x10\x02\x12\n\n\x06ANCHOR\x10\x03\
+    \"X\n\x04Zone\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x20\n\
+    \x0battenuation\x18\x02\x20\x01(\x02R\x0battenuation\x12\x1a\n\x08isolat\
+    ed\x18\x03\x20\x01(\x08R\x08isolated\"\x95\x01\n\x05Scene\x12.\n\x07devi\
+    ces\x18\x01\x20\x03(\x0b2\x14.netsim.model.DeviceR\x07devices\x12(\n\x05\
+    zones\x18\x02\x20\x03(\x0b2\x12.netsim.model.ZoneR\x05zones\x122\n\x06ra\
+    dios\x18\x03\x20\x03(\x0b2\x1a.netsim.model.RadioTrafficR\x06radios\"\
+    \xa5\x01\n\x08Waypoint\x122\n\x08position\x18\x01\x20\x01(\x0b2\x16.nets\
+    im.model.PositionR\x08position\x12;\n\x0borientation\x18\x02\x20\x01(\
+    \x0b2\x19.netsim.model.OrientationR\x0borientation\x12\x12\n\x04time\x18\
+    \x03\x20\x01(\x02R\x04time\x12\x14\n\x05speed\x18\x04\x20\x01(\x02R\x05s\
+    peed\"V\n\nTrajectory\x124\n\twaypoints\x18\x01\x20\x03(\x0b2\x16.netsim\
+    .model.WaypointR\twaypoints\x12\x12\n\x04loop\x18\x02\x20\x01(\x08R\x04l\
+    oop\"\xf8\x06\n\x07Capture\x12\x0e\n\x02id\x18\x01\x20\x01(\x05R\x02id\
+    \x124\n\tchip_kind\x18\x02\x20\x01(\x0e2\x17.netsim.common.ChipKindR\x08\
+    chipKind\x12\x1f\n\x0bdevice_name\x18\x03\x20\x01(\tR\ndeviceName\x12)\n\
+    \x05state\x18\x04\x20\x01(\x0e2\x13.netsim.model.StateR\x05state\x12\x12\
+    \n\x04size\x18\x05\x20\x01(\x05R\x04size\x12\x18\n\x07records\x18\x06\
+    \x20\x01(\x05R\x07records\x128\n\ttimestamp\x18\x07\x20\x01(\x0b2\x1a.go\
+    ogle.protobuf.TimestampR\ttimestamp\x12\x14\n\x05valid\x18\x08\x20\x01(\
+    \x08R\x05valid\x124\n\x06format\x18\t\x20\x01(\x0e2\x1c.netsim.model.Cap\
+    ture.FormatR\x06format\x124\n\x06limits\x18\n\x20\x01(\x0b2\x1c.netsim.m\
+    odel.Capture.LimitsR\x06limits\x12\x1a\n\x08segments\x18\x0b\x20\x01(\
+    \x05R\x08segments\x124\n\x06filter\x18\x0c\x20\x01(\x0b2\x1c.netsim.mode\
+    l.Capture.FilterR\x06filter\x1a\xc5\x01\n\x06Limits\x12\x1b\n\tmax_bytes\
+    \x18\x01\x20\x01(\x03R\x08maxBytes\x12\x1f\n\x0bmax_records\x18\x02\x20\
+    \x01(\x05R\nmaxRecords\x12*\n\x11max_duration_secs\x18\x03\x20\x01(\x05R\
+    \x0fmaxDurationSecs\x12.\n\x04mode\x18\x04\x20\x01(\x0e2\x1a.netsim.mode\
+    l.Capture.ModeR\x04mode\x12!\n\x0cmax_segments\x18\x05\x20\x01(\x05R\x0b\
+    maxSegments\x1a\x87\x01\n\x06Filter\x12!\n\x0cpacket_types\x18\x01\x20\
+    \x03(\rR\x0bpacketTypes\x12\x18\n\x07opcodes\x18\x02\x20\x03(\rR\x07opco\
+    des\x12\x1f\n\x0bevent_codes\x18\x03\x20\x03(\rR\neventCodes\x12\x1f\n\
+    \x0bsnap_length\x18\x04\x20\x01(\rR\nsnapLength\"\x1e\n\x06Format\x12\
+    \x08\n\x04PCAP\x10\0\x12\n\n\x06PCAPNG\x10\x01\"-\n\x04Mode\x12\x08\n\
+    \x04STOP\x10\0\x12\x0f\n\x0bRING_BUFFER\x10\x01\x12\n\n\x06ROTATE\x10\
+    \x02*e\n\x07PhyKind\x12\x08\n\x04NONE\x10\0\x12\x15\n\x11BLUETOOTH_CLASS\
+    IC\x10\x01\x12\x18\n\x14BLUETOOTH_LOW_ENERGY\x10\x02\x12\x08\n\x04WIFI\
+    \x10\x03\x12\x07\n\x03UWB\x10\x04\x12\x0c\n\x08WIFI_RTT\x10\x05*%\n\x05S\
+    tate\x12\x0b\n\x07UNKNOWN\x10\0\x12\x06\n\x02ON\x10\x01\x12\x07\n\x03OFF\
+    \x10\x02b\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -1889,18 +3851,31 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             let mut deps = ::std::vec::Vec::with_capacity(2);
             deps.push(super::common::file_descriptor().clone());
             deps.push(::protobuf::well_known_types::timestamp::file_descriptor().clone());
-            let mut messages = ::std::vec::Vec::with_capacity(8);
+            let mut messages = ::std::vec::Vec::with_capacity(17);
             messages.push(Position::generated_message_descriptor_data());
             messages.push(Orientation::generated_message_descriptor_data());
             messages.push(Chip::generated_message_descriptor_data());
+            messages.push(TrafficStats::generated_message_descriptor_data());
+            messages.push(RadioTraffic::generated_message_descriptor_data());
             messages.push(Device::generated_message_descriptor_data());
+            messages.push(Zone::generated_message_descriptor_data());
             messages.push(Scene::generated_message_descriptor_data());
+            messages.push(Waypoint::generated_message_descriptor_data());
+            messages.push(Trajectory::generated_message_descriptor_data());
             messages.push(Capture::generated_message_descriptor_data());
             messages.push(chip::Radio::generated_message_descriptor_data());
             messages.push(chip::Bluetooth::generated_message_descriptor_data());
-            let mut enums = ::std::vec::Vec::with_capacity(2);
+            messages.push(traffic_stats::Rate::generated_message_descriptor_data());
+            messages.push(traffic_stats::Direction::generated_message_descriptor_data());
+            messages.push(capture::Limits::generated_message_descriptor_data());
+            messages.push(capture::Filter::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(6);
             enums.push(PhyKind::generated_enum_descriptor_data());
             enums.push(State::generated_enum_descriptor_data());
+            enums.push(chip::AntennaPattern::generated_enum_descriptor_data());
+            enums.push(device::Behavior::generated_enum_descriptor_data());
+            enums.push(capture::Format::generated_enum_descriptor_data());
+            enums.push(capture::Mode::generated_enum_descriptor_data());
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
                 deps,
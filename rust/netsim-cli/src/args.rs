@@ -18,6 +18,10 @@ use frontend_proto::common::ChipKind;
 use frontend_proto::frontend;
 use frontend_proto::frontend::patch_capture_request::PatchCapture as PatchCaptureProto;
 use frontend_proto::model;
+use frontend_proto::model::capture::{
+    Filter as CaptureFilterProto, Format as CaptureFormatProto, Limits as CaptureLimitsProto,
+    Mode as CaptureModeProto,
+};
 use frontend_proto::model::chip::{Bluetooth as Chip_Bluetooth, Radio as Chip_Radio};
 use frontend_proto::model::{Chip, State};
 use frontend_proto::model::{Device, Position};
@@ -47,6 +51,8 @@ pub enum Command {
     Move(Move),
     /// Display device(s) information
     Devices(Devices),
+    /// Display the distance and estimated RSSI between each pair of devices
+    Distances,
     /// Reset Netsim device scene
     Reset,
     /// Open netsim Web UI
@@ -112,7 +118,21 @@ impl Command {
                 result.device = Some(device).into();
                 result.write_to_bytes().unwrap()
             }
-            Command::Devices(_) => Vec::new(),
+            Command::Devices(cmd) => {
+                let mut result = frontend::GetDevicesRequest::new();
+                result.label_selector = cmd.labels.clone().unwrap_or_default();
+                result.name_pattern = cmd.name.clone().unwrap_or_default();
+                if let Some(kind) = cmd.kind {
+                    result.chip_kind = match kind {
+                        DeviceChipKind::Bluetooth => ChipKind::BLUETOOTH,
+                        DeviceChipKind::Wifi => ChipKind::WIFI,
+                        DeviceChipKind::Uwb => ChipKind::UWB,
+                    }
+                    .into();
+                }
+                result.write_to_bytes().unwrap()
+            }
+            Command::Distances => Vec::new(),
             Command::Reset => Vec::new(),
             Command::Gui => {
                 unimplemented!("get_request_bytes is not implemented for Gui Command.");
@@ -148,6 +168,30 @@ impl Command {
                     };
                     let mut patch_capture = PatchCaptureProto::new();
                     patch_capture.state = capture_state.into();
+                    patch_capture.format =
+                        cmd.format.map(CaptureFormat::to_proto).unwrap_or_default().into();
+                    patch_capture.limits = Some(CaptureLimitsProto {
+                        max_bytes: cmd.max_bytes,
+                        max_records: cmd.max_records,
+                        max_duration_secs: cmd.max_duration,
+                        mode: match cmd.mode {
+                            CaptureMode::Stop => CaptureModeProto::STOP,
+                            CaptureMode::Ring => CaptureModeProto::RING_BUFFER,
+                            CaptureMode::Rotate => CaptureModeProto::ROTATE,
+                        }
+                        .into(),
+                        max_segments: cmd.max_segments,
+                        ..Default::default()
+                    })
+                    .into();
+                    patch_capture.filter = Some(CaptureFilterProto {
+                        packet_types: cmd.packet_types.iter().map(|kind| *kind as u32).collect(),
+                        opcodes: cmd.opcodes.clone(),
+                        event_codes: cmd.event_codes.clone(),
+                        snap_length: cmd.snap_length,
+                        ..Default::default()
+                    })
+                    .into();
                     result.patch = Some(patch_capture).into();
                     reqs.push(result.write_to_bytes().unwrap())
                 }
@@ -160,6 +204,15 @@ impl Command {
                 for capture in &filtered_captures {
                     let mut result = frontend::GetCaptureRequest::new();
                     result.id = capture.id;
+                    result.follow = cmd.follow;
+                    // Download in the recorded format unless another is given.
+                    let format = match cmd.format {
+                        Some(format) => {
+                            result.format = format.to_string();
+                            format.to_proto()
+                        }
+                        None => capture.format.enum_value_or_default(),
+                    };
                     reqs.push(result.write_to_bytes().unwrap());
                     let time_display = TimeDisplay::new(
                         capture.timestamp.get_or_default().seconds,
@@ -172,6 +225,10 @@ impl Command {
                         Self::chip_kind_to_string(capture.chip_kind.enum_value_or_default()),
                         time_display.utc_display()
                     ));
+                    cmd.extensions.push(match format {
+                        CaptureFormatProto::PCAP => "pcap",
+                        CaptureFormatProto::PCAPNG => "pcapng",
+                    });
                 }
                 reqs
             }
@@ -258,6 +315,65 @@ pub struct Devices {
     /// Continuously print device(s) information every second
     #[arg(short, long)]
     pub continuous: bool,
+    /// Only devices with the labels, e.g. "team=wear,role" or "team!=wear"
+    #[arg(short, long)]
+    pub labels: Option<String>,
+    /// Only devices with a name matching the pattern, where '*' matches any characters
+    #[arg(short, long)]
+    pub name: Option<String>,
+    /// Only devices with a chip of the kind
+    #[arg(short, long, value_enum, ignore_case = true)]
+    pub kind: Option<DeviceChipKind>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum DeviceChipKind {
+    Bluetooth,
+    Wifi,
+    Uwb,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CaptureFormat {
+    Pcap,
+    Pcapng,
+}
+
+impl CaptureFormat {
+    fn to_proto(self) -> CaptureFormatProto {
+        match self {
+            CaptureFormat::Pcap => CaptureFormatProto::PCAP,
+            CaptureFormat::Pcapng => CaptureFormatProto::PCAPNG,
+        }
+    }
+}
+
+impl fmt::Display for CaptureFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CaptureFormat::Pcap => write!(f, "pcap"),
+            CaptureFormat::Pcapng => write!(f, "pcapng"),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CaptureMode {
+    /// Stop the capture at a limit
+    Stop,
+    /// Keep the most recent records, about the limits
+    Ring,
+    /// Start a new numbered segment at a limit
+    Rotate,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum HciPacketType {
+    Command = 1,
+    Acl = 2,
+    Sco = 3,
+    Event = 4,
+    Iso = 5,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
@@ -289,6 +405,45 @@ pub struct PatchCapture {
     pub state: OnOffState,
     /// Optional strings of pattern for captures to patch. Possible filter fields include Capture ID, Device Name, and Chip Kind
     pub patterns: Vec<String>,
+    /// File format of the started capture, pcap by default
+    #[arg(short, long, value_enum, ignore_case = true)]
+    pub format: Option<CaptureFormat>,
+    /// Maximum bytes of the capture, or of each segment when rotating, 0 for no limit
+    #[arg(long, default_value_t = 0)]
+    pub max_bytes: i64,
+    /// Maximum records of the capture, or of each segment when rotating, 0 for no limit
+    #[arg(long, default_value_t = 0)]
+    pub max_records: i32,
+    /// Maximum duration in seconds of the capture, or of each segment when rotating, 0 for no limit
+    #[arg(long, default_value_t = 0)]
+    pub max_duration: i32,
+    /// What the capture does when it reaches a limit
+    #[arg(short, long, value_enum, ignore_case = true, default_value_t = CaptureMode::Stop)]
+    pub mode: CaptureMode,
+    /// Number of segments kept when rotating, 0 to keep all
+    #[arg(long, default_value_t = 0)]
+    pub max_segments: i32,
+    /// HCI packet types recorded by Bluetooth captures, all by default
+    #[arg(long, value_enum, ignore_case = true, value_delimiter = ',')]
+    pub packet_types: Vec<HciPacketType>,
+    /// Opcodes of the HCI commands recorded, e.g. 0x200a, all by default
+    #[arg(long, value_parser = parse_code, value_delimiter = ',')]
+    pub opcodes: Vec<u32>,
+    /// Codes of the HCI events recorded, e.g. 0x0e, all by default
+    #[arg(long, value_parser = parse_code, value_delimiter = ',')]
+    pub event_codes: Vec<u32>,
+    /// Maximum bytes of each record, longer records are truncated, 0 for no limit
+    #[arg(long, default_value_t = 0)]
+    pub snap_length: u32,
+}
+
+// Parses an opcode or event code, in hexadecimal with a 0x prefix or in decimal.
+fn parse_code(code: &str) -> Result<u32, String> {
+    match code.strip_prefix("0x").or_else(|| code.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => code.parse(),
+    }
+    .map_err(|err| format!("Invalid code {code}: {err}"))
 }
 
 #[derive(Debug, Args)]
@@ -298,6 +453,14 @@ pub struct GetCapture {
     /// Directory to store downloaded capture(s)
     #[arg(short = 'o', long)]
     pub location: Option<String>,
+    /// File format of the downloaded capture(s), the recorded format by default
+    #[arg(short, long, value_enum, ignore_case = true)]
+    pub format: Option<CaptureFormat>,
+    /// Stream a recording capture to stdout until it stops, e.g. netsim pcap get --follow | wireshark -k -i -
+    #[arg(long, conflicts_with = "location")]
+    pub follow: bool,
     #[arg(skip)]
     pub filenames: Vec<String>,
+    #[arg(skip)]
+    pub extensions: Vec<&'static str>,
 }
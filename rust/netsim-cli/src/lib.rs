@@ -29,7 +29,7 @@ use clap::Parser;
 use cxx::UniquePtr;
 use frontend_client_cxx::ffi::{new_frontend_client, ClientResult, FrontendClient, GrpcMethod};
 use frontend_client_cxx::ClientResponseReader;
-use pcap_handler::CaptureHandler;
+use pcap_handler::{CaptureHandler, FollowHandler};
 
 // helper function to process streaming Grpc request
 fn perform_streaming_request(
@@ -37,18 +37,22 @@ fn perform_streaming_request(
     cmd: &GetCapture,
     req: &BinaryProtobuf,
     filename: &str,
+    extension: &str,
 ) -> UniquePtr<ClientResult> {
+    if cmd.follow {
+        return client.get_capture(req, &ClientResponseReader { handler: Box::new(FollowHandler) });
+    }
     let dir = if cmd.location.is_some() {
         PathBuf::from(cmd.location.to_owned().unwrap())
     } else {
         env::current_dir().unwrap()
     };
     // Find next available file name
-    let mut output_file = dir.join(filename.to_string() + ".pcap");
+    let mut output_file = dir.join(format!("{}.{}", filename, extension));
     let mut idx = 0;
     while output_file.exists() {
         idx += 1;
-        output_file = dir.join(format!("{}_{}.pcap", filename, idx));
+        output_file = dir.join(format!("{}_{}.{}", filename, idx, extension));
     }
     client.get_capture(
         req,
@@ -77,6 +81,15 @@ fn perform_command(
         }
         _ => vec![command.get_request_bytes()],
     };
+    // The followed capture is the only stream written to stdout
+    if let args::Command::Pcap(args::Pcap::Get(cmd)) = command {
+        if cmd.follow && requests.len() != 1 {
+            return Err(format!(
+                "Exactly one capture can be followed, {} captures match the patterns.",
+                requests.len()
+            ));
+        }
+    }
 
     // Process each request
     for (i, req) in requests.iter().enumerate() {
@@ -88,7 +101,7 @@ fn perform_command(
             },
             // Get Pcap use streaming gRPC reader request
             args::Command::Pcap(args::Pcap::Get(ref cmd)) => {
-                perform_streaming_request(&client, cmd, req, &cmd.filenames[i])
+                perform_streaming_request(&client, cmd, req, &cmd.filenames[i], cmd.extensions[i])
             }
             // All other commands use a single gRPC call
             _ => client.send_grpc(&grpc_method, req),
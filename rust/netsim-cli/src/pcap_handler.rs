@@ -15,7 +15,7 @@
 use frontend_client_cxx::ClientResponseReadable;
 use std::fs::File;
 /// Implements handler for pcap operations
-use std::io::Write;
+use std::io::{self, Write};
 use std::path::PathBuf;
 
 pub struct CaptureHandler {
@@ -40,3 +40,21 @@ impl ClientResponseReadable for CaptureHandler {
         );
     }
 }
+
+/// Implements handler for following a capture on stdout
+pub struct FollowHandler;
+
+impl ClientResponseReadable for FollowHandler {
+    // function to handle writing each chunk to stdout as it arrives
+    fn handle_chunk(&self, chunk: &[u8]) {
+        let mut stdout = io::stdout().lock();
+        stdout
+            .write_all(chunk)
+            .and_then(|_| stdout.flush())
+            .unwrap_or_else(|_| panic!("Unable to write to stdout"));
+    }
+    // function to handle error response
+    fn handle_error(&self, error_code: u32, error_message: &str) {
+        eprintln!("Handling error code: {}, msg: {}, on stdout", error_code, error_message);
+    }
+}
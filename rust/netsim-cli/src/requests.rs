@@ -23,6 +23,7 @@ impl args::Command {
             Command::Radio(_) => GrpcMethod::PatchDevice,
             Command::Move(_) => GrpcMethod::PatchDevice,
             Command::Devices(_) => GrpcMethod::GetDevices,
+            Command::Distances => GrpcMethod::GetDistances,
             Command::Reset => GrpcMethod::Reset,
             Command::Pcap(cmd) => match cmd {
                 args::Pcap::List(_) => GrpcMethod::ListCapture,
@@ -235,6 +236,24 @@ mod tests {
         test_command("netsim-cli devices", GrpcMethod::GetDevices, Vec::new())
     }
 
+    #[test]
+    fn test_devices_filtered() {
+        let mut result = frontend::GetDevicesRequest::new();
+        result.label_selector = "team=wear,role".to_owned();
+        result.name_pattern = "emu*".to_owned();
+        result.chip_kind = ChipKind::UWB.into();
+        test_command(
+            "netsim-cli devices --labels team=wear,role --name emu* --kind uwb",
+            GrpcMethod::GetDevices,
+            result.write_to_bytes().unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_distances() {
+        test_command("netsim-cli distances", GrpcMethod::GetDistances, Vec::new())
+    }
+
     #[test]
     fn test_reset() {
         test_command("netsim-cli reset", GrpcMethod::Reset, Vec::new())
@@ -17,8 +17,8 @@ use std::cmp::max;
 use crate::args::{self, Command, OnOffState, Pcap};
 use frontend_proto::{
     common::ChipKind,
-    frontend::{GetDevicesResponse, ListCaptureResponse, VersionResponse},
-    model::{self, chip::Chip as Chip_oneof_chip, State},
+    frontend::{GetDevicesResponse, GetDistancesResponse, ListCaptureResponse, VersionResponse},
+    model::{self, chip::Chip as Chip_oneof_chip, PhyKind, State},
 };
 use protobuf::Message;
 
@@ -56,6 +56,10 @@ impl args::Command {
                     verbose,
                 );
             }
+            Command::Distances => Self::print_distances_response(
+                GetDistancesResponse::parse_from_bytes(response).unwrap(),
+                verbose,
+            ),
             Command::Reset => {
                 if verbose {
                     println!("All devices have been reset.");
@@ -74,8 +78,11 @@ impl args::Command {
                     );
                 }
             }
-            Command::Pcap(Pcap::Get(_)) => {
-                if verbose {
+            Command::Pcap(Pcap::Get(cmd)) => {
+                // The followed capture was written to stdout
+                if verbose && cmd.follow {
+                    eprintln!("Capture stopped.");
+                } else if verbose {
                     println!("Successfully downloaded Pcap.");
                 }
             }
@@ -105,6 +112,12 @@ impl args::Command {
                     "{:name_width$}  position: {:.pos_prec$}, {:.pos_prec$}, {:.pos_prec$}",
                     device.name, pos.x, pos.y, pos.z
                 );
+                if !device.labels.is_empty() {
+                    let mut labels: Vec<String> =
+                        device.labels.iter().map(|(key, value)| format!("{key}={value}")).collect();
+                    labels.sort();
+                    println!("{:chip_indent$}labels: {}", "", labels.join(", "));
+                }
                 for chip in &device.chips {
                     match &chip.chip {
                         Some(Chip_oneof_chip::Bt(bt)) => {
@@ -235,6 +248,62 @@ impl args::Command {
     }
 
     /// Helper function to convert frontend_proto::model::State to string for output
+    /// Helper function to format and print GetDistancesResponse
+    fn print_distances_response(response: GetDistancesResponse, verbose: bool) {
+        let name_width = 16;
+        let chip_indent = 2;
+        let radio_width = 9;
+        if verbose {
+            if response.distances.is_empty() {
+                println!("No pairs of devices found.");
+            } else {
+                println!("Distances between devices:");
+            }
+        }
+        for pair in response.distances {
+            print!(
+                "{:name_width$}  {:name_width$}  {:.2} m",
+                pair.device_name, pair.other_device, pair.distance
+            );
+            if !pair.unreachable.is_empty() {
+                print!("  unreachable: {}", pair.unreachable);
+            }
+            if verbose {
+                println!();
+                for rssi in &pair.rssi {
+                    println!(
+                        "{:chip_indent$}{:radio_width$}rssi: {} dBm | tx_power: {} dBm | link_loss: {}",
+                        "",
+                        format!("{}:", Self::radio_to_string(rssi.radio.enum_value_or_default())),
+                        rssi.rssi,
+                        rssi.tx_power,
+                        rssi.link_loss
+                    );
+                }
+            } else {
+                for rssi in &pair.rssi {
+                    print!(
+                        "  {}: {} dBm",
+                        Self::radio_to_string(rssi.radio.enum_value_or_default()),
+                        rssi.rssi
+                    );
+                }
+                println!();
+            }
+        }
+    }
+
+    fn radio_to_string(radio: PhyKind) -> String {
+        match radio {
+            PhyKind::BLUETOOTH_LOW_ENERGY => "ble".to_string(),
+            PhyKind::BLUETOOTH_CLASSIC => "classic".to_string(),
+            PhyKind::WIFI => "wifi".to_string(),
+            PhyKind::WIFI_RTT => "wifi_rtt".to_string(),
+            PhyKind::UWB => "uwb".to_string(),
+            PhyKind::NONE => "unknown".to_string(),
+        }
+    }
+
     fn chip_state_to_string(state: State) -> String {
         match state {
             State::ON => "up".to_string(),
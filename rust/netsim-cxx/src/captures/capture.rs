@@ -17,27 +17,44 @@
 //! CaptureInfo is the internal structure of any Capture that includes
 //! the protobuf structure. CaptureMaps contains mappings of ChipId
 //! and FacadeId to CaptureInfo.
+//!
+//! A capture is written to one or more segment files. It stops when it
+//! reaches one of its limits, or starts a new segment when it rotates.
+//! The packets of a Bluetooth capture may be filtered by their HCI packet
+//! type, command opcode and event code.
 
 use std::collections::btree_map::{Iter, Values};
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::fs::{File, OpenOptions};
-use std::io::Result;
+use std::io::{Error, ErrorKind, Result, Write};
+use std::path::PathBuf;
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender};
 use std::sync::{Arc, Mutex};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use frontend_proto::{
     common::ChipKind,
+    model::capture::{Filter as ProtoFilter, Format, Limits as ProtoLimits, Mode},
     model::{Capture as ProtoCapture, State},
 };
+use netsim_common::util::time_display::TimeDisplay;
 use protobuf::well_known_types::timestamp::Timestamp;
 
-use crate::ffi::get_facade_id;
+use crate::version::get_version;
 
-use super::pcap_util::write_pcap_header;
+use super::pcap_util::{self, write_pcap_header, LinkType, PacketDirection, PCAP_HEADER_LEN};
+use super::pcapng_util::{self, write_pcapng_header};
 
 pub type ChipId = i32;
 pub type FacadeId = i32;
 
+// The HCI packet types of commands and events.
+const HCI_COMMAND: u32 = 1;
+const HCI_EVENT: u32 = 4;
+
+// The records a follower may have pending before it is dropped.
+const FOLLOW_BACKLOG: usize = 1024;
+
 pub struct CaptureInfo {
     facade_id: FacadeId,
     pub file: Option<File>,
@@ -50,6 +67,22 @@ pub struct CaptureInfo {
     pub seconds: i64,
     pub nanos: i32,
     pub valid: bool,
+    pub format: Format,
+    pub limits: ProtoLimits,
+    pub filter: ProtoFilter,
+    // The segments kept, oldest first. The file is the last segment.
+    segments: VecDeque<Segment>,
+    next_segment: u32,
+    // The records are sent to the followers as they are appended.
+    followers: Vec<SyncSender<Vec<u8>>>,
+}
+
+// A file of a capture.
+struct Segment {
+    number: u32,
+    size: usize,
+    records: i32,
+    start: Instant,
 }
 
 // Captures contains a recent copy of all chips and their ChipKind, chip_id,
@@ -65,9 +98,14 @@ pub struct Captures {
 }
 
 impl CaptureInfo {
-    pub fn new(chip_kind: ChipKind, chip_id: ChipId, device_name: String) -> Self {
+    pub fn new(
+        chip_kind: ChipKind,
+        chip_id: ChipId,
+        facade_id: FacadeId,
+        device_name: String,
+    ) -> Self {
         CaptureInfo {
-            facade_id: get_facade_id(chip_id),
+            facade_id,
             id: chip_id,
             chip_kind,
             device_name,
@@ -76,37 +114,285 @@ impl CaptureInfo {
             seconds: 0,
             nanos: 0,
             valid: true,
+            format: Format::PCAP,
+            limits: ProtoLimits::new(),
+            filter: ProtoFilter::new(),
+            segments: VecDeque::new(),
+            next_segment: 0,
+            followers: Vec::new(),
             file: None,
         }
     }
 
-    // Creates a pcap file with headers and store it under temp directory
+    // The file of a segment under the temp directory, the first segment is not numbered
+    // Format: /tmp/netsim-pcaps/{chip_id}-{device_name}-{chip_kind}[-{segment}].{pcap|pcapng}
+    fn segment_path(&self, number: u32) -> PathBuf {
+        let segment = match number {
+            0 => String::new(),
+            number => format!("-{number}"),
+        };
+        let mut filename = std::env::temp_dir();
+        filename.push("netsim-pcaps");
+        filename.push(format!(
+            "{:?}-{:}-{:?}{}.{}",
+            self.id,
+            self.device_name,
+            self.chip_kind,
+            segment,
+            file_extension(self.format)
+        ));
+        filename
+    }
+
+    // The files of the segments kept, oldest first.
+    pub fn file_paths(&self) -> Vec<PathBuf> {
+        self.segments.iter().map(|segment| self.segment_path(segment.number)).collect()
+    }
+
+    // Reads the segments kept as a single file. The pcap segments after
+    // the first are appended without their header and the pcapng
+    // segments are appended as sections.
+    pub fn read(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::with_capacity(self.size);
+        for (index, path) in self.file_paths().into_iter().enumerate() {
+            let segment = std::fs::read(path)?;
+            match (index, self.format) {
+                (0, _) | (_, Format::PCAPNG) => bytes.extend(segment),
+                (_, Format::PCAP) => {
+                    bytes.extend(segment.get(PCAP_HEADER_LEN..).unwrap_or_default())
+                }
+            }
+        }
+        Ok(bytes)
+    }
+
+    pub fn link_type(&self) -> Option<LinkType> {
+        LinkType::from_chip_kind(self.chip_kind)
+    }
+
+    // The name of the chip as a pcapng interface.
+    pub fn interface_name(&self) -> String {
+        format!("{}-{:?}-{}", self.device_name, self.chip_kind, self.id)
+    }
+
+    // The comment of the pcapng section header.
+    pub fn section_comment(&self) -> String {
+        let time_display = TimeDisplay::new(self.seconds, self.nanos as u32);
+        format!("netsim {}, capture started at {}", get_version(), time_display.utc_display())
+    }
+
+    // Creates a pcap or pcapng file with headers and store it under temp directory
+    // The segments of the previous capture are deleted.
     // The lifecycle of the file is NOT tied to the lifecycle of the struct
-    // Format: /tmp/netsim-pcaps/{chip_id}-{device_name}-{chip_kind}.pcap
-    pub fn start_capture(&mut self) -> Result<()> {
+    pub fn start_capture(
+        &mut self,
+        format: Format,
+        limits: ProtoLimits,
+        filter: ProtoFilter,
+    ) -> Result<()> {
         if self.file.is_some() {
             return Ok(());
         }
-        let mut filename = std::env::temp_dir();
-        filename.push("netsim-pcaps");
-        std::fs::create_dir_all(&filename)?;
-        filename.push(format!("{:?}-{:}-{:?}.pcap", self.id, self.device_name, self.chip_kind));
-        let mut file = OpenOptions::new().write(true).truncate(true).create(true).open(filename)?;
-        let size = write_pcap_header(&mut file)?;
+        if self.link_type().is_none() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                format!("Capture of {:?} chips is not supported", self.chip_kind),
+            ));
+        }
+        for path in self.file_paths() {
+            let _ = std::fs::remove_file(path);
+        }
+        self.segments.clear();
+        self.next_segment = 0;
+        self.format = format;
+        self.limits = limits;
+        self.filter = filter;
         let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).expect("Time went backwards");
-        self.size = size;
-        self.records = 0;
         self.seconds = timestamp.as_secs() as i64;
         self.nanos = timestamp.subsec_nanos() as i32;
+        self.size = 0;
+        self.records = 0;
+        self.new_segment()
+    }
+
+    // The header of a file in the format of the capture.
+    fn header(&self) -> Result<Vec<u8>> {
+        let link_type = self.link_type().ok_or(ErrorKind::Unsupported)?;
+        let mut header = Vec::new();
+        match self.format {
+            Format::PCAP => write_pcap_header(&mut header, link_type)?,
+            Format::PCAPNG => write_pcapng_header(
+                &mut header,
+                link_type,
+                &self.interface_name(),
+                &self.section_comment(),
+            )?,
+        };
+        Ok(header)
+    }
+
+    // Creates the file of the next segment with headers and deletes the
+    // oldest segments beyond the segments kept.
+    fn new_segment(&mut self) -> Result<()> {
+        let header = self.header()?;
+        let number = self.next_segment;
+        let filename = self.segment_path(number);
+        std::fs::create_dir_all(filename.parent().unwrap())?;
+        let mut file = OpenOptions::new().write(true).truncate(true).create(true).open(filename)?;
+        file.write_all(&header)?;
+        let size = header.len();
+        self.next_segment += 1;
+        self.segments.push_back(Segment { number, size, records: 0, start: Instant::now() });
+        self.size += size;
         self.file = Some(file);
+        let (_, max_segments) = self.segment_limits();
+        while matches!(max_segments, Some(max_segments) if self.segments.len() > max_segments) {
+            let oldest = self.segments.pop_front().unwrap();
+            let _ = std::fs::remove_file(self.segment_path(oldest.number));
+            self.size -= oldest.size;
+            self.records -= oldest.records;
+        }
         Ok(())
     }
 
+    // The limits of each segment and the number of segments kept, None
+    // to keep all.
+    fn segment_limits(&self) -> (ProtoLimits, Option<usize>) {
+        match self.limits.mode.enum_value_or_default() {
+            Mode::STOP => (self.limits.clone(), Some(1)),
+            Mode::RING_BUFFER => {
+                let limits = ProtoLimits {
+                    max_bytes: (self.limits.max_bytes + 1) / 2,
+                    max_records: (self.limits.max_records + 1) / 2,
+                    max_duration_secs: (self.limits.max_duration_secs + 1) / 2,
+                    ..self.limits.clone()
+                };
+                (limits, Some(2))
+            }
+            Mode::ROTATE => {
+                let max_segments = Some(self.limits.max_segments as usize).filter(|max| *max > 0);
+                (self.limits.clone(), max_segments)
+            }
+        }
+    }
+
+    // Whether a record of the length does not fit in the current segment.
+    fn segment_full(&self, length: usize, now: Instant) -> bool {
+        let (limits, _) = self.segment_limits();
+        let segment = match self.segments.back() {
+            Some(segment) => segment,
+            None => return false,
+        };
+        let duration = Duration::from_secs(limits.max_duration_secs as u64);
+        (limits.max_bytes > 0 && (segment.size + length) as i64 > limits.max_bytes)
+            || (limits.max_records > 0 && segment.records >= limits.max_records)
+            || (limits.max_duration_secs > 0 && now.duration_since(segment.start) >= duration)
+    }
+
+    // Whether the filter records a packet. The HCI packet types, opcodes
+    // and event codes only apply to Bluetooth captures.
+    pub fn matches_filter(&self, packet_type: u32, packet: &[u8]) -> bool {
+        if self.chip_kind != ChipKind::BLUETOOTH {
+            return true;
+        }
+        let filter = &self.filter;
+        if !filter.packet_types.is_empty() && !filter.packet_types.contains(&packet_type) {
+            return false;
+        }
+        match (packet_type, packet) {
+            // Command: opcode (little endian), parameter length, parameters
+            (HCI_COMMAND, [low, high, ..]) if !filter.opcodes.is_empty() => {
+                filter.opcodes.contains(&u16::from_le_bytes([*low, *high]).into())
+            }
+            // Event: event code, parameter length, parameters
+            (HCI_EVENT, [code, ..]) if !filter.event_codes.is_empty() => {
+                filter.event_codes.contains(&(*code).into())
+            }
+            _ => true,
+        }
+    }
+
+    // Appends a packet to the file when the capture is on. At a limit the
+    // capture stops, or starts a new segment unless the current segment
+    // is empty.
+    pub fn append_record(
+        &mut self,
+        timestamp: Duration,
+        direction: PacketDirection,
+        packet_type: u32,
+        packet: &[u8],
+    ) -> Result<()> {
+        let link_type = match self.link_type().filter(|_| self.file.is_some()) {
+            Some(link_type) => link_type,
+            None => return Ok(()),
+        };
+        let snap_length = Some(self.filter.snap_length as usize).filter(|length| *length > 0);
+        let mut record = Vec::new();
+        match self.format {
+            Format::PCAP => pcap_util::append_record(
+                timestamp,
+                &mut record,
+                link_type,
+                direction,
+                packet_type,
+                packet,
+                snap_length,
+            )?,
+            Format::PCAPNG => pcapng_util::append_record(
+                timestamp,
+                &mut record,
+                0,
+                link_type,
+                direction,
+                packet_type,
+                packet,
+                snap_length,
+            )?,
+        };
+        if self.segment_full(record.len(), Instant::now()) {
+            match self.limits.mode.enum_value_or_default() {
+                Mode::STOP => {
+                    self.stop_capture();
+                    return Ok(());
+                }
+                _ if matches!(self.segments.back(), Some(segment) if segment.records > 0) => {
+                    self.new_segment()?
+                }
+                _ => {}
+            }
+        }
+        if let (Some(file), Some(segment)) = (&mut self.file, self.segments.back_mut()) {
+            file.write_all(&record)?;
+            segment.size += record.len();
+            segment.records += 1;
+            self.size += record.len();
+            self.records += 1;
+            // A follower that falls behind is dropped rather than
+            // buffering the capture in memory.
+            self.followers.retain(|follower| follower.try_send(record.clone()).is_ok());
+        }
+        Ok(())
+    }
+
+    // Follows an ongoing capture. Returns the header of a file and the
+    // receiver of the records appended from now on. The receiver is
+    // disconnected when the capture stops or when more than
+    // FOLLOW_BACKLOG records are pending.
+    pub fn follow(&mut self) -> Result<(Vec<u8>, Receiver<Vec<u8>>)> {
+        if self.file.is_none() {
+            return Err(Error::new(ErrorKind::NotFound, "Capture is not on"));
+        }
+        let (sender, receiver) = sync_channel(FOLLOW_BACKLOG);
+        self.followers.push(sender);
+        Ok((self.header()?, receiver))
+    }
+
     // Closes file by removing ownership of self.file
     // Capture info will still retain the size and record count
     // So it can be downloaded easily when GetCapture is invoked.
     pub fn stop_capture(&mut self) {
         self.file = None;
+        self.followers.clear();
     }
 
     pub fn new_facade_key(kind: ChipKind, facade_id: FacadeId) -> (ChipKind, FacadeId) {
@@ -132,11 +418,23 @@ impl CaptureInfo {
             records: self.records,
             timestamp: Some(timestamp).into(),
             valid: self.valid,
+            format: self.format.into(),
+            limits: Some(self.limits.clone()).into(),
+            filter: Some(self.filter.clone()).into(),
+            segments: self.segments.len() as i32,
             ..Default::default()
         }
     }
 }
 
+/// The file name extension of a capture format.
+pub fn file_extension(format: Format) -> &'static str {
+    match format {
+        Format::PCAP => "pcap",
+        Format::PCAPNG => "pcapng",
+    }
+}
+
 impl Captures {
     pub fn new() -> Self {
         Captures {
@@ -187,3 +485,157 @@ impl Captures {
         self.chip_id_to_capture.values()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A capture without the facade id, which needs the packet hub.
+    fn new_capture(device_name: &str) -> CaptureInfo {
+        CaptureInfo {
+            facade_id: 0,
+            id: 4000,
+            chip_kind: ChipKind::BLUETOOTH,
+            device_name: device_name.to_string(),
+            size: 0,
+            records: 0,
+            seconds: 0,
+            nanos: 0,
+            valid: true,
+            format: Format::PCAP,
+            limits: ProtoLimits::new(),
+            filter: ProtoFilter::new(),
+            segments: VecDeque::new(),
+            next_segment: 0,
+            followers: Vec::new(),
+            file: None,
+        }
+    }
+
+    fn append(capture: &mut CaptureInfo, count: u32) {
+        for second in 0..count {
+            let timestamp = Duration::from_secs(second.into());
+            let direction = PacketDirection::HostToController;
+            capture.append_record(timestamp, direction, 1, &[10, 32, 1, 0]).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_stop_at_limit() {
+        let mut capture = new_capture("test-stop");
+        let limits = ProtoLimits { max_records: 2, ..Default::default() };
+        capture.start_capture(Format::PCAP, limits, ProtoFilter::new()).unwrap();
+        append(&mut capture, 3);
+        assert!(capture.file.is_none());
+        assert_eq!(capture.records, 2);
+        let records = pcap_util::read_records(&capture.read().unwrap()).unwrap().len();
+        assert_eq!(records, 2);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut capture = new_capture("test-rotate");
+        let limits = ProtoLimits {
+            max_records: 2,
+            mode: Mode::ROTATE.into(),
+            max_segments: 2,
+            ..Default::default()
+        };
+        capture.start_capture(Format::PCAP, limits, ProtoFilter::new()).unwrap();
+        append(&mut capture, 5);
+        // The first segment is deleted, the last two are kept.
+        let file_paths = capture.file_paths();
+        assert_eq!(file_paths.len(), 2);
+        assert!(file_paths[0].to_str().unwrap().ends_with("-test-rotate-BLUETOOTH-1.pcap"));
+        assert!(!capture.segment_path(0).exists());
+        assert_eq!(capture.get_capture_proto().segments, 2);
+        let bytes = capture.read().unwrap();
+        let timestamps: Vec<u64> = pcap_util::read_records(&bytes)
+            .unwrap()
+            .iter()
+            .map(|(timestamp, _, _)| timestamp.as_secs())
+            .collect();
+        assert_eq!(timestamps, vec![2, 3, 4]);
+        assert_eq!((capture.records, capture.size), (3, bytes.len() + PCAP_HEADER_LEN));
+    }
+
+    #[test]
+    fn test_ring_buffer() {
+        let mut capture = new_capture("test-ring");
+        // A pcap header and 4 records of 25 bytes.
+        let limits = ProtoLimits {
+            max_bytes: 2 * (24 + 2 * 25),
+            mode: Mode::RING_BUFFER.into(),
+            ..Default::default()
+        };
+        capture.start_capture(Format::PCAP, limits, ProtoFilter::new()).unwrap();
+        append(&mut capture, 10);
+        assert!(capture.file.is_some());
+        assert!(capture.size as i64 <= capture.limits.max_bytes);
+        let bytes = capture.read().unwrap();
+        let records = pcap_util::read_records(&bytes).unwrap();
+        assert_eq!(records.len(), 4);
+        assert_eq!(records.last().unwrap().0, Duration::from_secs(9));
+        // Restarting the capture deletes the segments.
+        let file_paths = capture.file_paths();
+        capture.stop_capture();
+        capture.start_capture(Format::PCAP, ProtoLimits::new(), ProtoFilter::new()).unwrap();
+        assert!(file_paths.iter().all(|path| !path.exists()));
+        assert_eq!(capture.records, 0);
+    }
+
+    #[test]
+    fn test_follow() {
+        let mut capture = new_capture("test-follow");
+        assert!(capture.follow().is_err());
+        capture.start_capture(Format::PCAP, ProtoLimits::new(), ProtoFilter::new()).unwrap();
+        append(&mut capture, 1);
+        let (header, receiver) = capture.follow().unwrap();
+        append(&mut capture, 2);
+        // The follower gets the records appended after it started.
+        let mut bytes = header;
+        bytes.extend(receiver.try_iter().flatten());
+        let records = pcap_util::read_records(&bytes).unwrap();
+        assert_eq!(records.len(), 2);
+        capture.stop_capture();
+        assert!(receiver.recv().is_err());
+    }
+
+    #[test]
+    fn test_follow_backlog() {
+        let mut capture = new_capture("test-follow-backlog");
+        capture.start_capture(Format::PCAP, ProtoLimits::new(), ProtoFilter::new()).unwrap();
+        let (_, receiver) = capture.follow().unwrap();
+        for _ in 0..=FOLLOW_BACKLOG {
+            append(&mut capture, 1);
+        }
+        // The follower that fell behind is dropped.
+        assert_eq!(receiver.try_iter().count(), FOLLOW_BACKLOG);
+        assert!(receiver.recv().is_err());
+        capture.stop_capture();
+    }
+
+    #[test]
+    fn test_filter() {
+        let mut capture = new_capture("test-filter");
+        let filter = ProtoFilter {
+            packet_types: vec![HCI_COMMAND, HCI_EVENT],
+            opcodes: vec![0x200a],
+            snap_length: 8,
+            ..Default::default()
+        };
+        capture.start_capture(Format::PCAP, ProtoLimits::new(), filter).unwrap();
+        // LE Set Advertising Enable, Reset, ACL data and Command Complete.
+        assert!(capture.matches_filter(HCI_COMMAND, &[0x0a, 0x20, 1, 0]));
+        assert!(!capture.matches_filter(HCI_COMMAND, &[0x03, 0x0c, 0]));
+        assert!(!capture.matches_filter(2, &[0x01, 0x20, 0, 0]));
+        assert!(capture.matches_filter(HCI_EVENT, &[0x0e, 4, 1, 0x0a, 0x20, 0]));
+        capture
+            .append_record(Duration::ZERO, PacketDirection::HostToController, 1, &[10, 32, 1, 0])
+            .unwrap();
+        let bytes = capture.read().unwrap();
+        let records = pcap_util::read_records(&bytes).unwrap();
+        assert_eq!(records, vec![(Duration::ZERO, &[0, 0, 0, 0, 1, 10, 32, 1][..], 9)]);
+        assert_eq!(capture.get_capture_proto().filter.snap_length, 8);
+    }
+}
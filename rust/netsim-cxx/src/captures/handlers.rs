@@ -18,38 +18,54 @@
 //!
 //! /v1/captures --> handle_capture_list
 //! /v1/captures/{id} --> handle_capture_patch, handle_capture_get
+//! /v1/captures/{id}?format=pcapng --> handle_capture_get, converted to pcapng
+//! /v1/captures/{id}?follow=true --> handle_capture_follow
+//! /v1/captures/merged?device={patterns}&chip={patterns} --> handle_capture_merged
 //! handle_capture_cxx calls handle_capture, which calls handle_capture_* based on uri
 //! handle_packet_request and handle_packet_response is invoked by packet_hub
-//! to write packets to files if capture state is on.
+//! to count the traffic of the chips and to write packets to files if capture
+//! state is on and the packets match the filter of the capture.
 
 // TODO(b/274506882): Implement gRPC status proto on error responses. Also write better
 // and more descriptive error messages with proper error codes.
 
 use cxx::CxxVector;
 use frontend_proto::common::ChipKind;
-use frontend_proto::frontend::{GetDevicesResponse, ListCaptureResponse};
+use frontend_proto::frontend::patch_capture_request::PatchCapture as ProtoPatchCapture;
+use frontend_proto::frontend::{GetCaptureRequest, ListCaptureResponse};
+use frontend_proto::model::capture::{Filter as ProtoFilter, Format, Limits as ProtoLimits};
+use frontend_proto::model::State;
 use lazy_static::lazy_static;
 use netsim_common::util::time_display::TimeDisplay;
-use protobuf::Message;
-use protobuf_json_mapping::{print_to_string_with_options, PrintOptions};
+use protobuf::Enum;
+use protobuf_json_mapping::{merge_from_str, print_to_string_with_options, PrintOptions};
 use std::collections::HashSet;
 use std::fs::File;
-use std::io::{Read, Result};
+use std::io::{Error, ErrorKind, Read, Result};
 use std::pin::Pin;
-use std::sync::RwLock;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::{Once, RwLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use crate::captures::capture::{Captures, ChipId};
-use crate::ffi::{get_devices_bytes, CxxServerResponseWriter};
+use crate::devices::devices_handler::get_chip_facades;
+use crate::devices::traffic;
+use crate::ffi::CxxServerResponseWriter;
 use crate::http_server::http_request::{HttpHeaders, HttpRequest};
 use crate::http_server::server_response::ResponseWritable;
+use crate::lifecycle;
+use crate::version::get_version;
 use crate::CxxServerResponseWriterWrapper;
 
-use super::capture::CaptureInfo;
-use super::pcap_util::{append_record, PacketDirection};
-use super::PCAP_MIME_TYPE;
+use super::capture::{file_extension, CaptureInfo};
+use super::pcap_util::PacketDirection;
+use super::pcapng_util;
+use super::{PCAPNG_MIME_TYPE, PCAP_MIME_TYPE};
 
 const CHUNK_LEN: usize = 1_048_576;
+// How often a follower checks for a closed connection while no records
+// are captured.
+const FOLLOW_POLL: Duration = Duration::from_secs(1);
 const JSON_PRINT_OPTION: PrintOptions = PrintOptions {
     enum_values_int: false,
     proto_field_name: false,
@@ -62,6 +78,9 @@ lazy_static! {
     static ref RESOURCE: RwLock<Captures> = RwLock::new(Captures::new());
 }
 
+// The captures are stopped at shutdown so that the followers return.
+static STOP_AT_SHUTDOWN: Once = Once::new();
+
 // Update the Captures collection to reflect the currently connected devices.
 // This function removes entries from Captures when devices/chips
 // go away and adds entries when new devices/chips connect.
@@ -69,30 +88,13 @@ lazy_static! {
 // Note: if a device disconnects and there is captured data, the entry
 // remains with a flag valid = false so it can be retrieved.
 fn update_captures(captures: &mut Captures) {
-    // Perform get_devices_bytes ffi to receive bytes of GetDevicesResponse
-    // Print error and return empty hashmap if GetDevicesBytes fails.
-    let mut vec = Vec::<u8>::new();
-    if !get_devices_bytes(&mut vec) {
-        println!("netsim error: GetDevicesBytes failed - returning an empty set of captures");
-        return;
-    }
-
-    // Parse get_devices_response
-    let device_response = GetDevicesResponse::parse_from_bytes(&vec).unwrap();
-
     // Adding to Captures hashmap
     let mut chip_ids = HashSet::<ChipId>::new();
-    for device in device_response.devices {
-        for chip in device.chips {
-            chip_ids.insert(chip.id);
-            if !captures.contains(chip.id) {
-                let capture = CaptureInfo::new(
-                    chip.kind.enum_value_or_default(),
-                    chip.id,
-                    device.name.clone(),
-                );
-                captures.insert(capture);
-            }
+    for (chip_kind, chip_id, facade_id, device_name) in get_chip_facades() {
+        chip_ids.insert(chip_id);
+        if !captures.contains(chip_id) {
+            let capture = CaptureInfo::new(chip_kind, chip_id, facade_id, device_name);
+            captures.insert(capture);
         }
     }
 
@@ -131,55 +133,230 @@ fn update_captures(captures: &mut Captures) {
     }
 }
 
-// Helper function for getting file name from the given fields.
-fn get_file(id: ChipId, device_name: String, chip_kind: ChipKind) -> Result<File> {
-    let mut filename = std::env::temp_dir();
-    filename.push("netsim-pcaps");
-    filename.push(format!("{:?}-{:}-{:?}.pcap", id, device_name, chip_kind));
-    File::open(filename)
+fn mime_type(format: Format) -> &'static str {
+    match format {
+        Format::PCAP => PCAP_MIME_TYPE,
+        Format::PCAPNG => PCAPNG_MIME_TYPE,
+    }
+}
+
+// Helper function for parsing a capture format name such as "pcapng", ignoring case.
+// The status code of a capture error: 400 for the requests the capture
+// does not support and 404 otherwise.
+fn error_code(err: &Error) -> u16 {
+    match err.kind() {
+        ErrorKind::Unsupported | ErrorKind::InvalidInput => 400,
+        _ => 404,
+    }
+}
+
+fn parse_format(name: &str) -> std::result::Result<Format, String> {
+    Format::VALUES
+        .iter()
+        .find(|format| format!("{format:?}").eq_ignore_ascii_case(name))
+        .copied()
+        .ok_or(format!("Invalid capture format {name}"))
+}
+
+// Helper function for reading the segments of a capture in the format.
+fn read_capture(capture: &CaptureInfo, format: Format) -> Result<Vec<u8>> {
+    let bytes = capture.read()?;
+    let link_type = capture.link_type().ok_or(std::io::ErrorKind::Unsupported)?;
+    match format {
+        _ if format == capture.format => Ok(bytes),
+        Format::PCAP => pcapng_util::to_pcap(&bytes, link_type),
+        Format::PCAPNG => pcapng_util::from_pcap(
+            &bytes,
+            link_type,
+            &capture.interface_name(),
+            &capture.section_comment(),
+        ),
+    }
 }
 
 // TODO: GetCapture should return the information of the capture. Need to reconsider
 // uri hierarchy.
 // GET /captures/id/{id} --> Get Capture information
 // GET /captures/contents/{id} --> Download Pcap file
-pub fn handle_capture_get(writer: ResponseWritable, captures: &mut Captures, id: ChipId) {
+pub fn handle_capture_get(
+    writer: ResponseWritable,
+    captures: &mut Captures,
+    id: ChipId,
+    format: Option<Format>,
+) {
     // Get the most updated active captures
     update_captures(captures);
 
     if let Some(capture) = captures.get(id).map(|arc_capture| arc_capture.lock().unwrap()) {
         if capture.size == 0 {
             writer.put_error(404, "Capture file not found");
-        } else if let Ok(mut file) = get_file(id, capture.device_name.clone(), capture.chip_kind) {
-            let mut buffer = [0u8; CHUNK_LEN];
+        } else {
+            // The capture is sent in the format it is recorded in unless
+            // another format is requested.
+            let format = format.unwrap_or(capture.format);
             let time_display = TimeDisplay::new(capture.seconds, capture.nanos as u32);
             let header_value = format!(
-                "attachment; filename=\"{:?}-{:}-{:?}-{}.pcap\"",
+                "attachment; filename=\"{:?}-{:}-{:?}-{}.{}\"",
                 id,
                 capture.device_name.clone(),
                 capture.chip_kind,
-                time_display.utc_display()
+                time_display.utc_display(),
+                file_extension(format)
             );
-            writer.put_ok_with_length(
-                PCAP_MIME_TYPE,
-                capture.size,
-                &[("Content-Disposition", header_value.as_str())],
-            );
-            loop {
-                match file.read(&mut buffer) {
-                    Ok(0) => break,
-                    Ok(length) => writer.put_chunk(&buffer[..length]),
-                    Err(_) => writer.put_error(404, "Error reading pcap file"),
+            let headers = [("Content-Disposition", header_value.as_str())];
+            let file_paths = capture.file_paths();
+            // A single segment in the recorded format is sent as it is.
+            if format == capture.format && file_paths.len() == 1 {
+                let mut file = match File::open(&file_paths[0]) {
+                    Ok(file) => file,
+                    Err(_) => {
+                        writer.put_error(404, "Cannot open Capture file");
+                        return;
+                    }
+                };
+                let mut buffer = [0u8; CHUNK_LEN];
+                writer.put_ok_with_length(mime_type(format), capture.size, &headers);
+                loop {
+                    match file.read(&mut buffer) {
+                        Ok(0) => break,
+                        Ok(length) => writer.put_chunk(&buffer[..length]),
+                        Err(_) => writer.put_error(404, "Error reading pcap file"),
+                    }
+                }
+            } else {
+                match read_capture(&capture, format) {
+                    Ok(bytes) => {
+                        writer.put_ok_with_length(mime_type(format), bytes.len(), &headers);
+                        for chunk in bytes.chunks(CHUNK_LEN) {
+                            writer.put_chunk(chunk);
+                        }
+                    }
+                    Err(err) => writer.put_error(404, &format!("Cannot read Capture file: {err}")),
                 }
             }
-        } else {
-            writer.put_error(404, "Cannot open Capture file");
         }
     } else {
         writer.put_error(404, "Cannot access Capture Resource")
     }
 }
 
+// Whether a capture matches the device and chip kind patterns, a comma
+// separated list matched against the device name or chip kind ignoring case.
+// An empty list matches all captures.
+fn matches_patterns(capture: &CaptureInfo, device: &str, chip: &str) -> bool {
+    let matches = |patterns: &str, name: &str| {
+        patterns.is_empty()
+            || patterns
+                .split(',')
+                .any(|pattern| name.to_lowercase().contains(&pattern.to_lowercase()))
+    };
+    matches(device, &capture.device_name) && matches(chip, &format!("{:?}", capture.chip_kind))
+}
+
+// GET /captures/merged --> Download the records of all matching captures in a
+// pcapng file, with an interface per chip and the records in timestamp order
+pub fn handle_capture_merged(
+    writer: ResponseWritable,
+    captures: &mut Captures,
+    device: &str,
+    chip: &str,
+) {
+    // Get the most updated active captures
+    update_captures(captures);
+
+    let mut files = Vec::new();
+    for capture in captures.values().map(|arc_capture| arc_capture.lock().unwrap()) {
+        if capture.size == 0 || !matches_patterns(&capture, device, chip) {
+            continue;
+        }
+        let link_type = match capture.link_type() {
+            Some(link_type) => link_type,
+            None => continue,
+        };
+        match read_capture(&capture, Format::PCAPNG) {
+            Ok(bytes) => files.push((link_type, capture.interface_name(), bytes)),
+            Err(err) => {
+                writer.put_error(404, &format!("Cannot read Capture file: {err}"));
+                return;
+            }
+        }
+    }
+    if files.is_empty() {
+        writer.put_error(404, "Capture file not found");
+        return;
+    }
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).expect("Time went backwards");
+    let time_display = TimeDisplay::new(timestamp.as_secs() as i64, timestamp.subsec_nanos());
+    let comment = format!(
+        "netsim {}, merged capture of {} chips at {}",
+        get_version(),
+        files.len(),
+        time_display.utc_display()
+    );
+    match pcapng_util::merge(&files, &comment) {
+        Ok(bytes) => {
+            let header_value = format!(
+                "attachment; filename=\"merged-{}.{}\"",
+                time_display.utc_display(),
+                file_extension(Format::PCAPNG)
+            );
+            let headers = [("Content-Disposition", header_value.as_str())];
+            writer.put_ok_with_length(PCAPNG_MIME_TYPE, bytes.len(), &headers);
+            for chunk in bytes.chunks(CHUNK_LEN) {
+                writer.put_chunk(chunk);
+            }
+        }
+        Err(err) => writer.put_error(404, &format!("Cannot merge Capture files: {err}")),
+    }
+}
+
+// GET /captures/{id}?follow=true --> Stream the header and then the records as they are captured
+pub fn handle_capture_follow(writer: ResponseWritable, id: ChipId, format: Option<Format>) {
+    STOP_AT_SHUTDOWN.call_once(|| lifecycle::add_shutdown_callback(Box::new(stop_captures)));
+    // The captures are released before streaming, the packet path needs
+    // them to append the records.
+    let follow = {
+        let mut captures = RESOURCE.write().unwrap();
+        update_captures(&mut captures);
+        let mut capture = match captures.get(id) {
+            Some(arc_capture) => arc_capture.lock().unwrap(),
+            None => {
+                writer.put_error(404, "Cannot access Capture Resource");
+                return;
+            }
+        };
+        if matches!(format, Some(format) if format != capture.format) {
+            writer.put_error(400, "Capture can only be followed in its recorded format");
+            return;
+        }
+        capture.follow().map(|(header, receiver)| (capture.format, header, receiver))
+    };
+    let (format, header, receiver) = match follow {
+        Ok(follow) => follow,
+        Err(err) => {
+            writer.put_error(error_code(&err), err.to_string().as_str());
+            return;
+        }
+    };
+    writer.put_ok_streaming(mime_type(format), &[]);
+    writer.put_chunk(&header);
+    // The receiver is disconnected when the capture stops or the follower
+    // falls behind.
+    while !writer.is_closed() {
+        match receiver.recv_timeout(FOLLOW_POLL) {
+            Ok(record) => writer.put_chunk(&record),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+}
+
+fn stop_captures() {
+    for capture in RESOURCE.read().unwrap().values() {
+        capture.lock().unwrap().stop_capture();
+    }
+}
+
 pub fn handle_capture_list(writer: ResponseWritable, captures: &mut Captures) {
     // Get the most updated active captures
     update_captures(captures);
@@ -203,6 +380,9 @@ pub fn handle_capture_patch(
     captures: &mut Captures,
     id: ChipId,
     state: bool,
+    format: Format,
+    limits: ProtoLimits,
+    filter: ProtoFilter,
 ) {
     // Get the most updated active captures
     update_captures(captures);
@@ -210,8 +390,9 @@ pub fn handle_capture_patch(
     if let Some(mut capture) = captures.get(id).map(|arc_capture| arc_capture.lock().unwrap()) {
         match state {
             true => {
-                if let Err(err) = capture.start_capture() {
-                    writer.put_error(404, err.to_string().as_str());
+                // The format, limits and filter of an ongoing capture are left alone.
+                if let Err(err) = capture.start_capture(format, limits, filter) {
+                    writer.put_error(error_code(&err), err.to_string().as_str());
                     return;
                 }
             }
@@ -231,7 +412,7 @@ pub fn handle_capture_patch(
 
 /// The Rust capture handler used directly by Http frontend for LIST, GET, and PATCH
 pub fn handle_capture(request: &HttpRequest, param: &str, writer: ResponseWritable) {
-    if request.uri.as_str() == "/v1/captures" {
+    if request.path() == "/v1/captures" {
         match request.method.as_str() {
             "GET" => {
                 let mut captures = RESOURCE.write().unwrap();
@@ -241,8 +422,13 @@ pub fn handle_capture(request: &HttpRequest, param: &str, writer: ResponseWritab
         }
     } else {
         match request.method.as_str() {
-            "GET" => {
+            "GET" if param == "merged" => {
+                let device = request.query_param("device").unwrap_or_default();
+                let chip = request.query_param("chip").unwrap_or_default();
                 let mut captures = RESOURCE.write().unwrap();
+                handle_capture_merged(writer, &mut captures, &device, &chip);
+            }
+            "GET" => {
                 let id = match param.parse::<i32>() {
                     Ok(num) => num,
                     Err(_) => {
@@ -250,7 +436,19 @@ pub fn handle_capture(request: &HttpRequest, param: &str, writer: ResponseWritab
                         return;
                     }
                 };
-                handle_capture_get(writer, &mut captures, id);
+                let (format, follow) = match get_capture_request(request) {
+                    Ok(get_capture_request) => get_capture_request,
+                    Err(err) => {
+                        writer.put_error(400, &err);
+                        return;
+                    }
+                };
+                if follow {
+                    handle_capture_follow(writer, id, format);
+                } else {
+                    let mut captures = RESOURCE.write().unwrap();
+                    handle_capture_get(writer, &mut captures, id, format);
+                }
             }
             "PATCH" => {
                 let mut captures = RESOURCE.write().unwrap();
@@ -262,11 +460,43 @@ pub fn handle_capture(request: &HttpRequest, param: &str, writer: ResponseWritab
                     }
                 };
                 let body = &request.body;
-                let state = String::from_utf8(body.to_vec()).unwrap();
-                match state.as_str() {
-                    "1" => handle_capture_patch(writer, &mut captures, id, true),
-                    "2" => handle_capture_patch(writer, &mut captures, id, false),
-                    _ => writer.put_error(404, "Incorrect state for PatchCapture"),
+                let body = String::from_utf8(body.to_vec()).unwrap();
+                // The body is the state, or a PatchCapture in json with the
+                // state, format, limits and filter.
+                let mut patch = ProtoPatchCapture::new();
+                match body.as_str() {
+                    "1" => patch.state = State::ON.into(),
+                    "2" => patch.state = State::OFF.into(),
+                    _ => {
+                        if let Err(err) = merge_from_str(&mut patch, &body) {
+                            writer.put_error(400, &format!("Incorrect PatchCapture {body}: {err}"));
+                            return;
+                        }
+                    }
+                }
+                let format = patch.format.enum_value_or_default();
+                let limits = patch.limits.unwrap_or_default();
+                let filter = patch.filter.unwrap_or_default();
+                match patch.state.enum_value_or_default() {
+                    State::ON => handle_capture_patch(
+                        writer,
+                        &mut captures,
+                        id,
+                        true,
+                        format,
+                        limits,
+                        filter,
+                    ),
+                    State::OFF => handle_capture_patch(
+                        writer,
+                        &mut captures,
+                        id,
+                        false,
+                        format,
+                        limits,
+                        filter,
+                    ),
+                    _ => writer.put_error(400, "Incorrect state for PatchCapture"),
                 }
             }
             _ => writer.put_error(404, "Not found."),
@@ -274,6 +504,39 @@ pub fn handle_capture(request: &HttpRequest, param: &str, writer: ResponseWritab
     }
 }
 
+// The requested format of a capture download and whether to follow the
+// capture, from the GetCaptureRequest body sent by the gRPC frontend or
+// from the query parameters of the Http frontend. The format is None for
+// the format the capture is recorded in.
+fn get_capture_request(
+    request: &HttpRequest,
+) -> std::result::Result<(Option<Format>, bool), String> {
+    let mut proto = GetCaptureRequest::new();
+    if !request.body.is_empty() {
+        let body = String::from_utf8_lossy(&request.body);
+        merge_from_str(&mut proto, &body)
+            .map_err(|e| format!("Error parsing GetCaptureRequest json {body}: {e}"))?;
+    }
+    if let Some(format) = request.query_param("format") {
+        proto.format = format;
+    }
+    if let Some(follow) = request.query_param("follow") {
+        proto.follow = matches!(follow.as_str(), "" | "1" | "true");
+    }
+    let format = match proto.format.as_str() {
+        "" => None,
+        name => Some(parse_format(name)?),
+    };
+    Ok((format, proto.follow))
+}
+
+/// Whether the request follows a capture, GET /v1/captures/{id}?follow=true.
+pub fn is_follow_request(request: &HttpRequest) -> bool {
+    request.method == "GET"
+        && request.path().starts_with("/v1/captures/")
+        && matches!(get_capture_request(request), Ok((_, true)))
+}
+
 /// capture handle cxx for grpc server to call
 pub fn handle_capture_cxx(
     responder: Pin<&mut CxxServerResponseWriter>,
@@ -311,13 +574,24 @@ fn int_to_chip_kind(kind: u32) -> ChipKind {
 }
 
 // A common code for handle_request and handle_response cxx mehtods
-fn handle_packet(
+pub(crate) fn handle_packet(
     kind: u32,
     facade_id: u32,
-    packet: &CxxVector<u8>,
+    packet: &[u8],
     packet_type: u32,
     direction: PacketDirection,
 ) {
+    let traffic_direction = match direction {
+        PacketDirection::HostToController => traffic::Direction::Tx,
+        PacketDirection::ControllerToHost => traffic::Direction::Rx,
+    };
+    traffic::record(
+        int_to_chip_kind(kind),
+        facade_id as i32,
+        traffic_direction,
+        packet.len(),
+        Instant::now(),
+    );
     let captures = RESOURCE.read().unwrap();
     let facade_key = CaptureInfo::new_facade_key(int_to_chip_kind(kind), facade_id as i32);
     if let Some(mut capture) = captures
@@ -325,19 +599,11 @@ fn handle_packet(
         .get(&facade_key)
         .map(|arc_capture| arc_capture.lock().unwrap())
     {
-        if let Some(ref mut file) = capture.file {
-            if int_to_chip_kind(kind) == ChipKind::BLUETOOTH {
-                let timestamp =
-                    SystemTime::now().duration_since(UNIX_EPOCH).expect("Time went backwards");
-                match append_record(timestamp, file, direction, packet_type, packet.as_slice()) {
-                    Ok(size) => {
-                        capture.size += size;
-                        capture.records += 1;
-                    }
-                    Err(err) => {
-                        println!("netsimd: {err:?}");
-                    }
-                }
+        if capture.file.is_some() && capture.matches_filter(packet_type, packet) {
+            let timestamp =
+                SystemTime::now().duration_since(UNIX_EPOCH).expect("Time went backwards");
+            if let Err(err) = capture.append_record(timestamp, direction, packet_type, packet) {
+                println!("netsimd: {err:?}");
             }
         }
     };
@@ -345,12 +611,24 @@ fn handle_packet(
 
 // Cxx Method for packet_hub to invoke (Host to Controller Packet Flow)
 pub fn handle_packet_request(kind: u32, facade_id: u32, packet: &CxxVector<u8>, packet_type: u32) {
-    handle_packet(kind, facade_id, packet, packet_type, PacketDirection::HostToController)
+    handle_packet(
+        kind,
+        facade_id,
+        packet.as_slice(),
+        packet_type,
+        PacketDirection::HostToController,
+    )
 }
 
 // Cxx Method for packet_hub to invoke (Controller to Host Packet Flow)
 pub fn handle_packet_response(kind: u32, facade_id: u32, packet: &CxxVector<u8>, packet_type: u32) {
-    handle_packet(kind, facade_id, packet, packet_type, PacketDirection::ControllerToHost)
+    handle_packet(
+        kind,
+        facade_id,
+        packet.as_slice(),
+        packet_type,
+        PacketDirection::ControllerToHost,
+    )
 }
 
 // Cxx Method for clearing pcap files in temp directory
@@ -366,3 +644,78 @@ pub fn clear_pcap_files() -> bool {
     // Delete the directory.
     std::fs::remove_dir_all(&path).is_ok()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::devices::devices_handler::{add_chip, remove_chip};
+    use crate::http_server::server_response::ServerResponseWriter;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_handle_uwb_response() {
+        let capture = CaptureInfo::new(ChipKind::UWB, 9500, 9500, "uwb-capture-test".to_string());
+        RESOURCE.write().unwrap().insert(capture);
+        let capture = RESOURCE.write().unwrap().get(9500).unwrap().clone();
+        capture
+            .lock()
+            .unwrap()
+            .start_capture(Format::PCAP, ProtoLimits::new(), ProtoFilter::new())
+            .unwrap();
+        // A RANGE_DATA_NTF from the controller to the host.
+        let packet = [0x62, 0x00, 0x00, 0x00];
+        handle_packet(3, 9500, &packet, 0, PacketDirection::ControllerToHost);
+        let contents = capture.lock().unwrap().read().unwrap();
+        assert_eq!(capture.lock().unwrap().records, 1);
+        assert!(contents.ends_with(&packet));
+        RESOURCE.write().unwrap().remove(&9500);
+    }
+
+    fn new_request(method: &str, uri: &str, body: &str) -> HttpRequest {
+        HttpRequest {
+            method: method.to_string(),
+            uri: uri.to_string(),
+            headers: HttpHeaders::new(),
+            version: "1.1".to_string(),
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    // The status line of the response to a capture request.
+    fn capture_status(method: &str, param: &str, query: &str, body: &str) -> String {
+        let request = new_request(method, &format!("/v1/captures/{param}{query}"), body);
+        let mut stream = Cursor::new(Vec::new());
+        handle_capture(&request, param, &mut ServerResponseWriter::new(&mut stream));
+        let response = String::from_utf8_lossy(stream.get_ref()).to_string();
+        response.lines().next().unwrap_or_default().to_string()
+    }
+
+    #[test]
+    fn test_patch_capture_bad_request() {
+        assert_eq!(capture_status("PATCH", "9501", "", "{not json"), "HTTP/1.1 400");
+        assert_eq!(capture_status("PATCH", "9501", "", "{}"), "HTTP/1.1 400");
+    }
+
+    #[test]
+    fn test_get_capture_bad_request() {
+        assert_eq!(capture_status("GET", "9502", "?format=bogus", ""), "HTTP/1.1 400");
+        assert_eq!(capture_status("GET", "9502", "", "{not json"), "HTTP/1.1 400");
+        // The captures follow the chips of the devices, recorded as pcap.
+        let result = add_chip("follow-guid-1", "follow-device-1", ChipKind::UWB, "uwb", "", "");
+        let chip_id = result.chip_id.to_string();
+        assert_eq!(
+            capture_status("GET", &chip_id, "?follow=true&format=pcapng", ""),
+            "HTTP/1.1 400"
+        );
+        remove_chip(result.device_id, result.chip_id);
+    }
+
+    #[test]
+    fn test_is_follow_request() {
+        assert!(is_follow_request(&new_request("GET", "/v1/captures/1?follow=true", "")));
+        assert!(is_follow_request(&new_request("GET", "/v1/captures/1", r#"{"follow": true}"#)));
+        assert!(!is_follow_request(&new_request("GET", "/v1/captures/1", "")));
+        assert!(!is_follow_request(&new_request("GET", "/v1/devices?follow=true", "")));
+        assert!(!is_follow_request(&new_request("PATCH", "/v1/captures/1?follow=true", "")));
+    }
+}
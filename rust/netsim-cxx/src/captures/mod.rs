@@ -15,5 +15,7 @@
 pub mod capture;
 pub mod handlers;
 pub mod pcap_util;
+pub mod pcapng_util;
 
 pub const PCAP_MIME_TYPE: &str = "application/vnd.tcpdump.pcap";
+pub const PCAPNG_MIME_TYPE: &str = "application/x-pcapng";
@@ -13,10 +13,11 @@
 // limitations under the License.
 
 use std::{
-    fs::File,
-    io::{Result, Write},
+    io::{Error, ErrorKind, Result, Write},
     time::Duration,
 };
+
+use frontend_proto::common::ChipKind;
 macro_rules! be_vec {
     ( $( $x:expr ),* ) => {
          Vec::<u8>::new().iter().copied()
@@ -25,14 +26,68 @@ macro_rules! be_vec {
        };
     }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum PacketDirection {
     HostToController = 0,
     ControllerToHost = 1,
 }
 
-pub fn write_pcap_header(output: &mut File) -> Result<usize> {
-    let linktype: u32 = 201; // LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR
+/// The link type of the packets in a pcap file.
+// https://www.tcpdump.org/linktypes.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LinkType {
+    BluetoothHciH4WithPhdr = 201,
+    Ieee80211Radiotap = 127,
+    FiraUci = 299,
+}
+
+impl LinkType {
+    /// The link type of the captures of a chip kind, None when the chip
+    /// kind cannot be captured.
+    pub fn from_chip_kind(chip_kind: ChipKind) -> Option<LinkType> {
+        match chip_kind {
+            ChipKind::BLUETOOTH => Some(LinkType::BluetoothHciH4WithPhdr),
+            ChipKind::WIFI => Some(LinkType::Ieee80211Radiotap),
+            ChipKind::UWB => Some(LinkType::FiraUci),
+            _ => None,
+        }
+    }
+}
+
+// The radiotap header of an 802.11 frame, without any optional fields.
+// https://www.radiotap.org/
+fn radiotap_header() -> Vec<u8> {
+    vec![
+        0u8, // version
+        0u8, // padding
+        8u8, 0u8, // length (little endian)
+        0u8, 0u8, 0u8, 0u8, // present flags
+    ]
+}
 
+// The bytes in front of a packet for the link type.
+fn link_layer_header(
+    link_type: LinkType,
+    packet_direction: PacketDirection,
+    packet_type: u32,
+) -> Vec<u8> {
+    match link_type {
+        // Record (direction, type, packet)
+        LinkType::BluetoothHciH4WithPhdr => {
+            be_vec![packet_direction as u32, packet_type as u8]
+        }
+        // Record (radiotap, 802.11 frame)
+        LinkType::Ieee80211Radiotap => radiotap_header(),
+        // Record (UCI packet). The direction is given by the message type
+        // of the UCI header.
+        LinkType::FiraUci => Vec::new(),
+    }
+}
+
+// The length of the file header.
+pub(super) const PCAP_HEADER_LEN: usize = 24;
+
+pub fn write_pcap_header(output: &mut impl Write, link_type: LinkType) -> Result<usize> {
     // https://tools.ietf.org/id/draft-gharris-opsawg-pcap-00.html#name-file-header
     let header: Vec<u8> = be_vec![
         0xa1b2c3d4u32, // magic number
@@ -41,47 +96,102 @@ pub fn write_pcap_header(output: &mut File) -> Result<usize> {
         0u32,          // reserved 1
         0u32,          // reserved 2
         u32::MAX,      // snaplen
-        linktype
+        link_type as u32
     ];
 
     output.write_all(&header)?;
     Ok(header.len())
 }
 
+// The link layer header and packet of a record, truncated to the snap
+// length, and the original length of the record.
+pub(super) fn link_layer_record(
+    link_type: LinkType,
+    packet_direction: PacketDirection,
+    packet_type: u32,
+    packet: &[u8],
+    snap_length: Option<usize>,
+) -> (Vec<u8>, usize) {
+    let mut record = link_layer_header(link_type, packet_direction, packet_type);
+    record.extend(packet);
+    let original_length = record.len();
+    if let Some(snap_length) = snap_length {
+        record.truncate(snap_length);
+    }
+    (record, original_length)
+}
+
+/// Append a record, truncated to the snap length when one is given.
 pub fn append_record(
     timestamp: Duration,
-    output: &mut File,
+    output: &mut impl Write,
+    link_type: LinkType,
     packet_direction: PacketDirection,
     packet_type: u32,
     packet: &[u8],
+    snap_length: Option<usize>,
 ) -> Result<usize> {
-    // Record (direciton, type, packet)
-    let record: Vec<u8> = be_vec![packet_direction as u32, packet_type as u8];
+    let (record, original_length) =
+        link_layer_record(link_type, packet_direction, packet_type, packet, snap_length);
+    write_record(timestamp, output, &record, original_length)
+}
 
+// Write a record of the link layer header and packet, which may be
+// shorter than the original record.
+pub(super) fn write_record(
+    timestamp: Duration,
+    output: &mut impl Write,
+    record: &[u8],
+    original_length: usize,
+) -> Result<usize> {
     // https://tools.ietf.org/id/draft-gharris-opsawg-pcap-00.html#name-packet-record
-    let length = record.len() + packet.len();
+    let length = record.len();
     let header: Vec<u8> = be_vec![
         timestamp.as_secs() as u32, // seconds
         timestamp.subsec_micros(),  // microseconds
         length as u32,              // Captured Packet Length
-        length as u32               // Original Packet Length
+        original_length as u32      // Original Packet Length
     ];
     let mut bytes = Vec::<u8>::with_capacity(header.len() + length);
     bytes.extend(&header);
-    bytes.extend(&record);
-    bytes.extend(packet);
+    bytes.extend(record);
     output.write_all(&bytes)?;
     output.flush()?;
     Ok(header.len() + length)
 }
 
+/// The timestamps, records, link layer header and packet, and original
+/// lengths of the records of a pcap file written by `write_pcap_header`
+/// and `append_record`.
+pub(super) fn read_records(pcap: &[u8]) -> Result<Vec<(Duration, &[u8], usize)>> {
+    let invalid = || Error::new(ErrorKind::InvalidData, "Invalid pcap file");
+    let field = |bytes: &[u8], offset: usize| -> Result<u32> {
+        let field = bytes.get(offset..offset + 4).ok_or_else(invalid)?;
+        Ok(u32::from_be_bytes(field.try_into().unwrap()))
+    };
+    if field(pcap, 0)? != 0xa1b2c3d4 {
+        return Err(invalid());
+    }
+    let mut records = Vec::new();
+    let mut rest = pcap.get(PCAP_HEADER_LEN..).ok_or_else(invalid)?;
+    while !rest.is_empty() {
+        let timestamp = Duration::new(field(rest, 0)?.into(), field(rest, 4)? * 1000);
+        let length = field(rest, 8)? as usize;
+        let original_length = field(rest, 12)? as usize;
+        let record = rest.get(16..16 + length).ok_or_else(invalid)?;
+        records.push((timestamp, record, original_length));
+        rest = &rest[16 + length..];
+    }
+    Ok(records)
+}
+
 #[cfg(test)]
 mod tests {
     use std::{fs::File, io::Read, time::Duration};
 
-    use crate::captures::pcap_util::{append_record, PacketDirection};
+    use crate::captures::pcap_util::{append_record, LinkType, PacketDirection};
 
-    use super::write_pcap_header;
+    use super::{read_records, write_pcap_header};
 
     static EXPECTED: &[u8; 76] = include_bytes!("sample.pcap");
 
@@ -93,21 +203,25 @@ mod tests {
         let mut temp_dir = std::env::temp_dir();
         temp_dir.push("test.pcap");
         if let Ok(mut file) = File::create(temp_dir.clone()) {
-            write_pcap_header(&mut file).unwrap();
+            write_pcap_header(&mut file, LinkType::BluetoothHciH4WithPhdr).unwrap();
             append_record(
                 Duration::from_secs(0),
                 &mut file,
+                LinkType::BluetoothHciH4WithPhdr,
                 PacketDirection::HostToController,
                 4u32,
                 &[14, 4, 1, 10, 32, 0],
+                None,
             )
             .unwrap();
             append_record(
                 Duration::from_millis(250),
                 &mut file,
+                LinkType::BluetoothHciH4WithPhdr,
                 PacketDirection::ControllerToHost,
                 1u32,
                 &[10, 32, 1, 0],
+                None,
             )
             .unwrap();
         } else {
@@ -124,4 +238,92 @@ mod tests {
             panic!("Cannot create temp file")
         }
     }
+
+    #[test]
+    fn test_wifi_pcap_file() {
+        let mut temp_dir = std::env::temp_dir();
+        temp_dir.push("test-wifi.pcap");
+        let frame = [0x80, 0x00, 0x00, 0x00, 0xff, 0xff];
+        {
+            let mut file = File::create(temp_dir.clone()).unwrap();
+            write_pcap_header(&mut file, LinkType::Ieee80211Radiotap).unwrap();
+            let size = append_record(
+                Duration::from_millis(1500),
+                &mut file,
+                LinkType::Ieee80211Radiotap,
+                PacketDirection::ControllerToHost,
+                0u32,
+                &frame,
+                None,
+            )
+            .unwrap();
+            assert_eq!(size, 16 + 8 + frame.len());
+        }
+        let mut buffer = Vec::new();
+        File::open(temp_dir).unwrap().read_to_end(&mut buffer).unwrap();
+        // The link type is the last field of the file header.
+        assert_eq!(buffer[20..24], 127u32.to_be_bytes());
+        // Seconds, microseconds and the captured and original lengths.
+        assert_eq!(buffer[24..28], 1u32.to_be_bytes());
+        assert_eq!(buffer[28..32], 500_000u32.to_be_bytes());
+        assert_eq!(buffer[32..36], 14u32.to_be_bytes());
+        assert_eq!(buffer[36..40], 14u32.to_be_bytes());
+        assert_eq!(buffer[40..48], [0, 0, 8, 0, 0, 0, 0, 0]);
+        assert_eq!(buffer[48..], frame);
+        let records = read_records(&buffer).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].0, Duration::from_millis(1500));
+        assert_eq!(records[0].1[8..], frame);
+        assert_eq!(records[0].2, 14);
+        assert!(read_records(&buffer[..buffer.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_snap_length() {
+        let mut pcap = Vec::new();
+        let packet = [0x02, 0x20, 0x0c, 0x00, 0x08, 0x00];
+        let link_type = LinkType::BluetoothHciH4WithPhdr;
+        let direction = PacketDirection::HostToController;
+        write_pcap_header(&mut pcap, link_type).unwrap();
+        let size =
+            append_record(Duration::ZERO, &mut pcap, link_type, direction, 2, &packet, Some(8))
+                .unwrap();
+        assert_eq!(size, 16 + 8);
+        // The record is truncated and keeps its original length.
+        let records = read_records(&pcap).unwrap();
+        assert_eq!(records, vec![(Duration::ZERO, &[0, 0, 0, 0, 2, 0x02, 0x20, 0x0c][..], 11)]);
+    }
+
+    #[test]
+    fn test_uci_pcap_file() {
+        let mut temp_dir = std::env::temp_dir();
+        temp_dir.push("test-uci.pcap");
+        // CORE_GET_DEVICE_INFO_CMD and its response.
+        let packets = [vec![0x20, 0x02, 0x00, 0x00], vec![0x40, 0x02, 0x00, 0x01, 0x00]];
+        {
+            let mut file = File::create(temp_dir.clone()).unwrap();
+            write_pcap_header(&mut file, LinkType::FiraUci).unwrap();
+            let directions = [PacketDirection::HostToController, PacketDirection::ControllerToHost];
+            for (direction, packet) in directions.into_iter().zip(&packets) {
+                append_record(
+                    Duration::ZERO,
+                    &mut file,
+                    LinkType::FiraUci,
+                    direction,
+                    0u32,
+                    packet,
+                    None,
+                )
+                .unwrap();
+            }
+        }
+        let mut buffer = Vec::new();
+        File::open(temp_dir).unwrap().read_to_end(&mut buffer).unwrap();
+        assert_eq!(buffer[20..24], 299u32.to_be_bytes());
+        // The records hold the UCI packets as they are.
+        assert_eq!(buffer[32..36], 4u32.to_be_bytes());
+        assert_eq!(buffer[40..44], packets[0]);
+        assert_eq!(buffer[56..60], 5u32.to_be_bytes());
+        assert_eq!(buffer[60..], packets[1]);
+    }
 }
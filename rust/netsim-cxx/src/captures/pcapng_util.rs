@@ -0,0 +1,354 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The pcapng file format.
+//!
+//! A pcapng file is a section header block followed by an interface
+//! description block for each chip and an enhanced packet block for each
+//! packet. Interfaces are numbered in the order of their blocks and have
+//! nanosecond timestamps.
+//!
+//! https://www.ietf.org/archive/id/draft-tuexen-opsawg-pcapng-05.html
+
+use std::{
+    io::{Error, ErrorKind, Result, Write},
+    time::Duration,
+};
+
+use super::pcap_util::{self, link_layer_record, LinkType, PacketDirection};
+
+const SECTION_HEADER_BLOCK: u32 = 0x0a0d0d0a;
+const INTERFACE_DESCRIPTION_BLOCK: u32 = 1;
+const ENHANCED_PACKET_BLOCK: u32 = 6;
+const BYTE_ORDER_MAGIC: u32 = 0x1a2b3c4d;
+
+const OPT_ENDOFOPT: u16 = 0;
+const OPT_COMMENT: u16 = 1;
+const IF_NAME: u16 = 2;
+const IF_TSRESOL: u16 = 9;
+const EPB_FLAGS: u16 = 2;
+// Timestamps in units of 10^-9 seconds.
+const NANOSECONDS: u8 = 9;
+
+// The bytes padded to 32 bits.
+fn padded(bytes: &[u8]) -> Vec<u8> {
+    let mut padded = bytes.to_vec();
+    padded.resize((bytes.len() + 3) & !3, 0);
+    padded
+}
+
+fn option(code: u16, value: &[u8]) -> Vec<u8> {
+    let mut option = Vec::new();
+    option.extend(code.to_be_bytes());
+    option.extend((value.len() as u16).to_be_bytes());
+    option.extend(padded(value));
+    option
+}
+
+fn options(options: &[(u16, &[u8])]) -> Vec<u8> {
+    let mut bytes: Vec<u8> =
+        options.iter().flat_map(|(code, value)| option(*code, value)).collect();
+    bytes.extend(option(OPT_ENDOFOPT, &[]));
+    bytes
+}
+
+fn write_block(output: &mut impl Write, block_type: u32, body: &[u8]) -> Result<usize> {
+    // The block type and the total length before and after the body.
+    let length = (body.len() + 12) as u32;
+    let mut block = Vec::with_capacity(length as usize);
+    block.extend(block_type.to_be_bytes());
+    block.extend(length.to_be_bytes());
+    block.extend(body);
+    block.extend(length.to_be_bytes());
+    output.write_all(&block)?;
+    output.flush()?;
+    Ok(block.len())
+}
+
+/// Write the section header block that starts the file, with a comment.
+pub fn write_section_header(output: &mut impl Write, comment: &str) -> Result<usize> {
+    let mut body = Vec::new();
+    body.extend(BYTE_ORDER_MAGIC.to_be_bytes());
+    body.extend(1u16.to_be_bytes()); // major version
+    body.extend(0u16.to_be_bytes()); // minor version
+    body.extend((-1i64).to_be_bytes()); // section length, not specified
+    body.extend(options(&[(OPT_COMMENT, comment.as_bytes())]));
+    write_block(output, SECTION_HEADER_BLOCK, &body)
+}
+
+/// Write the interface description block of the next interface.
+pub fn write_interface(output: &mut impl Write, link_type: LinkType, name: &str) -> Result<usize> {
+    let mut body = Vec::new();
+    body.extend((link_type as u16).to_be_bytes());
+    body.extend(0u16.to_be_bytes()); // reserved
+    body.extend(0u32.to_be_bytes()); // snaplen, no limit
+    body.extend(options(&[(IF_NAME, name.as_bytes()), (IF_TSRESOL, &[NANOSECONDS])]));
+    write_block(output, INTERFACE_DESCRIPTION_BLOCK, &body)
+}
+
+/// Write the header of a file with a single interface.
+pub fn write_pcapng_header(
+    output: &mut impl Write,
+    link_type: LinkType,
+    interface: &str,
+    comment: &str,
+) -> Result<usize> {
+    Ok(write_section_header(output, comment)? + write_interface(output, link_type, interface)?)
+}
+
+// Write an enhanced packet block of a record, link layer header and packet,
+// which may be shorter than the original record.
+fn write_record(
+    timestamp: Duration,
+    output: &mut impl Write,
+    interface_id: u32,
+    packet_direction: Option<PacketDirection>,
+    record: &[u8],
+    original_length: usize,
+) -> Result<usize> {
+    let nanos = timestamp.as_nanos() as u64;
+    let mut body = Vec::new();
+    body.extend(interface_id.to_be_bytes());
+    body.extend(((nanos >> 32) as u32).to_be_bytes()); // timestamp (high)
+    body.extend((nanos as u32).to_be_bytes()); // timestamp (low)
+    body.extend((record.len() as u32).to_be_bytes()); // captured packet length
+    body.extend((original_length as u32).to_be_bytes()); // original packet length
+    body.extend(padded(record));
+    // The direction is inbound to or outbound from the host.
+    if let Some(packet_direction) = packet_direction {
+        let flags: u32 = match packet_direction {
+            PacketDirection::HostToController => 2,
+            PacketDirection::ControllerToHost => 1,
+        };
+        body.extend(options(&[(EPB_FLAGS, &flags.to_be_bytes())]));
+    }
+    write_block(output, ENHANCED_PACKET_BLOCK, &body)
+}
+
+/// Append a record, truncated to the snap length when one is given.
+#[allow(clippy::too_many_arguments)]
+pub fn append_record(
+    timestamp: Duration,
+    output: &mut impl Write,
+    interface_id: u32,
+    link_type: LinkType,
+    packet_direction: PacketDirection,
+    packet_type: u32,
+    packet: &[u8],
+    snap_length: Option<usize>,
+) -> Result<usize> {
+    let (record, original_length) =
+        link_layer_record(link_type, packet_direction, packet_type, packet, snap_length);
+    write_record(timestamp, output, interface_id, Some(packet_direction), &record, original_length)
+}
+
+fn invalid() -> Error {
+    Error::new(ErrorKind::InvalidData, "Invalid pcapng file")
+}
+
+fn field(bytes: &[u8], offset: usize) -> Result<u32> {
+    let field = bytes.get(offset..offset + 4).ok_or_else(invalid)?;
+    Ok(u32::from_be_bytes(field.try_into().unwrap()))
+}
+
+// The timestamps and enhanced packet blocks of a pcapng file written by
+// this module.
+fn enhanced_packet_blocks(pcapng: &[u8]) -> Result<Vec<(Duration, &[u8])>> {
+    if field(pcapng, 0)? != SECTION_HEADER_BLOCK || field(pcapng, 8)? != BYTE_ORDER_MAGIC {
+        return Err(invalid());
+    }
+    let mut blocks = Vec::new();
+    let mut rest = pcapng;
+    while !rest.is_empty() {
+        let length = field(rest, 4)? as usize;
+        let block = rest.get(..length).filter(|_| length >= 12).ok_or_else(invalid)?;
+        if field(block, 0)? == ENHANCED_PACKET_BLOCK {
+            if length < 32 {
+                return Err(invalid());
+            }
+            let nanos = (field(block, 12)? as u64) << 32 | field(block, 16)? as u64;
+            blocks.push((Duration::from_nanos(nanos), block));
+        }
+        rest = &rest[length..];
+    }
+    Ok(blocks)
+}
+
+/// The timestamps, records and original lengths of the records of the
+/// enhanced packet blocks of a pcapng file written by this module.
+pub(super) fn read_records(pcapng: &[u8]) -> Result<Vec<(Duration, &[u8], usize)>> {
+    let mut records = Vec::new();
+    for (timestamp, block) in enhanced_packet_blocks(pcapng)? {
+        let record_length = field(block, 20)? as usize;
+        let original_length = field(block, 24)? as usize;
+        let record = block.get(28..28 + record_length).ok_or_else(invalid)?;
+        records.push((timestamp, record, original_length));
+    }
+    Ok(records)
+}
+
+/// Merge pcapng files with a single interface, of the link type and
+/// interface name given with each file, into a file with an interface
+/// per file. The records are interleaved in timestamp order.
+pub fn merge(files: &[(LinkType, String, Vec<u8>)], comment: &str) -> Result<Vec<u8>> {
+    let mut pcapng = Vec::new();
+    write_section_header(&mut pcapng, comment)?;
+    let mut blocks = Vec::new();
+    for (interface_id, (link_type, interface, file)) in files.iter().enumerate() {
+        write_interface(&mut pcapng, *link_type, interface)?;
+        for (timestamp, block) in enhanced_packet_blocks(file)? {
+            blocks.push((timestamp, interface_id as u32, block));
+        }
+    }
+    // The sort is stable, the records of a file keep their order.
+    blocks.sort_by_key(|(timestamp, _, _)| *timestamp);
+    for (_, interface_id, block) in blocks {
+        // The interface id is the first field after the block type and length.
+        pcapng.extend(&block[..8]);
+        pcapng.extend(interface_id.to_be_bytes());
+        pcapng.extend(&block[12..]);
+    }
+    Ok(pcapng)
+}
+
+/// Convert a pcap file to a pcapng file with a single interface.
+pub fn from_pcap(
+    pcap: &[u8],
+    link_type: LinkType,
+    interface: &str,
+    comment: &str,
+) -> Result<Vec<u8>> {
+    let mut pcapng = Vec::new();
+    write_pcapng_header(&mut pcapng, link_type, interface, comment)?;
+    for (timestamp, record, original_length) in pcap_util::read_records(pcap)? {
+        // The direction is only known from the Bluetooth pseudo header.
+        let packet_direction = match (link_type, record.get(..4)) {
+            (LinkType::BluetoothHciH4WithPhdr, Some([0, 0, 0, 0])) => {
+                Some(PacketDirection::HostToController)
+            }
+            (LinkType::BluetoothHciH4WithPhdr, Some(_)) => Some(PacketDirection::ControllerToHost),
+            _ => None,
+        };
+        write_record(timestamp, &mut pcapng, 0, packet_direction, record, original_length)?;
+    }
+    Ok(pcapng)
+}
+
+/// Convert a pcapng file with a single interface to a pcap file.
+pub fn to_pcap(pcapng: &[u8], link_type: LinkType) -> Result<Vec<u8>> {
+    let mut pcap = Vec::new();
+    pcap_util::write_pcap_header(&mut pcap, link_type)?;
+    for (timestamp, record, original_length) in read_records(pcapng)? {
+        pcap_util::write_record(timestamp, &mut pcap, record, original_length)?;
+    }
+    Ok(pcap)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pcapng_records() {
+        let mut pcapng = Vec::new();
+        write_pcapng_header(
+            &mut pcapng,
+            LinkType::BluetoothHciH4WithPhdr,
+            "phone-BLUETOOTH-1",
+            "netsim",
+        )
+        .unwrap();
+        // The block type and length of the section header and the byte
+        // order magic, then the comment option padded to 8 bytes.
+        assert_eq!(pcapng[..12], [0x0a, 0x0d, 0x0d, 0x0a, 0, 0, 0, 44, 0x1a, 0x2b, 0x3c, 0x4d]);
+        assert_eq!(pcapng[24..36], [0, 1, 0, 6, b'n', b'e', b't', b's', b'i', b'm', 0, 0]);
+        // The link type of the interface.
+        assert_eq!(pcapng[52..54], 201u16.to_be_bytes());
+        let timestamp = Duration::new(1, 5);
+        let size = append_record(
+            timestamp,
+            &mut pcapng,
+            0,
+            LinkType::BluetoothHciH4WithPhdr,
+            PacketDirection::ControllerToHost,
+            4,
+            &[14, 4, 1, 10, 32, 0],
+            None,
+        )
+        .unwrap();
+        // The 11 bytes record is padded to 12 and followed by the flags.
+        assert_eq!(size, 28 + 12 + 12 + 4);
+        let epb = &pcapng[pcapng.len() - size..];
+        assert_eq!(epb[12..20], [0, 0, 0, 0, 0x3b, 0x9a, 0xca, 0x05]);
+        assert_eq!(epb[size - 16..size - 8], [0, 2, 0, 4, 0, 0, 0, 1]);
+        let records = read_records(&pcapng).unwrap();
+        assert_eq!(records, vec![(timestamp, &[0, 0, 0, 1, 4, 14, 4, 1, 10, 32, 0][..], 11)]);
+        assert!(read_records(&pcapng[..pcapng.len() - 4]).is_err());
+    }
+
+    #[test]
+    fn test_convert() {
+        let link_type = LinkType::BluetoothHciH4WithPhdr;
+        let mut pcap = Vec::new();
+        pcap_util::write_pcap_header(&mut pcap, link_type).unwrap();
+        for (millis, packet_direction) in
+            [(0, PacketDirection::HostToController), (250, PacketDirection::ControllerToHost)]
+        {
+            let timestamp = Duration::from_millis(millis);
+            pcap_util::append_record(
+                timestamp,
+                &mut pcap,
+                link_type,
+                packet_direction,
+                1,
+                &[10, 32],
+                Some(6),
+            )
+            .unwrap();
+        }
+        let pcapng = from_pcap(&pcap, link_type, "phone-BLUETOOTH-1", "netsim").unwrap();
+        assert_eq!(read_records(&pcapng).unwrap(), pcap_util::read_records(&pcap).unwrap());
+        assert_eq!(to_pcap(&pcapng, link_type).unwrap(), pcap);
+    }
+
+    #[test]
+    fn test_merge() {
+        let link_type = LinkType::BluetoothHciH4WithPhdr;
+        let direction = PacketDirection::HostToController;
+        let mut files = Vec::new();
+        for (interface, millis) in [("phone-BLUETOOTH-1", [0, 20]), ("watch-BLUETOOTH-2", [10, 20])]
+        {
+            let mut pcapng = Vec::new();
+            write_pcapng_header(&mut pcapng, link_type, interface, "netsim").unwrap();
+            for millis in millis {
+                let timestamp = Duration::from_millis(millis);
+                append_record(timestamp, &mut pcapng, 0, link_type, direction, 1, &[10, 32], None)
+                    .unwrap();
+            }
+            files.push((link_type, interface.to_string(), pcapng));
+        }
+        let merged = merge(&files, "netsim").unwrap();
+        // The records are in timestamp order, the first file first at the
+        // same timestamp, each with the interface of its file.
+        let blocks = enhanced_packet_blocks(&merged).unwrap();
+        let interfaces: Vec<(u128, u32)> = blocks
+            .iter()
+            .map(|(timestamp, block)| (timestamp.as_millis(), field(block, 8).unwrap()))
+            .collect();
+        assert_eq!(interfaces, vec![(0, 0), (10, 1), (20, 0), (20, 1)]);
+        let interface = b"watch-BLUETOOTH-2";
+        assert!(merged.windows(interface.len()).any(|bytes| bytes == interface));
+        assert!(merge(&[(link_type, String::new(), vec![0])], "netsim").is_err());
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Built-in behaviors of virtual devices.
+//!
+//! A virtual device has no emulator behind it. Its behavior profile
+//! gives the chips it needs and the packet it sends from them at a fixed
+//! interval, e.g. the advertisement of a BLE beacon.
+
+use crate::devices::device::DeviceIdentifier;
+use frontend_proto::common::ChipKind as ProtoChipKind;
+use frontend_proto::model::device::Behavior as ProtoBehavior;
+use std::time::Duration;
+
+// The maximum length of the advertising data of a legacy advertisement.
+const MAX_ADV_DATA_LEN: usize = 31;
+const MAX_SSID_LEN: usize = 32;
+
+/// What a behavior sends and how often.
+pub struct Profile {
+    /// The kind of the chip the packets are sent from.
+    pub chip_kind: ProtoChipKind,
+    pub interval: Duration,
+    /// Build the packet sent by a device with the id and name.
+    pub packet: fn(DeviceIdentifier, &str) -> Vec<u8>,
+}
+
+/// The profile of a behavior, None for BEHAVIOR_NONE.
+pub fn profile(behavior: ProtoBehavior) -> Option<Profile> {
+    match behavior {
+        ProtoBehavior::BEHAVIOR_NONE => None,
+        ProtoBehavior::BEACON => Some(Profile {
+            chip_kind: ProtoChipKind::BLUETOOTH,
+            interval: Duration::from_millis(100),
+            packet: advertisement,
+        }),
+        // The usual beacon interval of 100 TU.
+        ProtoBehavior::ACCESS_POINT => Some(Profile {
+            chip_kind: ProtoChipKind::WIFI,
+            interval: Duration::from_micros(102_400),
+            packet: beacon_frame,
+        }),
+        ProtoBehavior::ANCHOR => Some(Profile {
+            chip_kind: ProtoChipKind::UWB,
+            interval: Duration::from_millis(200),
+            packet: range_data_notification,
+        }),
+    }
+}
+
+/// A BLE static random address derived from the device id.
+fn ble_address(id: DeviceIdentifier) -> [u8; 6] {
+    let id = id.to_le_bytes();
+    [id[0], id[1], id[2], id[3], 0x00, 0xc0]
+}
+
+/// A locally administered Wi-Fi MAC address derived from the device id.
+fn wifi_address(id: DeviceIdentifier) -> [u8; 6] {
+    let id = id.to_be_bytes();
+    [0x02, 0x00, id[0], id[1], id[2], id[3]]
+}
+
+/// A non-connectable BLE advertising PDU with the flags and the complete
+/// local name, or the shortened name when it does not fit.
+fn advertisement(id: DeviceIdentifier, name: &str) -> Vec<u8> {
+    let flags = [0x02, 0x01, 0x06];
+    let max_name_len = MAX_ADV_DATA_LEN - flags.len() - 2;
+    let (name_type, name) = match name.as_bytes() {
+        name if name.len() > max_name_len => (0x08, &name[..max_name_len]),
+        name => (0x09, name),
+    };
+    let mut adv_data = flags.to_vec();
+    adv_data.extend([name.len() as u8 + 1, name_type]);
+    adv_data.extend(name);
+    // ADV_NONCONN_IND with a random advertiser address.
+    let mut pdu = vec![0x42, (6 + adv_data.len()) as u8];
+    pdu.extend(ble_address(id));
+    pdu.extend(adv_data);
+    pdu
+}
+
+/// An 802.11 beacon frame of an open ESS with the name as SSID.
+fn beacon_frame(id: DeviceIdentifier, name: &str) -> Vec<u8> {
+    let bssid = wifi_address(id);
+    let ssid = &name.as_bytes()[..name.len().min(MAX_SSID_LEN)];
+    // Frame control, duration and the broadcast destination.
+    let mut frame = vec![0x80, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff];
+    frame.extend(bssid);
+    frame.extend(bssid);
+    // Sequence control and timestamp.
+    frame.extend([0u8; 10]);
+    // Beacon interval of 100 TU and the ESS capability.
+    frame.extend([0x64, 0x00, 0x01, 0x00]);
+    frame.extend([0x00, ssid.len() as u8]);
+    frame.extend(ssid);
+    frame
+}
+
+/// A UCI RANGE_DATA_NTF header without ranging measurements.
+fn range_data_notification(_id: DeviceIdentifier, _name: &str) -> Vec<u8> {
+    vec![0x62, 0x00, 0x00, 0x00]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advertisement() {
+        let pdu = advertisement(0x1234, "tag");
+        assert_eq!(pdu[..2], [0x42, 14]);
+        assert_eq!(pdu[2..8], [0x34, 0x12, 0x00, 0x00, 0x00, 0xc0]);
+        assert_eq!(pdu[8..], [0x02, 0x01, 0x06, 0x04, 0x09, b't', b'a', b'g']);
+        // A long name is shortened to fit the advertising data.
+        let pdu = advertisement(1, &"x".repeat(40));
+        assert_eq!(pdu.len(), 2 + 6 + MAX_ADV_DATA_LEN);
+        assert_eq!(pdu[12], 0x08);
+    }
+
+    #[test]
+    fn test_beacon_frame() {
+        let frame = beacon_frame(7, "lab-ap");
+        assert_eq!(frame[..2], [0x80, 0x00]);
+        assert_eq!(frame[10..16], [0x02, 0x00, 0x00, 0x00, 0x00, 0x07]);
+        assert_eq!(frame[36..], [0x00, 6, b'l', b'a', b'b', b'-', b'a', b'p']);
+    }
+}
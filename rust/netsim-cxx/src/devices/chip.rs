@@ -17,22 +17,24 @@
 ///
 /// The chip facade is a library that implements the controller protocol.
 ///
-use crate::devices::facades::FacadeIdentifier;
-use crate::devices::facades::*;
+use crate::devices::device::DeviceIdentifier;
+use crate::devices::facades::{get_facade, radio_kind, ChipFacade, FacadeIdentifier};
 use crate::devices::id_factory::IdFactory;
+use crate::devices::traffic;
 use frontend_proto::common::ChipKind as ProtoChipKind;
+use frontend_proto::model::chip::AntennaPattern;
 use frontend_proto::model::Chip as ProtoChip;
 use frontend_proto::model::State as ProtoState;
 use lazy_static::lazy_static;
 use protobuf::EnumOrUnknown;
-
-use std::sync::RwLock;
-
-use super::facades;
-use crate::devices::device::DeviceIdentifier;
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
 
 pub type ChipIdentifier = i32;
 
+/// Sends packets from a chip, see `Chip::transmitter`.
+pub type Transmitter = Box<dyn Fn(&[u8]) + Send>;
+
 // Allocator for chip identifiers.
 lazy_static! {
     static ref IDS: RwLock<IdFactory<ChipIdentifier>> = RwLock::new(IdFactory::new(2000, 1));
@@ -41,6 +43,7 @@ lazy_static! {
 pub struct Chip {
     pub id: ChipIdentifier,
     pub facade_id: FacadeIdentifier,
+    facade: Arc<dyn ChipFacade>,
     pub kind: ProtoChipKind,
     pub name: String,
     // TODO: may not be necessary
@@ -49,12 +52,15 @@ pub struct Chip {
     manufacturer: String,
     product_name: String,
     capture: ProtoState,
+    pub antenna: AntennaPattern,
 }
 
 impl Chip {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         id: ChipIdentifier,
         facade_id: FacadeIdentifier,
+        facade: Arc<dyn ChipFacade>,
         kind: ProtoChipKind,
         name: &str,
         device_name: &str,
@@ -64,12 +70,14 @@ impl Chip {
         Self {
             id,
             facade_id,
+            facade,
             kind,
             name: name.to_string(),
             device_name: device_name.to_string(),
             manufacturer: manufacturer.to_string(),
             product_name: product_name.to_string(),
             capture: ProtoState::OFF,
+            antenna: AntennaPattern::ISOTROPIC,
         }
     }
 
@@ -82,17 +90,9 @@ impl Chip {
         chip.manufacturer = self.manufacturer.clone();
         chip.product_name = self.product_name.clone();
         chip.capture = EnumOrUnknown::new(self.capture);
-        match chip.kind.enum_value() {
-            Ok(ProtoChipKind::BLUETOOTH) => {
-                chip.set_bt(hci_get(self.facade_id));
-            }
-            Ok(ProtoChipKind::WIFI) => {
-                chip.set_wifi(wifi_get(self.facade_id));
-            }
-            _ => {
-                eprint!("Unknown chip kind: {:?}", chip.kind);
-            }
-        }
+        chip.antenna = EnumOrUnknown::new(self.antenna);
+        chip.chip = Some(self.facade.get(self.facade_id));
+        chip.traffic = Some(traffic::get(self.id, Instant::now())).into();
         chip
     }
 
@@ -104,6 +104,11 @@ impl Chip {
                 self.capture = patch_capture;
             }
         }
+        if let Ok(patch_antenna) = patch.antenna.enum_value() {
+            if patch_antenna != AntennaPattern::ANTENNA_UNSPECIFIED {
+                self.antenna = patch_antenna;
+            }
+        }
         if !patch.manufacturer.is_empty() {
             self.manufacturer = patch.manufacturer.clone();
         }
@@ -111,45 +116,42 @@ impl Chip {
             self.product_name = patch.product_name.clone();
         }
         // Check both ChipKind and RadioKind fields, they should be consistent
-        if self.kind == ProtoChipKind::BLUETOOTH && patch.has_bt() {
-            facades::hci_patch(self.facade_id, patch.bt());
-        } else if self.kind == ProtoChipKind::WIFI && patch.has_wifi() {
-            wifi_patch(self.facade_id, patch.wifi());
-        } else {
-            eprint!("Unknown chip kind or missing radio: {:?}", self.kind);
+        match &patch.chip {
+            Some(radio) if radio_kind(radio) == self.kind => {
+                self.facade.patch(self.facade_id, radio);
+            }
+            _ => eprint!("Unknown chip kind or missing radio: {:?}", self.kind),
         }
     }
 
     pub fn remove(&mut self) {
-        match self.kind {
-            ProtoChipKind::BLUETOOTH => {
-                hci_remove(self.facade_id);
-            }
-            ProtoChipKind::WIFI => {
-                wifi_remove(self.facade_id);
-            }
-            _ => {
-                eprint!("Unknown chip kind: {:?}", self.kind);
-            }
-        }
+        traffic::remove(self.id);
+        self.facade.remove(self.facade_id);
+    }
+
+    /// A function sending packets from the chip through its facade. It is
+    /// called without the device lock, as the facades look up the devices
+    /// a packet is delivered to.
+    pub fn transmitter(&self) -> Transmitter {
+        let (kind, facade_id, facade) = (self.kind, self.facade_id, Arc::clone(&self.facade));
+        Box::new(move |packet| {
+            traffic::record(kind, facade_id, traffic::Direction::Tx, packet.len(), Instant::now());
+            facade.transmit(facade_id, packet);
+        })
     }
 
     pub fn reset(&mut self) {
-        match self.kind {
-            ProtoChipKind::BLUETOOTH => {
-                hci_reset(self.facade_id);
-            }
-            ProtoChipKind::WIFI => {
-                wifi_reset(self.facade_id);
-            }
-            _ => {
-                eprint!("Unknown chip kind: {:?}", self.kind);
-            }
-        }
+        self.antenna = AntennaPattern::ISOTROPIC;
+        traffic::reset(self.id);
+        self.facade.reset(self.facade_id);
     }
 }
 
-/// Allocates a new chip with a facade_id.
+/// Allocates a new chip with a facade_id from the facade registered for
+/// the chip kind. The chip gets a new identifier unless a previous
+/// identifier of the chip is given.
+///
+/// Returns None when no facade is registered for the chip kind.
 pub fn chip_new(
     device_id: DeviceIdentifier,
     chip_kind: ProtoChipKind,
@@ -157,22 +159,76 @@ pub fn chip_new(
     device_name: &str,
     chip_manufacturer: &str,
     chip_product_name: &str,
-) -> Chip {
-    let id = IDS.write().unwrap().next_id();
-    let facade_id = match chip_kind {
-        ProtoChipKind::BLUETOOTH => facades::hci_add(device_id),
-        ProtoChipKind::WIFI => facades::wifi_add(device_id),
-        _ => {
-            panic!("Unknown chip kind: {:?}", chip_kind);
+    previous_id: Option<ChipIdentifier>,
+) -> Option<Chip> {
+    let facade = match get_facade(chip_kind) {
+        Some(facade) => facade,
+        None => {
+            eprintln!("No chip facade for chip kind: {:?}", chip_kind);
+            return None;
         }
     };
-    Chip::new(
+    let id = previous_id.unwrap_or_else(|| IDS.write().unwrap().next_id());
+    let facade_id = facade.add(device_id);
+    traffic::add(id, chip_kind, facade_id);
+    Some(Chip::new(
         id,
         facade_id,
+        facade,
         chip_kind,
         chip_name,
         device_name,
         chip_manufacturer,
         chip_product_name,
-    )
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frontend_proto::model::chip::Chip as ProtoChipRadio;
+    use frontend_proto::model::chip::Radio as ProtoRadioChip;
+    use std::sync::Mutex;
+
+    // A facade that records the calls made by the chip.
+    #[derive(Default)]
+    struct FakeFacade {
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl ChipFacade for FakeFacade {
+        fn add(&self, device_id: DeviceIdentifier) -> FacadeIdentifier {
+            self.calls.lock().unwrap().push(format!("add {device_id}"));
+            7
+        }
+        fn remove(&self, facade_id: FacadeIdentifier) {
+            self.calls.lock().unwrap().push(format!("remove {facade_id}"));
+        }
+        fn get(&self, _facade_id: FacadeIdentifier) -> ProtoChipRadio {
+            ProtoChipRadio::Wifi(ProtoRadioChip::new())
+        }
+        fn patch(&self, facade_id: FacadeIdentifier, _patch: &ProtoChipRadio) {
+            self.calls.lock().unwrap().push(format!("patch {facade_id}"));
+        }
+        fn reset(&self, facade_id: FacadeIdentifier) {
+            self.calls.lock().unwrap().push(format!("reset {facade_id}"));
+        }
+    }
+
+    #[test]
+    fn test_chip_uses_facade() {
+        let facade = Arc::new(FakeFacade::default());
+        let mut chip =
+            Chip::new(1, 7, facade.clone(), ProtoChipKind::WIFI, "wifi", "device", "", "");
+        assert!(chip.get().has_wifi());
+        let mut patch = ProtoChip::new();
+        patch.set_wifi(ProtoRadioChip::new());
+        chip.patch(&patch);
+        // A patch for another radio is not sent to the facade.
+        patch.set_uwb(ProtoRadioChip::new());
+        chip.patch(&patch);
+        chip.reset();
+        chip.remove();
+        assert_eq!(*facade.calls.lock().unwrap(), vec!["patch 7", "reset 7", "remove 7"]);
+    }
 }
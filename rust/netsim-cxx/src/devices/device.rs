@@ -20,10 +20,13 @@ use crate::devices::chip;
 use crate::devices::chip::Chip;
 use crate::devices::chip::ChipIdentifier;
 use crate::devices::facades::FacadeIdentifier;
+use crate::devices::zones::DEFAULT_ZONE;
 use frontend_proto::common::ChipKind as ProtoChipKind;
+use frontend_proto::model::device::Behavior as ProtoBehavior;
 use frontend_proto::model::Device as ProtoDevice;
 use frontend_proto::model::Orientation as ProtoOrientation;
 use frontend_proto::model::Position as ProtoPosition;
+use frontend_proto::model::State as ProtoState;
 use std::collections::HashMap;
 
 pub type DeviceIdentifier = i32;
@@ -31,10 +34,14 @@ pub type DeviceIdentifier = i32;
 pub struct Device {
     pub id: DeviceIdentifier,
     pub guid: String,
-    name: String,
-    visible: bool,
+    pub name: String,
+    pub visible: ProtoState,
     pub position: ProtoPosition,
-    orientation: ProtoOrientation,
+    pub orientation: ProtoOrientation,
+    pub zone: String,
+    // The built-in behavior of a virtual device, fixed at creation.
+    pub behavior: ProtoBehavior,
+    pub labels: HashMap<String, String>,
     pub chips: HashMap<ChipIdentifier, Chip>,
 }
 impl Device {
@@ -43,9 +50,12 @@ impl Device {
             id,
             guid,
             name,
-            visible: true,
+            visible: ProtoState::ON,
             position: ProtoPosition::new(),
             orientation: ProtoOrientation::new(),
+            zone: DEFAULT_ZONE.to_string(),
+            behavior: ProtoBehavior::BEHAVIOR_NONE,
+            labels: HashMap::new(),
             chips: HashMap::new(),
         }
     }
@@ -62,9 +72,12 @@ impl Device {
         let mut device = ProtoDevice::new();
         device.id = self.id;
         device.name = self.name.clone();
-        device.visible = self.visible;
+        device.visible = self.visible.into();
         device.position = protobuf::MessageField::from(Some(self.position.clone()));
         device.orientation = protobuf::MessageField::from(Some(self.orientation.clone()));
+        device.zone = self.zone.clone();
+        device.behavior = self.behavior.into();
+        device.labels = self.labels.clone();
         for chip in self.chips.values() {
             device.chips.push(chip.get());
         }
@@ -72,38 +85,70 @@ impl Device {
     }
 
     /// Patch a device and its chips.
-    pub fn patch(&mut self, patch: &ProtoDevice) {
-        // TODO visible should be State
-        self.visible = patch.visible;
+    ///
+    /// Returns the identifiers of the chips that were patched.
+    pub fn patch(&mut self, patch: &ProtoDevice) -> Vec<ChipIdentifier> {
+        let mut patched_chip_ids = Vec::new();
+        if let Ok(patch_visible) = patch.visible.enum_value() {
+            if patch_visible != ProtoState::UNKNOWN {
+                self.visible = patch_visible;
+            }
+        }
         if patch.position.is_some() {
             self.position.clone_from(&patch.position);
         }
         if patch.orientation.is_some() {
             self.orientation.clone_from(&patch.orientation);
         }
+        if !patch.zone.is_empty() {
+            self.zone.clone_from(&patch.zone);
+        }
+        // An empty label value removes the label.
+        for (key, value) in patch.labels.iter() {
+            if value.is_empty() {
+                self.labels.remove(key);
+            } else {
+                self.labels.insert(key.clone(), value.clone());
+            }
+        }
         // iterate over patched ProtoChip entries and patch matching chip
         for patch_chip in patch.chips.iter() {
             // Allow default chip kind of BLUETOOTH
-            let patch_chip_kind = patch_chip.kind.enum_value_or(ProtoChipKind::BLUETOOTH);
+            let patch_chip_kind = match patch_chip.kind.enum_value_or_default() {
+                ProtoChipKind::UNSPECIFIED => ProtoChipKind::BLUETOOTH,
+                kind => kind,
+            };
             let patch_chip_name = &patch_chip.name;
-            // Find the matching chip and patch the proto chip
+            // Find the matching chip and patch the proto chip. A patch without
+            // a chip name (e.g. from the CLI radio command) applies to every
+            // chip of that kind.
             for chip in self.chips.values_mut() {
-                if chip.name.eq(patch_chip_name) && chip.kind == patch_chip_kind {
+                if chip.kind != patch_chip_kind {
+                    continue;
+                }
+                if patch_chip_name.is_empty() {
+                    chip.patch(patch_chip);
+                    patched_chip_ids.push(chip.id);
+                } else if chip.name.eq(patch_chip_name) {
                     chip.patch(patch_chip);
+                    patched_chip_ids.push(chip.id);
                     break; // next proto chip
                 }
             }
         }
+        patched_chip_ids
     }
 
     /// Remove a chip from a device.
-    pub fn remove_chip(&mut self, chip_id: ChipIdentifier) {
+    ///
+    /// Returns true if the chip was found and removed.
+    pub fn remove_chip(&mut self, chip_id: ChipIdentifier) -> bool {
         if let Some(chip) = self.chips.get_mut(&chip_id) {
             chip.remove();
         } else {
             eprintln!("RemoveChip id {chip_id} not found");
         }
-        self.chips.remove(&chip_id);
+        self.chips.remove(&chip_id).is_some()
     }
 
     pub fn add_chip(
@@ -113,6 +158,7 @@ impl Device {
         chip_name: &str,
         chip_manufacturer: &str,
         chip_product_name: &str,
+        previous_chip_id: Option<ChipIdentifier>,
     ) -> Option<AddChipResult> {
         for chip in self.chips.values() {
             if chip.kind == chip_kind && chip.name == chip_name {
@@ -127,16 +173,18 @@ impl Device {
             device_name,
             chip_manufacturer,
             chip_product_name,
-        );
+            previous_chip_id,
+        )?;
         let chip_id = chip.id;
         let facade_id = chip.facade_id;
         self.chips.insert(chip.id, chip);
         Some(AddChipResult { device_id: self.id, chip_id, facade_id })
     }
 
-    /// Reset a device to its default state.
+    /// Reset a device to its default state. The device keeps its zone and
+    /// labels.
     pub fn reset(&mut self) {
-        self.visible = true;
+        self.visible = ProtoState::ON;
         self.position.clear();
         self.orientation.clear();
         for chp in self.chips.values_mut() {
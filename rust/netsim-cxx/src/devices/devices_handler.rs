@@ -19,55 +19,253 @@
 // The Devices struct is a singleton for the devices collection.
 //
 // Additional functions are
-// -- inactivity instant
+// -- inactivity notifications for the lifecycle manager
+// -- resolving device names for the link loss model
+// -- moving devices along their trajectories
+// -- partitioning devices into radio zones
+// -- restoring the state of reconnecting devices
+// -- creating virtual devices and running their behaviors
 // -- vending device identifiers
 
+use super::behavior;
 use super::chip::ChipIdentifier;
 use super::device::DeviceIdentifier;
+use super::events;
+use super::events::Event;
+use super::facades::FacadeIdentifier;
+use super::filter::{parse_chip_kind, DeviceFilter};
 use super::id_factory::IdFactory;
+use super::link_loss;
+use super::reconnect::{ChipIdentifiers, Departed};
+use super::traffic;
+use super::trajectory::Trajectory;
+use super::zones::{Zones, DEFAULT_ZONE};
 use crate::devices::device::AddChipResult;
 use crate::devices::device::Device;
+use crate::http_server::http_request::HttpRequest;
+use crate::http_server::server_response::ResponseWritable;
+use crate::lifecycle;
+use crate::ranging::{antenna_rssi, reference_tx_power, Antenna};
 use frontend_proto::common::ChipKind as ProtoChipKind;
+use frontend_proto::frontend::device_distance::Rssi as ProtoDeviceDistanceRssi;
+use frontend_proto::frontend::AssignZoneRequest;
+use frontend_proto::frontend::CreateDeviceRequest;
+use frontend_proto::frontend::CreateDeviceResponse;
+use frontend_proto::frontend::DeviceDistance;
+use frontend_proto::frontend::Event as ProtoEvent;
+use frontend_proto::frontend::GetDevicesRequest;
+use frontend_proto::frontend::GetDistancesResponse;
+use frontend_proto::frontend::ListLinkLossResponse;
+use frontend_proto::frontend::ListZonesResponse;
+use frontend_proto::frontend::PatchDeviceRequest;
+use frontend_proto::frontend::PatchZoneRequest;
+use frontend_proto::frontend::SetLinkLossRequest;
+use frontend_proto::frontend::SetTrajectoryRequest;
+use frontend_proto::model::chip::AntennaPattern;
+use frontend_proto::model::device::Behavior as ProtoBehavior;
 use frontend_proto::model::Device as ProtoDevice;
+use frontend_proto::model::PhyKind as ProtoPhyKind;
 use frontend_proto::model::Position as ProtoPosition;
+use frontend_proto::model::RadioTraffic as ProtoRadioTraffic;
 use frontend_proto::model::Scene as ProtoScene;
+use frontend_proto::model::State as ProtoState;
 use lazy_static::lazy_static;
+use protobuf::Enum;
 use protobuf_json_mapping::merge_from_str;
 use protobuf_json_mapping::print_to_string;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::Once;
 use std::sync::RwLock;
 use std::sync::RwLockWriteGuard;
-use std::time::Duration;
-use std::time::Instant;
+use std::thread;
+use std::time::{Duration, Instant};
 
 lazy_static! {
     static ref DEVICES: RwLock<Devices> = RwLock::new(Devices::new());
 }
-static IDLE_SECS_FOR_SHUTDOWN: u64 = 120;
+
+// Devices with a trajectory are moved on every tick.
+const TRAJECTORY_TICK_INTERVAL: Duration = Duration::from_millis(100);
+static TRAJECTORY_THREAD: Once = Once::new();
+
+// Virtual devices send the packets of their behavior on every tick.
+const BEHAVIOR_TICK_INTERVAL: Duration = Duration::from_millis(50);
+static BEHAVIOR_THREAD: Once = Once::new();
+
+// The guid prefix of virtual devices, which have no transport.
+const VIRTUAL_GUID_PREFIX: &str = "virtual:";
+
+/// The error of a request to the device handlers.
+#[derive(Debug, PartialEq)]
+pub enum RequestError {
+    /// A malformed or invalid request, Http status 400.
+    BadRequest(String),
+    /// A request for a device, chip or zone that does not exist, Http status 404.
+    NotFound(String),
+}
+
+impl RequestError {
+    fn status_code(&self) -> u16 {
+        match self {
+            RequestError::BadRequest(_) => 400,
+            RequestError::NotFound(_) => 404,
+        }
+    }
+}
+
+impl fmt::Display for RequestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RequestError::BadRequest(message) | RequestError::NotFound(message) => {
+                write!(f, "{message}")
+            }
+        }
+    }
+}
+
+// The validation errors of the link loss model, zones and trajectories
+// are bad requests.
+impl From<String> for RequestError {
+    fn from(message: String) -> Self {
+        RequestError::BadRequest(message)
+    }
+}
+
+impl From<&str> for RequestError {
+    fn from(message: &str) -> Self {
+        RequestError::BadRequest(message.to_string())
+    }
+}
+
+// Respond with an empty json object, or with the status of the error.
+fn put_result(writer: ResponseWritable, result: Result<(), RequestError>) {
+    match result {
+        Ok(()) => writer.put_ok("text/json", "{}", &[]),
+        Err(err) => writer.put_error(err.status_code(), &err.to_string()),
+    }
+}
+
+/// A device behind its own lock, so that operations on different devices
+/// run in parallel.
+///
+/// The DEVICES lock is held for reading while a device is used, and for
+/// writing only to add or remove devices and to change the scene wide
+/// state. The DEVICES lock and the trajectories and behaviors locks are
+/// taken before a device lock, never while a device lock is held.
+type DeviceRef = Arc<RwLock<Device>>;
 
 /// The Device resource is a singleton that manages all devices.
 struct Devices {
-    devices: HashMap<DeviceIdentifier, Device>,
+    devices: HashMap<DeviceIdentifier, DeviceRef>,
+    // The devices by guid, and by name as names need not be unique.
+    guids: HashMap<String, DeviceIdentifier>,
+    names: HashMap<String, BTreeSet<DeviceIdentifier>>,
     id_factory: IdFactory<DeviceIdentifier>,
-    pub idle_since: Option<Instant>,
+    // Restored scene entries, by device name, waiting for their device
+    // or some of its chips to connect.
+    pending_scene: HashMap<String, ProtoDevice>,
+    // Trajectories of the moving devices and their start time.
+    trajectories: Mutex<HashMap<DeviceIdentifier, (Trajectory, Instant)>>,
+    zones: Zones,
+    // Departed devices waiting for their reconnection.
+    departed: Departed,
+    // Previous chip identifiers of the reconnected devices, by device.
+    previous_chip_ids: HashMap<DeviceIdentifier, ChipIdentifiers>,
+    // The time of the next packet of the virtual devices.
+    behaviors: Mutex<HashMap<DeviceIdentifier, Instant>>,
 }
 
 impl Devices {
     fn new() -> Self {
-        Devices { devices: HashMap::new(), id_factory: IdFactory::new(1000, 1), idle_since: None }
+        Devices {
+            devices: HashMap::new(),
+            guids: HashMap::new(),
+            names: HashMap::new(),
+            id_factory: IdFactory::new(1000, 1),
+            pending_scene: HashMap::new(),
+            trajectories: Mutex::new(HashMap::new()),
+            zones: Zones::new(),
+            departed: Departed::new(),
+            previous_chip_ids: HashMap::new(),
+            behaviors: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn get(&self, id: DeviceIdentifier) -> Result<&DeviceRef, RequestError> {
+        self.devices
+            .get(&id)
+            .ok_or_else(|| RequestError::NotFound(format!("No such device with id {id}")))
+    }
+
+    fn insert(&mut self, device: Device) {
+        self.guids.insert(device.guid.clone(), device.id);
+        self.names.entry(device.name.clone()).or_default().insert(device.id);
+        self.devices.insert(device.id, Arc::new(RwLock::new(device)));
     }
-}
 
-#[allow(dead_code)]
-fn notify_all() {
-    // TODO
+    fn remove(&mut self, id: DeviceIdentifier) -> Option<DeviceRef> {
+        let device_ref = self.devices.remove(&id)?;
+        let device = device_ref.read().unwrap();
+        self.guids.remove(&device.guid);
+        if let Some(ids) = self.names.get_mut(&device.name) {
+            ids.remove(&id);
+            if ids.is_empty() {
+                self.names.remove(&device.name);
+            }
+        }
+        drop(device);
+        Some(device_ref)
+    }
+
+    /// Find the device matching a name or guid.
+    ///
+    /// An exact match on the device name wins, followed by an exact match
+    /// on the transport guid. Otherwise the name is matched as a substring
+    /// of the device names. Names shared by several devices and substrings
+    /// of several names are ambiguous.
+    fn match_device(&self, name: &str) -> Result<DeviceIdentifier, RequestError> {
+        if name.is_empty() {
+            return Err("Patch requires a device id or name".into());
+        }
+        if let Some(ids) = self.names.get(name) {
+            if ids.len() > 1 {
+                let ids: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+                return Err(RequestError::BadRequest(format!(
+                    "Multiple devices are named {name}, with ids {}",
+                    ids.join(", ")
+                )));
+            }
+            if let Some(id) = ids.first() {
+                return Ok(*id);
+            }
+        }
+        if let Some(id) = self.guids.get(name) {
+            return Ok(*id);
+        }
+        let mut matches = self
+            .names
+            .iter()
+            .filter(|(device_name, _)| device_name.contains(name))
+            .flat_map(|(_, ids)| ids);
+        match (matches.next(), matches.next()) {
+            (Some(id), None) => Ok(*id),
+            (Some(_), Some(_)) => Err(RequestError::BadRequest(format!(
+                "Multiple ambiguous matches were found with substring {name}"
+            ))),
+            _ => Err(RequestError::NotFound(format!("No such device with name {name}"))),
+        }
+    }
 }
 
 /// add_chip is called by the transport layer when a new chip is attached.
 ///
 /// The guid is a transport layer identifier for the device (host:port)
 /// that is adding the chip.
-#[allow(dead_code)]
 pub fn add_chip(
     device_guid: &str,
     device_name: &str,
@@ -76,152 +274,1223 @@ pub fn add_chip(
     chip_manufacturer: &str,
     chip_product_name: &str,
 ) -> AddChipResult {
-    let mut resource = DEVICES.write().unwrap();
-    resource.idle_since = None;
-    let device_id = get_or_create_device(&mut resource, device_guid, device_name);
-    // This is infrequent, so we can afford to do another lookup for the device.
-    resource
-        .devices
-        .get_mut(&device_id)
-        .unwrap()
-        .add_chip(device_name, chip_kind, chip_name, chip_manufacturer, chip_product_name)
-        .unwrap_or_else(|| {
+    lifecycle::set_active();
+    let mut pending = Vec::new();
+    // Add the chip under the device lock only, so that the other devices
+    // are not held up while the facade is created. The device lock is
+    // taken before the DEVICES lock is released so that the device is not
+    // removed in the meantime.
+    let device_ref: Arc<RwLock<Device>>;
+    let resource = DEVICES.read().unwrap();
+    // The DEVICES write lock is only needed to create the device or to
+    // take the chip ids kept for its reconnection.
+    let known_id = resource
+        .guids
+        .get(device_guid)
+        .copied()
+        .filter(|id| !resource.previous_chip_ids.contains_key(id));
+    let (device_id, previous_chip_id, has_pending_scene, mut device) = match known_id {
+        Some(device_id) => {
+            let has_pending_scene = resource.pending_scene.contains_key(device_name);
+            device_ref = Arc::clone(&resource.devices[&device_id]);
+            let device = device_ref.write().unwrap();
+            drop(resource);
+            (device_id, None, has_pending_scene, device)
+        }
+        None => {
+            drop(resource);
+            let mut resource = DEVICES.write().unwrap();
+            let device_id =
+                get_or_create_device(&mut resource, device_guid, device_name, &mut pending);
+            let previous_chip_id = resource
+                .previous_chip_ids
+                .get_mut(&device_id)
+                .and_then(|chip_ids| chip_ids.remove(&(chip_kind, chip_name.to_string())));
+            let has_pending_scene = resource.pending_scene.contains_key(device_name);
+            device_ref = Arc::clone(&resource.devices[&device_id]);
+            let device = device_ref.write().unwrap();
+            (device_id, previous_chip_id, has_pending_scene, device)
+        }
+    };
+    match device.add_chip(
+        device_name,
+        chip_kind,
+        chip_name,
+        chip_manufacturer,
+        chip_product_name,
+        previous_chip_id,
+    ) {
+        Some(result) => {
+            pending.push(Event::ChipAdded { device_id, chip_id: result.chip_id, kind: chip_kind });
+            drop(device);
+            if has_pending_scene {
+                apply_pending_scene(&mut DEVICES.write().unwrap(), device_id, &mut pending);
+            }
+            events::publish_all(pending);
+            result
+        }
+        None => {
+            drop(device);
+            events::publish_all(pending);
             eprintln!("Error adding chip to device {}", device_id);
             AddChipResult { device_id: 0, chip_id: 0, facade_id: 0 }
-        })
+        }
+    }
 }
 
 /// Get or create a device.
+///
+/// A device that reconnects within the grace period is restored to its
+/// last known state as its chips are added, and gets its previous
+/// identifiers back when configured. The events are added to `pending`
+/// for the caller to publish once the locks are released.
 fn get_or_create_device(
     resource: &mut RwLockWriteGuard<Devices>,
     guid: &str,
     name: &str,
+    pending: &mut Vec<Event>,
 ) -> DeviceIdentifier {
     // Check if a device with the given guid already exists
-    if let Some(existing_device) = resource.devices.values().find(|d| d.guid == guid) {
+    if let Some(existing_id) = resource.guids.get(guid) {
         // A device with the same guid already exists, return it
-        existing_device.id
+        *existing_id
     } else {
         // No device with the same guid exists, insert the new device
-        let new_id = resource.id_factory.next_id();
-        resource.devices.insert(new_id, Device::new(new_id, guid.to_string(), name.to_string()));
+        let departed = resource.departed.take(guid, name, Instant::now());
+        let new_id = match &departed {
+            Some(departed)
+                if resource.departed.same_ids && !resource.devices.contains_key(&departed.id) =>
+            {
+                departed.id
+            }
+            _ => resource.id_factory.next_id(),
+        };
+        resource.insert(Device::new(new_id, guid.to_string(), name.to_string()));
+        pending.push(Event::DeviceAdded { id: new_id, name: name.to_string() });
+        if let Some(departed) = departed {
+            resource.pending_scene.insert(name.to_string(), departed.snapshot);
+            if resource.departed.same_ids {
+                resource.previous_chip_ids.insert(new_id, departed.chip_ids);
+            }
+        }
         new_id
     }
 }
 
 /// Remove a device from the simulation.
 ///
-/// Called when the last chip for the device is removed.
-fn remove_device(resource: &mut RwLockWriteGuard<Devices>, id: DeviceIdentifier) {
-    resource.devices.remove(&id).or_else(|| {
-        eprintln!("Error removing device id {id}");
-        None
-    });
+/// Called when the last chip for the device is removed. The events are
+/// added to `pending` for the caller to publish once the locks are released.
+fn remove_device(
+    resource: &mut RwLockWriteGuard<Devices>,
+    id: DeviceIdentifier,
+    pending: &mut Vec<Event>,
+) {
+    match resource.remove(id) {
+        Some(device) => {
+            let name = device.read().unwrap().name.clone();
+            pending.push(Event::DeviceRemoved { id, name });
+        }
+        None => eprintln!("Error removing device id {id}"),
+    }
+    link_loss::remove_device_links(id);
+    resource.trajectories.lock().unwrap().remove(&id);
+    resource.previous_chip_ids.remove(&id);
+    resource.behaviors.lock().unwrap().remove(&id);
     if resource.devices.is_empty() {
-        resource.idle_since = Some(Instant::now());
+        lifecycle::set_idle();
     }
 }
 
 /// Remove a chip from a device.
 ///
-/// Called when the packet transport for the chip shuts down.
-#[allow(dead_code)]
+/// Called when the packet transport for the chip shuts down. The device
+/// departs with its last chip and its state is kept for its reconnection.
 pub fn remove_chip(device_id: DeviceIdentifier, chip_id: ChipIdentifier) {
     let mut resource = DEVICES.write().unwrap();
-    let mut is_empty = false;
-    resource.devices.entry(device_id).and_modify(|device| {
-        device.remove_chip(chip_id);
-        is_empty = device.chips.is_empty();
-    });
-    if is_empty {
-        remove_device(&mut resource, device_id);
+    let device_ref = match resource.devices.get(&device_id) {
+        Some(device_ref) => device_ref.clone(),
+        None => return,
+    };
+    let mut device = device_ref.write().unwrap();
+    // Take the snapshot while the last chip is still attached.
+    let snapshot =
+        (device.chips.len() == 1 && device.chips.contains_key(&chip_id)).then(|| device.get());
+    let mut pending = Vec::new();
+    if device.remove_chip(chip_id) {
+        pending.push(Event::ChipRemoved { device_id, chip_id });
+    }
+    if device.chips.is_empty() {
+        let guid = device.guid.clone();
+        drop(device);
+        if let Some(snapshot) = snapshot {
+            resource.departed.insert(device_id, &guid, snapshot, Instant::now());
+        }
+        remove_device(&mut resource, device_id, &mut pending);
+    } else {
+        drop(device);
     }
+    drop(resource);
+    events::publish_all(pending);
 }
-/// MATCH
-///
+
+/// The identifiers of a chip added by `add_chip_cxx`.
+pub struct AddChipResultCxx {
+    device_id: u32,
+    chip_id: u32,
+    facade_id: u32,
+}
+
+impl AddChipResultCxx {
+    pub fn get_device_id(&self) -> u32 {
+        self.device_id
+    }
+
+    pub fn get_chip_id(&self) -> u32 {
+        self.chip_id
+    }
+
+    pub fn get_facade_id(&self) -> u32 {
+        self.facade_id
+    }
+}
+
+/// add_chip for the C++ packet transports. The device id is 0 when the
+/// chip could not be added.
+pub fn add_chip_cxx(
+    device_guid: &str,
+    device_name: &str,
+    chip_kind: u32,
+    chip_name: &str,
+    chip_manufacturer: &str,
+    chip_product_name: &str,
+) -> Box<AddChipResultCxx> {
+    let chip_kind = ProtoChipKind::from_i32(chip_kind as i32).unwrap_or_default();
+    let result = add_chip(
+        device_guid,
+        device_name,
+        chip_kind,
+        chip_name,
+        chip_manufacturer,
+        chip_product_name,
+    );
+    Box::new(AddChipResultCxx {
+        device_id: result.device_id as u32,
+        chip_id: result.chip_id as u32,
+        facade_id: result.facade_id as u32,
+    })
+}
+
+/// remove_chip for the C++ packet transports.
+pub fn remove_chip_cxx(device_id: u32, chip_id: u32) {
+    remove_chip(device_id as DeviceIdentifier, chip_id as ChipIdentifier);
+}
+
+/// The kind, identifier and facade identifier of every chip with the
+/// name of its device, used by the captures to follow the chips.
+pub fn get_chip_facades() -> Vec<(ProtoChipKind, ChipIdentifier, FacadeIdentifier, String)> {
+    let resource = DEVICES.read().unwrap();
+    let mut chips = Vec::new();
+    for device in resource.devices.values() {
+        let device = device.read().unwrap();
+        for chip in device.chips.values() {
+            chips.push((chip.kind, chip.id, chip.facade_id, device.name.clone()));
+        }
+    }
+    chips
+}
+
+/// Set how long the state of a departed device is kept for its
+/// reconnection, zero disables restoring reconnecting devices.
+pub fn set_reconnect_grace_secs(secs: u32) {
+    DEVICES.write().unwrap().departed.set_grace_period(Duration::from_secs(secs.into()));
+}
+
+/// Give reconnecting devices and their chips their previous identifiers.
+pub fn set_reconnect_same_ids(same_ids: bool) {
+    DEVICES.write().unwrap().departed.same_ids = same_ids;
+}
+/// Patch a device by id, or by the id or name in the PatchDeviceRequest.
 ///
+/// The patch_json is a PatchDeviceRequest as sent by the CLI and the UI.
+/// When no id is given the device is resolved with `Devices::match_device`.
+pub fn patch_device(
+    id_option: Option<DeviceIdentifier>,
+    patch_json: &str,
+) -> Result<(), RequestError> {
+    let mut request = PatchDeviceRequest::new();
+    merge_from_str(&mut request, patch_json)
+        .map_err(|e| format!("Error parsing PatchDeviceRequest json {patch_json}: {e}"))?;
+    let proto_device = request.device.get_or_default();
+    let resource = DEVICES.read().unwrap();
+    let id = match id_option {
+        Some(id) => id,
+        None if proto_device.id != 0 => proto_device.id,
+        None => resource.match_device(&proto_device.name)?,
+    };
+    if !proto_device.zone.is_empty() && !resource.zones.contains(&proto_device.zone) {
+        return Err(RequestError::NotFound(format!("No such zone {}", proto_device.zone)));
+    }
+    let device = resource.get(id)?;
+    // An explicit move stops the device on its trajectory.
+    if proto_device.position.is_some() {
+        resource.trajectories.lock().unwrap().remove(&id);
+    }
+    let pending = patch_with_events(&mut device.write().unwrap(), proto_device);
+    drop(resource);
+    events::publish_all(pending);
+    Ok(())
+}
 
-// lock the devices, find the id and call the patch function
-#[allow(dead_code)]
-fn patch_device(id: DeviceIdentifier, patch_json: &str) {
-    let mut proto_device = ProtoDevice::new();
-    if merge_from_str(&mut proto_device, patch_json).is_ok() {
-        DEVICES
-            .write()
-            .unwrap()
-            .devices
-            .get_mut(&id)
-            .map(|device_ref| device_ref.patch(&proto_device))
-            .or_else(|| {
-                eprintln!("No such device with id {id}");
-                None
-            });
-    } else {
-        eprintln!("Error parsing device {id} patch json {}", patch_json);
+// Patch the device and return the resulting events, to be published once
+// the locks are released.
+fn patch_with_events(device: &mut Device, patch: &ProtoDevice) -> Vec<Event> {
+    let visible = device.visible;
+    let zone = device.zone.clone();
+    let patched_chip_ids = device.patch(patch);
+    let mut pending = Vec::new();
+    if device.visible != visible {
+        pending.push(Event::VisibilityChanged { id: device.id, visible: device.visible });
+    }
+    if device.zone != zone {
+        pending.push(Event::ZoneChanged { id: device.id, zone: device.zone.clone() });
+    }
+    if patch.position.is_some() || patch.orientation.is_some() {
+        pending.push(Event::PositionChanged {
+            id: device.id,
+            position: device.position.clone(),
+            orientation: device.orientation.clone(),
+        });
     }
+    for chip_id in patched_chip_ids {
+        pending.push(Event::ChipPatched { device_id: device.id, chip_id });
+    }
+    pending
 }
 
 fn distance(a: &ProtoPosition, b: &ProtoPosition) -> f32 {
     ((b.x - a.x).powf(2.0) + (b.y - a.y).powf(2.0) + (b.z - a.z).powf(2.0)).sqrt()
 }
 
-#[allow(dead_code)]
-pub fn get_distance(id: DeviceIdentifier, other_id: DeviceIdentifier) -> f32 {
-    print!("get_distance({:?}, {:?}) = ", id, other_id);
-    let devices = &DEVICES.read().unwrap().devices;
-    let a = devices.get(&id).map(|device_ref| device_ref.position.clone()).or_else(|| {
-        eprintln!("No such device with id {id}");
-        None
-    });
-    let b = devices.get(&other_id).map(|device_ref| device_ref.position.clone()).or_else(|| {
-        eprintln!("No such device with id {id}");
-        None
-    });
-    match (a, b) {
-        (Some(a), Some(b)) => distance(&a, &b),
-        _ => 0.0,
+/// The distance in meters between two devices.
+pub fn get_distance(id: DeviceIdentifier, other_id: DeviceIdentifier) -> Result<f32, RequestError> {
+    let resource = DEVICES.read().unwrap();
+    let position = |id: DeviceIdentifier| -> Result<ProtoPosition, RequestError> {
+        Ok(resource.get(id)?.read().unwrap().position.clone())
+    };
+    Ok(distance(&position(id)?, &position(other_id)?))
+}
+
+// The radios of the chips of a kind.
+fn chip_radios(kind: ProtoChipKind) -> &'static [ProtoPhyKind] {
+    match kind {
+        ProtoChipKind::BLUETOOTH => {
+            &[ProtoPhyKind::BLUETOOTH_LOW_ENERGY, ProtoPhyKind::BLUETOOTH_CLASSIC]
+        }
+        ProtoChipKind::WIFI => &[ProtoPhyKind::WIFI],
+        ProtoChipKind::UWB => &[ProtoPhyKind::UWB],
+        _ => &[],
     }
 }
 
-#[allow(dead_code)]
+/// The distance and the estimated RSSI of every pair of devices as a
+/// GetDistancesResponse json, with the reason why a pair cannot see each
+/// other.
+///
+/// The RSSI of each radio both devices have is the estimate of `get_rssi`
+/// at the reference transmit power of the radio, with the zone attenuation
+/// left out for isolated zones.
+pub fn get_distances() -> String {
+    struct DeviceState {
+        id: DeviceIdentifier,
+        name: String,
+        position: ProtoPosition,
+        visible: ProtoState,
+        zone: String,
+        radios: Vec<ProtoPhyKind>,
+    }
+    let resource = DEVICES.read().unwrap();
+    let mut devices: Vec<DeviceState> = resource
+        .devices
+        .values()
+        .map(|device| {
+            let device = device.read().unwrap();
+            let mut radios: Vec<ProtoPhyKind> = Vec::new();
+            for radio in device.chips.values().flat_map(|chip| chip_radios(chip.kind)) {
+                if !radios.contains(radio) {
+                    radios.push(*radio);
+                }
+            }
+            radios.sort_by_key(|radio| *radio as i32);
+            DeviceState {
+                id: device.id,
+                name: device.name.clone(),
+                position: device.position.clone(),
+                visible: device.visible,
+                zone: device.zone.clone(),
+                radios,
+            }
+        })
+        .collect();
+    devices.sort_by_key(|device| device.id);
+    let mut response = GetDistancesResponse::new();
+    for (i, a) in devices.iter().enumerate() {
+        for b in &devices[i + 1..] {
+            let mut pair = DeviceDistance::new();
+            pair.device_name = a.name.clone();
+            pair.other_device = b.name.clone();
+            pair.distance = distance(&a.position, &b.position);
+            let attenuation = resource.zones.attenuation(&a.zone, &b.zone);
+            pair.unreachable = if a.visible == ProtoState::OFF {
+                format!("{} is invisible", a.name)
+            } else if b.visible == ProtoState::OFF {
+                format!("{} is invisible", b.name)
+            } else if attenuation.is_none() {
+                format!("zones {} and {} are isolated from each other", a.zone, b.zone)
+            } else {
+                String::new()
+            };
+            for radio in a.radios.iter().filter(|radio| b.radios.contains(radio)) {
+                let tx_power = reference_tx_power(*radio);
+                let antenna_rssi =
+                    pair_rssi(&resource, a.id, b.id, radio_chip_kind(*radio), tx_power)
+                        .map_or(i8::MIN, |(rssi, _)| rssi) as f32;
+                let mut rssi = ProtoDeviceDistanceRssi::new();
+                rssi.radio = (*radio).into();
+                rssi.tx_power = tx_power.into();
+                rssi.rssi = (antenna_rssi - attenuation.unwrap_or_default()) as i32;
+                rssi.link_loss = link_loss::get_link_loss(a.id, b.id, *radio);
+                pair.rssi.push(rssi);
+            }
+            response.distances.push(pair);
+        }
+    }
+    print_to_string(&response).unwrap()
+}
+
+/// The Rust distances handler used directly by Http frontend and by the
+/// gRPC frontend through `handle_frontend_cxx`.
+///
+/// GET /v1/distances --> the distance and estimated RSSI of every pair of devices
+pub fn handle_distances(request: &HttpRequest, param: &str, writer: ResponseWritable) {
+    match (request.method.as_str(), param) {
+        ("GET", "") => writer.put_ok("text/json", &get_distances(), &[]),
+        _ => writer.put_error(404, "Not found."),
+    }
+}
+
+// The kind of the chips with a radio.
+fn radio_chip_kind(radio: ProtoPhyKind) -> ProtoChipKind {
+    match radio {
+        ProtoPhyKind::BLUETOOTH_LOW_ENERGY | ProtoPhyKind::BLUETOOTH_CLASSIC => {
+            ProtoChipKind::BLUETOOTH
+        }
+        ProtoPhyKind::WIFI | ProtoPhyKind::WIFI_RTT => ProtoChipKind::WIFI,
+        ProtoPhyKind::UWB => ProtoChipKind::UWB,
+        ProtoPhyKind::NONE => ProtoChipKind::UNSPECIFIED,
+    }
+}
+
+// The RSSI of a transmission between two devices from their distance and
+// the gain of the antennas of their chips of a kind, with the attenuation
+// between their zones, None when the signal does not cross the zones.
+fn pair_rssi(
+    resource: &Devices,
+    id: DeviceIdentifier,
+    other_id: DeviceIdentifier,
+    kind: ProtoChipKind,
+    tx_power: i8,
+) -> Option<(i8, Option<f32>)> {
+    // Copy the pose of one device at a time, the two may be the same.
+    let pose = |id: DeviceIdentifier| {
+        let device = resource.devices.get(&id)?.read().unwrap();
+        let pattern = device
+            .chips
+            .values()
+            .find(|chip| chip.kind == kind)
+            .map_or(AntennaPattern::ISOTROPIC, |chip| chip.antenna);
+        Some((device.position.clone(), device.orientation.clone(), pattern, device.zone.clone()))
+    };
+    let (position, orientation, pattern, zone) = pose(id)?;
+    let (other_position, other_orientation, other_pattern, other_zone) = pose(other_id)?;
+    let antenna = Antenna { position: &position, orientation: &orientation, pattern };
+    let other_antenna = Antenna {
+        position: &other_position,
+        orientation: &other_orientation,
+        pattern: other_pattern,
+    };
+    let rssi = antenna_rssi(tx_power, &antenna, &other_antenna);
+    Some((rssi, resource.zones.attenuation(&zone, &other_zone)))
+}
+
+/// Compute the RSSI (dBm) of a transmission from one device to another on
+/// the chips of a kind, from the distance between the devices, the gain
+/// of the chip antennas given the device orientations and the attenuation
+/// between the zones of the devices.
+///
+/// Returns None for unknown devices and for devices in zones the signal
+/// does not cross.
+pub fn get_rssi(
+    id: DeviceIdentifier,
+    other_id: DeviceIdentifier,
+    kind: ProtoChipKind,
+    tx_power: i8,
+) -> Option<i8> {
+    let (rssi, attenuation) = pair_rssi(&DEVICES.read().unwrap(), id, other_id, kind, tx_power)?;
+    Some((rssi as f32 - attenuation?) as i8)
+}
+
+/// The RSSI for the radio facades, see `get_rssi`. The weakest RSSI when
+/// either device is unknown or the signal does not reach the other.
+pub fn get_rssi_cxx(device_id: u32, other_device_id: u32, chip_kind: u32, tx_power: i8) -> i8 {
+    let kind = ProtoChipKind::from_i32(chip_kind as i32).unwrap_or_default();
+    get_rssi(device_id as DeviceIdentifier, other_device_id as DeviceIdentifier, kind, tx_power)
+        .unwrap_or(i8::MIN)
+}
+
 pub fn get_devices() -> String {
+    get_filtered_devices(&DeviceFilter::default())
+}
+
+/// The scene with the devices that match the filter, and all the zones
+/// and radios.
+fn get_filtered_devices(filter: &DeviceFilter) -> String {
     let mut scene = ProtoScene::new();
-    // iterate over the devices and add each to the scene
-    DEVICES.read().unwrap().devices.values().for_each(|device| {
-        scene.devices.push(device.get());
-    });
+    let resource = DEVICES.read().unwrap();
+    // iterate over the matching devices and add each to the scene
+    for device in resource.devices.values() {
+        let device = device.read().unwrap();
+        if filter.matches(&device) {
+            scene.devices.push(device.get());
+        }
+    }
+    scene.zones = resource.zones.list();
+    for (kind, stats) in traffic::get_radios(Instant::now()) {
+        let mut radio = ProtoRadioTraffic::new();
+        radio.kind = kind.into();
+        radio.traffic = Some(stats).into();
+        scene.radios.push(radio);
+    }
     print_to_string(&scene).unwrap_or_else(|e| -> String {
         eprintln!("Error converting scene {:?}", e);
         String::new()
     })
 }
 
-#[allow(dead_code)]
-pub fn reset(id: DeviceIdentifier) {
-    DEVICES.write().unwrap().devices.get_mut(&id).map(|device_ref| device_ref.reset()).or_else(
-        || {
-            eprintln!("No such device with id {id}");
-            None
+/// The device with the id as json.
+pub fn get_device(id: DeviceIdentifier) -> Result<String, RequestError> {
+    let device = DEVICES.read().unwrap().get(id)?.read().unwrap().get();
+    Ok(print_to_string(&device).map_err(|e| format!("Error converting device {id}: {e}"))?)
+}
+
+/// Remove a chip on request of the frontend, the device departs with its
+/// last chip as in `remove_chip`. The transport of the chip is left open.
+pub fn delete_chip(
+    device_id: DeviceIdentifier,
+    chip_id: ChipIdentifier,
+) -> Result<(), RequestError> {
+    let has_chip = DEVICES
+        .read()
+        .unwrap()
+        .devices
+        .get(&device_id)
+        .map(|device| device.read().unwrap().chips.contains_key(&chip_id))
+        .unwrap_or(false);
+    if !has_chip {
+        return Err(RequestError::NotFound(format!(
+            "No such chip with id {chip_id} on device {device_id}"
+        )));
+    }
+    remove_chip(device_id, chip_id);
+    Ok(())
+}
+
+/// Create the virtual device of a CreateDeviceRequest and return the
+/// CreateDeviceResponse json.
+///
+/// The device gets the chips of the request and a chip of the kind its
+/// behavior sends from, and is patched with the rest of the request.
+pub fn create_device(request_json: &str) -> Result<String, RequestError> {
+    let mut request = CreateDeviceRequest::new();
+    merge_from_str(&mut request, request_json)
+        .map_err(|e| format!("Error parsing CreateDeviceRequest json {request_json}: {e}"))?;
+    let mut response = CreateDeviceResponse::new();
+    let mut pending = Vec::new();
+    let device = create_virtual_device(
+        &mut DEVICES.write().unwrap(),
+        request.device.get_or_default(),
+        &mut pending,
+    )?;
+    events::publish_all(pending);
+    response.device = Some(device).into();
+    BEHAVIOR_THREAD.call_once(|| {
+        thread::Builder::new()
+            .name("behaviors".to_string())
+            .spawn(|| loop {
+                thread::sleep(BEHAVIOR_TICK_INTERVAL);
+                tick_behaviors(Instant::now());
+            })
+            .unwrap();
+    });
+    Ok(print_to_string(&response).map_err(|e| format!("Error converting device: {e}"))?)
+}
+
+// Create a virtual device, adding its events to `pending`.
+fn create_virtual_device(
+    resource: &mut RwLockWriteGuard<Devices>,
+    proto_device: &ProtoDevice,
+    pending: &mut Vec<Event>,
+) -> Result<ProtoDevice, RequestError> {
+    let name = &proto_device.name;
+    let behavior = proto_device.behavior.enum_value_or_default();
+    let profile = behavior::profile(behavior).ok_or("CreateDevice requires a behavior")?;
+    if name.is_empty() {
+        return Err("CreateDevice requires a device name".into());
+    }
+    if resource.names.contains_key(name) {
+        return Err(format!("Device {name} already exists").into());
+    }
+    if !proto_device.zone.is_empty() && !resource.zones.contains(&proto_device.zone) {
+        return Err(RequestError::NotFound(format!("No such zone {}", proto_device.zone)));
+    }
+    // Allow default chip kind of BLUETOOTH and default chip names.
+    let mut chips: Vec<(ProtoChipKind, String)> = proto_device
+        .chips
+        .iter()
+        .map(|chip| match chip.kind.enum_value_or_default() {
+            ProtoChipKind::UNSPECIFIED => (ProtoChipKind::BLUETOOTH, chip.name.clone()),
+            kind => (kind, chip.name.clone()),
+        })
+        .collect();
+    if !chips.iter().any(|(kind, _)| *kind == profile.chip_kind) {
+        chips.push((profile.chip_kind, String::new()));
+    }
+    let id = resource.id_factory.next_id();
+    let mut device = Device::new(id, format!("{VIRTUAL_GUID_PREFIX}{name}"), name.clone());
+    device.behavior = behavior;
+    let mut chip_added_events = Vec::new();
+    for (kind, chip_name) in chips {
+        let chip_name =
+            if chip_name.is_empty() { format!("{kind:?}").to_lowercase() } else { chip_name };
+        match device.add_chip(name, kind, &chip_name, "", "", None) {
+            Some(result) => chip_added_events.push(Event::ChipAdded {
+                device_id: id,
+                chip_id: result.chip_id,
+                kind,
+            }),
+            None => {
+                device.remove();
+                return Err(format!("Unable to add chip {chip_name} to device {name}").into());
+            }
+        }
+    }
+    lifecycle::set_active();
+    pending.push(Event::DeviceAdded { id, name: name.clone() });
+    pending.extend(chip_added_events);
+    pending.extend(patch_with_events(&mut device, proto_device));
+    let created = device.get();
+    resource.insert(device);
+    resource.behaviors.lock().unwrap().insert(id, Instant::now());
+    Ok(created)
+}
+
+/// Delete a virtual device with its chips. Other devices are removed when
+/// their transport closes.
+pub fn delete_device(id: DeviceIdentifier) -> Result<(), RequestError> {
+    let mut resource = DEVICES.write().unwrap();
+    let device_ref = Arc::clone(resource.get(id)?);
+    let mut device = device_ref.write().unwrap();
+    if device.behavior == ProtoBehavior::BEHAVIOR_NONE {
+        return Err(format!("Device {id} is not a virtual device").into());
+    }
+    let chip_ids: Vec<ChipIdentifier> = device.chips.keys().copied().collect();
+    let mut pending = Vec::new();
+    for chip_id in chip_ids {
+        if device.remove_chip(chip_id) {
+            pending.push(Event::ChipRemoved { device_id: id, chip_id });
+        }
+    }
+    drop(device);
+    remove_device(&mut resource, id, &mut pending);
+    drop(resource);
+    events::publish_all(pending);
+    Ok(())
+}
+
+/// Send the packets of the virtual devices that are due at `now`.
+/// Invisible devices skip their packets.
+fn tick_behaviors(now: Instant) {
+    // The packets are sent once the locks are released, the facades check
+    // the devices on delivery.
+    let mut packets = Vec::new();
+    {
+        let resource = DEVICES.read().unwrap();
+        resource.behaviors.lock().unwrap().retain(|id, next| {
+            let device = match resource.devices.get(id) {
+                Some(device) => device.read().unwrap(),
+                None => return false,
+            };
+            let profile = match behavior::profile(device.behavior) {
+                Some(profile) => profile,
+                None => return false,
+            };
+            if now < *next {
+                return true;
+            }
+            // Skip the packets missed by a late tick rather than bursting.
+            *next = (*next + profile.interval).max(now);
+            if device.visible == ProtoState::OFF {
+                return true;
+            }
+            let packet = (profile.packet)(*id, &device.name);
+            for chip in device.chips.values().filter(|chip| chip.kind == profile.chip_kind) {
+                packets.push((chip.transmitter(), packet.clone()));
+            }
+            true
+        });
+    }
+    for (transmit, packet) in packets {
+        transmit(&packet);
+    }
+}
+
+// The filter of a device listing, from the GetDevicesRequest body sent by
+// the gRPC frontend or from the query parameters of the Http frontend.
+fn device_filter(request: &HttpRequest) -> Result<DeviceFilter, RequestError> {
+    let mut proto = GetDevicesRequest::new();
+    if !request.body.is_empty() {
+        let body = String::from_utf8_lossy(&request.body);
+        merge_from_str(&mut proto, &body)
+            .map_err(|e| format!("Error parsing GetDevicesRequest json {body}: {e}"))?;
+    }
+    if let Some(label_selector) = request.query_param("label_selector") {
+        proto.label_selector = label_selector;
+    }
+    if let Some(name_pattern) = request.query_param("name_pattern") {
+        proto.name_pattern = name_pattern;
+    }
+    if let Some(chip_kind) = request.query_param("chip_kind") {
+        proto.chip_kind = parse_chip_kind(&chip_kind)?.into();
+    }
+    Ok(DeviceFilter::from_proto(&proto)?)
+}
+
+fn parse_id(id: &str) -> Result<i32, RequestError> {
+    id.parse::<i32>().map_err(|_| RequestError::BadRequest(format!("Invalid id {id}")))
+}
+
+/// The Rust devices handler used directly by Http frontend and by the gRPC
+/// frontend through `handle_frontend_cxx`.
+///
+/// GET /v1/devices --> the scene with the devices matching the optional
+///   label_selector, name_pattern and chip_kind query parameters
+/// PATCH /v1/devices --> patch the device named in the PatchDeviceRequest body
+/// POST /v1/devices --> create the virtual device of the CreateDeviceRequest body
+/// GET /v1/devices/{id} --> the device
+/// PATCH /v1/devices/{id} --> patch the device with the PatchDeviceRequest body
+/// DELETE /v1/devices/{id} --> delete the virtual device
+/// DELETE /v1/devices/{id}/chips/{chip_id} --> remove the chip
+/// POST /v1/devices/reset --> reset every device
+/// POST /v1/devices/{id}/reset --> reset the device
+pub fn handle_devices(request: &HttpRequest, param: &str, writer: ResponseWritable) {
+    let body = String::from_utf8_lossy(&request.body);
+    let segments: Vec<&str> = param.split('/').filter(|segment| !segment.is_empty()).collect();
+    let result = match (request.method.as_str(), segments.as_slice()) {
+        ("GET", []) => match device_filter(request) {
+            Ok(filter) => {
+                writer.put_ok("text/json", &get_filtered_devices(&filter), &[]);
+                return;
+            }
+            Err(err) => Err(err),
         },
-    );
+        ("PATCH", []) => patch_device(None, &body),
+        ("POST", []) => match create_device(&body) {
+            Ok(response_json) => {
+                writer.put_ok("text/json", &response_json, &[]);
+                return;
+            }
+            Err(err) => Err(err),
+        },
+        ("GET", [id]) => match parse_id(id).and_then(get_device) {
+            Ok(device_json) => {
+                writer.put_ok("text/json", &device_json, &[]);
+                return;
+            }
+            Err(err) => Err(err),
+        },
+        ("PATCH", [id]) => parse_id(id).and_then(|id| patch_device(Some(id), &body)),
+        ("DELETE", [id]) => parse_id(id).and_then(delete_device),
+        ("DELETE", [id, "chips", chip_id]) => {
+            parse_id(id).and_then(|id| delete_chip(id, parse_id(chip_id)?))
+        }
+        ("POST", ["reset"]) => {
+            reset_all();
+            Ok(())
+        }
+        ("POST", [id, "reset"]) => parse_id(id).and_then(reset),
+        _ => {
+            writer.put_error(404, "Not found.");
+            return;
+        }
+    };
+    put_result(writer, result);
 }
 
-#[allow(dead_code)]
-fn get_secs_until_idle_shutdown() -> Option<u32> {
-    if let Some(idle_since) = DEVICES.read().unwrap().idle_since {
-        let remaining_secs = idle_since
-            .elapsed()
-            .saturating_sub(Duration::from_secs(IDLE_SECS_FOR_SHUTDOWN))
-            .as_secs();
-        Some(remaining_secs.try_into().unwrap())
-    } else {
-        None
+/// Restore a scene snapshot produced by `get_devices`.
+///
+/// Devices are matched by name and patched with the position,
+/// orientation, visibility, radio states and capture state of the snapshot.
+/// Entries for devices or chips that are not connected yet are kept and
+/// applied when they connect.
+pub fn restore_scene(scene_json: &str) -> Result<(), RequestError> {
+    let mut scene = ProtoScene::new();
+    merge_from_str(&mut scene, scene_json).map_err(|e| format!("Error parsing scene json: {e}"))?;
+    let mut resource = DEVICES.write().unwrap();
+    for zone in &scene.zones {
+        resource.zones.patch(zone)?;
+    }
+    resource.pending_scene.clear();
+    for snapshot in scene.devices {
+        resource.pending_scene.insert(snapshot.name.clone(), snapshot);
+    }
+    let connected_ids: Vec<DeviceIdentifier> = resource.devices.keys().copied().collect();
+    let mut pending = Vec::new();
+    for id in connected_ids {
+        apply_pending_scene(&mut resource, id, &mut pending);
+    }
+    drop(resource);
+    events::publish_all(pending);
+    Ok(())
+}
+
+// Apply the pending scene entry for a connected device, dropping the entry
+// once every chip in it has been restored.
+fn apply_pending_scene(
+    resource: &mut RwLockWriteGuard<Devices>,
+    id: DeviceIdentifier,
+    pending: &mut Vec<Event>,
+) {
+    let resource: &mut Devices = resource;
+    let mut device = match resource.devices.get(&id) {
+        Some(device) => device.write().unwrap(),
+        None => return,
+    };
+    let key = device.name.clone();
+    let snapshot = match resource.pending_scene.get_mut(&key) {
+        Some(snapshot) => snapshot,
+        None => return,
+    };
+    // The zone of the snapshot may have been deleted since.
+    if !resource.zones.contains(&snapshot.zone) {
+        snapshot.zone.clear();
+    }
+    let snapshot = &resource.pending_scene[&key];
+    pending.extend(patch_with_events(&mut device, snapshot));
+    let is_complete = snapshot.chips.iter().all(|snapshot_chip| {
+        device.chips.values().any(|chip| {
+            chip.kind == snapshot_chip.kind.enum_value_or_default()
+                && chip.name == snapshot_chip.name
+        })
+    });
+    if is_complete {
+        resource.pending_scene.remove(&key);
+    }
+}
+
+// The path of a scene file in the scenes directory. The file name must be
+// a plain name so that the frontends cannot reach other files.
+// Format: /tmp/netsim-scenes/{file_name}
+fn scene_path(file_name: &str) -> Result<PathBuf, RequestError> {
+    let is_plain = !file_name.is_empty()
+        && !file_name.starts_with('.')
+        && file_name.chars().all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c));
+    if !is_plain {
+        return Err(format!("Invalid scene file name {file_name:?}").into());
+    }
+    let mut path = std::env::temp_dir();
+    path.push("netsim-scenes");
+    path.push(file_name);
+    Ok(path)
+}
+
+/// Save a snapshot of the scene to a json file in the scenes directory.
+pub fn save_scene(file_name: &str) -> Result<(), RequestError> {
+    let path = scene_path(file_name)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Error creating scenes directory {}: {e}", dir.display()))?;
+    }
+    Ok(std::fs::write(&path, get_devices())
+        .map_err(|e| format!("Error writing scene {file_name}: {e}"))?)
+}
+
+/// Restore the scene from a json file written by `save_scene`.
+pub fn load_scene(file_name: &str) -> Result<(), RequestError> {
+    let scene_json = std::fs::read_to_string(scene_path(file_name)?)
+        .map_err(|e| RequestError::NotFound(format!("Error reading scene {file_name}: {e}")))?;
+    restore_scene(&scene_json)
+}
+
+/// The Rust scene handler used directly by Http frontend and by the gRPC
+/// frontend through `handle_frontend_cxx`.
+///
+/// GET /v1/scene --> the scene snapshot as json
+/// PUT /v1/scene --> restore the scene snapshot in the body
+/// POST /v1/scene/save --> save the scene to the scene file named in the body
+/// POST /v1/scene/load --> restore the scene from the scene file named in the body
+pub fn handle_scene(request: &HttpRequest, param: &str, writer: ResponseWritable) {
+    let body = String::from_utf8_lossy(&request.body);
+    let result = match (request.method.as_str(), param) {
+        ("GET", "") => {
+            writer.put_ok("text/json", &get_devices(), &[]);
+            return;
+        }
+        ("PUT", "") => restore_scene(&body),
+        ("POST", "save") => save_scene(body.trim()),
+        ("POST", "load") => load_scene(body.trim()),
+        _ => {
+            writer.put_error(404, "Not found.");
+            return;
+        }
+    };
+    put_result(writer, result);
+}
+
+/// Attach the trajectory of a SetTrajectoryRequest to a device.
+///
+/// The device is resolved with `Devices::match_device` and starts moving from the
+/// first waypoint now. A trajectory without waypoints stops the device.
+pub fn set_trajectory(request_json: &str) -> Result<(), RequestError> {
+    let mut request = SetTrajectoryRequest::new();
+    merge_from_str(&mut request, request_json)
+        .map_err(|e| format!("Error parsing SetTrajectoryRequest json {request_json}: {e}"))?;
+    let trajectory = Trajectory::from_proto(request.trajectory.get_or_default())?;
+    let resource = DEVICES.read().unwrap();
+    let id = resource.match_device(&request.device_name)?;
+    let mut trajectories = resource.trajectories.lock().unwrap();
+    match trajectory {
+        Some(trajectory) => {
+            trajectories.insert(id, (trajectory, Instant::now()));
+            TRAJECTORY_THREAD.call_once(|| {
+                thread::Builder::new()
+                    .name("trajectories".to_string())
+                    .spawn(|| loop {
+                        thread::sleep(TRAJECTORY_TICK_INTERVAL);
+                        tick_trajectories(Instant::now());
+                    })
+                    .unwrap();
+            });
+        }
+        None => {
+            trajectories.remove(&id);
+        }
+    }
+    Ok(())
+}
+
+/// Move the devices with a trajectory to their pose at `now`, publishing
+/// PositionChanged events. Finished trajectories are removed.
+fn tick_trajectories(now: Instant) {
+    let mut pending = Vec::new();
+    let resource = DEVICES.read().unwrap();
+    resource.trajectories.lock().unwrap().retain(|id, (trajectory, start)| {
+        let mut device = match resource.devices.get(id) {
+            Some(device) => device.write().unwrap(),
+            None => return false,
+        };
+        let pose = trajectory.pose_at(now.saturating_duration_since(*start));
+        let orientation = pose.orientation.unwrap_or_else(|| device.orientation.clone());
+        if device.position != pose.position || device.orientation != orientation {
+            device.position = pose.position;
+            device.orientation = orientation;
+            pending.push(Event::PositionChanged {
+                id: *id,
+                position: device.position.clone(),
+                orientation: device.orientation.clone(),
+            });
+        }
+        !pose.is_finished
+    });
+    drop(resource);
+    events::publish_all(pending);
+}
+
+/// The Rust trajectory handler used directly by Http frontend and by the
+/// gRPC frontend through `handle_frontend_cxx`.
+///
+/// PUT /v1/trajectory --> attach the SetTrajectoryRequest body to a device
+pub fn handle_trajectory(request: &HttpRequest, param: &str, writer: ResponseWritable) {
+    let body = String::from_utf8_lossy(&request.body);
+    let result = match (request.method.as_str(), param) {
+        ("PUT", "") => set_trajectory(&body),
+        _ => {
+            writer.put_error(404, "Not found.");
+            return;
+        }
+    };
+    put_result(writer, result);
+}
+
+/// Decide whether a packet sent from one device to another on a radio is
+/// lost. Called by the radio facades for every delivered packet.
+///
+/// Invisible devices neither send nor receive and packets do not cross
+/// into or out of isolated zones, otherwise the link loss model applies.
+pub fn should_drop_packet(device_id: u32, other_device_id: u32, radio: i32) -> bool {
+    {
+        let resource = DEVICES.read().unwrap();
+        // The visibility and zone of one device at a time, the two may be
+        // the same.
+        let state = |id: u32| {
+            let device = resource.devices.get(&(id as DeviceIdentifier))?.read().unwrap();
+            Some((device.visible, device.zone.clone()))
+        };
+        let (device, other_device) = (state(device_id), state(other_device_id));
+        let is_invisible = |device: &Option<(ProtoState, String)>| matches!(device, Some((visible, _)) if *visible == ProtoState::OFF);
+        if is_invisible(&device) || is_invisible(&other_device) {
+            return true;
+        }
+        if let (Some((_, zone)), Some((_, other_zone))) = (device, other_device) {
+            if resource.zones.attenuation(&zone, &other_zone).is_none() {
+                return true;
+            }
+        }
+    }
+    link_loss::should_drop_packet(device_id, other_device_id, radio)
+}
+
+/// Set the loss of the link between two devices named in a
+/// SetLinkLossRequest.
+///
+/// The devices are resolved with `Devices::match_device` and a link loss of 0
+/// removes the link.
+pub fn set_link_loss(request_json: &str) -> Result<(), RequestError> {
+    let mut request = SetLinkLossRequest::new();
+    merge_from_str(&mut request, request_json)
+        .map_err(|e| format!("Error parsing SetLinkLossRequest json {request_json}: {e}"))?;
+    if request.device_name.is_empty() || request.other_device.is_empty() {
+        return Err("Link loss requires two device names".into());
+    }
+    let resource = DEVICES.read().unwrap();
+    let id = resource.match_device(&request.device_name)?;
+    let other_id = resource.match_device(&request.other_device)?;
+    Ok(link_loss::set_link_loss(
+        id,
+        other_id,
+        request.radio.enum_value_or_default(),
+        request.link_loss,
+    )?)
+}
+
+/// The links with a link loss as a ListLinkLossResponse json.
+pub fn list_link_loss() -> String {
+    let resource = DEVICES.read().unwrap();
+    let name = |id: DeviceIdentifier| {
+        resource
+            .devices
+            .get(&id)
+            .map(|device| device.read().unwrap().name.clone())
+            .unwrap_or_default()
+    };
+    let mut response = ListLinkLossResponse::new();
+    for (id, other_id, radio, loss) in link_loss::list_link_loss() {
+        let mut link = SetLinkLossRequest::new();
+        link.device_name = name(id);
+        link.other_device = name(other_id);
+        link.radio = radio.into();
+        link.link_loss = loss;
+        response.links.push(link);
+    }
+    print_to_string(&response).unwrap()
+}
+
+/// The Rust link loss handler used directly by Http frontend and by the
+/// gRPC frontend through `handle_frontend_cxx`.
+///
+/// GET /v1/link_loss --> the links with a link loss
+/// PUT /v1/link_loss --> set the link loss of the SetLinkLossRequest body
+/// DELETE /v1/link_loss --> remove the link loss of all links
+pub fn handle_link_loss(request: &HttpRequest, param: &str, writer: ResponseWritable) {
+    let body = String::from_utf8_lossy(&request.body);
+    let result = match (request.method.as_str(), param) {
+        ("GET", "") => {
+            writer.put_ok("text/json", &list_link_loss(), &[]);
+            return;
+        }
+        ("PUT", "") => set_link_loss(&body),
+        ("DELETE", "") => {
+            link_loss::clear_link_loss();
+            Ok(())
+        }
+        _ => {
+            writer.put_error(404, "Not found.");
+            return;
+        }
+    };
+    put_result(writer, result);
+}
+
+/// Create or update the zone of a PatchZoneRequest.
+pub fn patch_zone(request_json: &str) -> Result<(), RequestError> {
+    let mut request = PatchZoneRequest::new();
+    merge_from_str(&mut request, request_json)
+        .map_err(|e| format!("Error parsing PatchZoneRequest json {request_json}: {e}"))?;
+    Ok(DEVICES.write().unwrap().zones.patch(request.zone.get_or_default())?)
+}
+
+/// Delete a zone and move its devices to the default zone.
+pub fn delete_zone(name: &str) -> Result<(), RequestError> {
+    let mut resource = DEVICES.write().unwrap();
+    if !resource.zones.contains(name) {
+        return Err(RequestError::NotFound(format!("No such zone {name}")));
     }
+    resource.zones.delete(name)?;
+    let mut pending = Vec::new();
+    for device in resource.devices.values() {
+        let mut device = device.write().unwrap();
+        if device.zone == name {
+            device.zone = DEFAULT_ZONE.to_string();
+            pending.push(Event::ZoneChanged { id: device.id, zone: device.zone.clone() });
+        }
+    }
+    drop(resource);
+    events::publish_all(pending);
+    Ok(())
+}
+
+/// Assign the device of an AssignZoneRequest to a zone. The device is
+/// resolved with `Devices::match_device`.
+pub fn assign_zone(request_json: &str) -> Result<(), RequestError> {
+    let mut request = AssignZoneRequest::new();
+    merge_from_str(&mut request, request_json)
+        .map_err(|e| format!("Error parsing AssignZoneRequest json {request_json}: {e}"))?;
+    let resource = DEVICES.read().unwrap();
+    if !resource.zones.contains(&request.zone) {
+        return Err(RequestError::NotFound(format!("No such zone {}", request.zone)));
+    }
+    let id = resource.match_device(&request.device_name)?;
+    let patch = ProtoDevice { zone: request.zone, ..Default::default() };
+    let pending = patch_with_events(&mut resource.get(id)?.write().unwrap(), &patch);
+    drop(resource);
+    events::publish_all(pending);
+    Ok(())
+}
+
+/// The zones as a ListZonesResponse json.
+pub fn list_zones() -> String {
+    let mut response = ListZonesResponse::new();
+    response.zones = DEVICES.read().unwrap().zones.list();
+    print_to_string(&response).unwrap()
+}
+
+/// The Rust zones handler used directly by Http frontend and by the gRPC
+/// frontend through `handle_frontend_cxx`.
+///
+/// GET /v1/zones --> the zones
+/// PUT /v1/zones --> create or update the zone of the PatchZoneRequest body
+/// PUT /v1/zones/assign --> assign a device with the AssignZoneRequest body
+/// DELETE /v1/zones/{name} --> delete the zone
+pub fn handle_zones(request: &HttpRequest, param: &str, writer: ResponseWritable) {
+    let body = String::from_utf8_lossy(&request.body);
+    let result = match (request.method.as_str(), param) {
+        ("GET", "") => {
+            writer.put_ok("text/json", &list_zones(), &[]);
+            return;
+        }
+        ("PUT", "") => patch_zone(&body),
+        ("PUT", "assign") => assign_zone(&body),
+        ("DELETE", name) if !name.is_empty() => delete_zone(name),
+        _ => {
+            writer.put_error(404, "Not found.");
+            return;
+        }
+    };
+    put_result(writer, result);
+}
+
+pub fn reset(id: DeviceIdentifier) -> Result<(), RequestError> {
+    let resource = DEVICES.read().unwrap();
+    resource.trajectories.lock().unwrap().remove(&id);
+    resource.get(id)?.write().unwrap().reset();
+    drop(resource);
+    events::publish(Event::Reset { id });
+    Ok(())
+}
+
+/// Reset every device, as the Reset of the gRPC frontend.
+pub fn reset_all() {
+    let resource = DEVICES.read().unwrap();
+    resource.trajectories.lock().unwrap().clear();
+    let mut pending = Vec::new();
+    for device in resource.devices.values() {
+        let mut device = device.write().unwrap();
+        device.reset();
+        pending.push(Event::Reset { id: device.id });
+    }
+    drop(resource);
+    events::publish_all(pending);
+}
+
+/// Wait up to `timeout` for the next scene event and return the frontend
+/// Event for the RegisterEvents stream, which carries the devices after
+/// the change.
+pub fn next_event(
+    receiver: &Receiver<Event>,
+    timeout: Duration,
+) -> Result<ProtoEvent, RecvTimeoutError> {
+    receiver.recv_timeout(timeout)?;
+    // Coalesce events that were queued while the caller was busy.
+    while receiver.try_recv().is_ok() {}
+    let mut event = ProtoEvent::new();
+    DEVICES.read().unwrap().devices.values().for_each(|device| {
+        event.devices.push(device.read().unwrap().get());
+    });
+    Ok(event)
+}
+
+/// A subscription to the scene events for the RegisterEvents stream of
+/// the gRPC frontend. Dropping it unsubscribes.
+pub struct EventReceiverCxx {
+    receiver: Receiver<Event>,
+}
+
+impl EventReceiverCxx {
+    /// The json of the next frontend Event, or an empty string when there
+    /// is no event within `timeout_ms` so that the caller can check
+    /// whether the stream was cancelled.
+    pub fn next_event_json(&self, timeout_ms: u32) -> String {
+        match next_event(&self.receiver, Duration::from_millis(timeout_ms.into())) {
+            Ok(event) => print_to_string(&event).unwrap_or_default(),
+            Err(_) => String::new(),
+        }
+    }
+}
+
+/// Subscribe to the scene events for the C++ gRPC frontend.
+pub fn subscribe_events_cxx() -> Box<EventReceiverCxx> {
+    let (_, receiver) = events::subscribe();
+    Box::new(EventReceiverCxx { receiver })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::captures::handlers::handle_packet;
+    use crate::captures::pcap_util::PacketDirection;
+    use crate::http_server::http_request::HttpHeaders;
+    use crate::http_server::server_response::ServerResponseWriter;
+    use crate::uwb::facade::handle_uwb_request;
+    use frontend_proto::model::Orientation as ProtoOrientation;
+    use std::sync::{Mutex, MutexGuard};
+
+    lazy_static! {
+        static ref TEST_LOCK: Mutex<()> = Mutex::new(());
+    }
+
+    // Tests share the DEVICES singleton, serialize the tests that use it.
+    fn lock_devices() -> MutexGuard<'static, ()> {
+        TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+    }
 
     fn new_with_xyz(x: f32, y: f32, z: f32) -> ProtoPosition {
         ProtoPosition { x, y, z, ..Default::default() }
@@ -236,4 +1505,769 @@ mod tests {
         b = new_with_xyz(2.0, 3.0, 6.0);
         assert_eq!(distance(&a, &b), 7.0);
     }
+
+    // Collect the events published for the device, ignoring the events
+    // from tests running concurrently on the shared event bus.
+    fn device_events(receiver: &Receiver<Event>, id: DeviceIdentifier) -> Vec<Event> {
+        receiver.try_iter().filter(|event| event.device_id() == id).collect()
+    }
+
+    #[test]
+    fn test_add_chip_and_remove_chip_events() {
+        let _lock = lock_devices();
+        let (_, receiver) = events::subscribe();
+        let result =
+            add_chip("events-guid-1", "events-device-1", ProtoChipKind::BLUETOOTH, "bt", "", "");
+        let id = result.device_id;
+        assert_eq!(
+            device_events(&receiver, id),
+            vec![
+                Event::DeviceAdded { id, name: "events-device-1".to_string() },
+                Event::ChipAdded {
+                    device_id: id,
+                    chip_id: result.chip_id,
+                    kind: ProtoChipKind::BLUETOOTH
+                },
+            ]
+        );
+        remove_chip(id, result.chip_id);
+        assert_eq!(
+            device_events(&receiver, id),
+            vec![
+                Event::ChipRemoved { device_id: id, chip_id: result.chip_id },
+                Event::DeviceRemoved { id, name: "events-device-1".to_string() },
+            ]
+        );
+    }
+
+    fn get_proto_device(id: DeviceIdentifier) -> Option<ProtoDevice> {
+        let resource = DEVICES.read().unwrap();
+        resource.devices.get(&id).map(|device| device.read().unwrap().get())
+    }
+
+    #[test]
+    fn test_add_chips_and_remove_chips() {
+        let _lock = lock_devices();
+        let bt = add_chip("chips-guid-1", "chips-device-1", ProtoChipKind::BLUETOOTH, "bt", "", "");
+        let uwb = add_chip("chips-guid-1", "chips-device-1", ProtoChipKind::UWB, "uwb", "", "");
+        // The chips of a guid share a device.
+        assert_eq!(bt.device_id, uwb.device_id);
+        let id = bt.device_id;
+        let device = get_proto_device(id).unwrap();
+        assert_eq!(device.name, "chips-device-1");
+        assert_eq!(device.visible.enum_value_or_default(), ProtoState::ON);
+        assert!(device.position.is_some() && device.orientation.is_some());
+        let mut chips: Vec<_> =
+            device.chips.iter().map(|chip| (chip.id, chip.kind.enum_value_or_default())).collect();
+        chips.sort_by_key(|(chip_id, _)| *chip_id);
+        assert_eq!(
+            chips,
+            vec![(bt.chip_id, ProtoChipKind::BLUETOOTH), (uwb.chip_id, ProtoChipKind::UWB)]
+        );
+        // The device is removed with its last chip.
+        remove_chip(id, bt.chip_id);
+        assert_eq!(get_proto_device(id).unwrap().chips.len(), 1);
+        remove_chip(id, uwb.chip_id);
+        assert!(get_proto_device(id).is_none());
+    }
+
+    #[test]
+    fn test_reset_all() {
+        let _lock = lock_devices();
+        let result =
+            add_chip("reset-guid-1", "reset-device-1", ProtoChipKind::BLUETOOTH, "bt", "", "");
+        let id = result.device_id;
+        patch_device(
+            Some(id),
+            r#"{"device": {"visible": "OFF", "position": {"x": 10.0, "y": 20.0, "z": 30.0},
+                "orientation": {"yaw": 3.0, "pitch": 1.0, "roll": 2.0}}}"#,
+        )
+        .unwrap();
+        let device = get_proto_device(id).unwrap();
+        assert_eq!(device.visible.enum_value_or_default(), ProtoState::OFF);
+        assert_eq!(device.position.x, 10.0);
+        assert_eq!(device.orientation.pitch, 1.0);
+        reset_all();
+        let device = get_proto_device(id).unwrap();
+        assert_eq!(device.visible.enum_value_or_default(), ProtoState::ON);
+        assert_eq!(device.position, Some(ProtoPosition::new()).into());
+        assert_eq!(device.orientation, Some(ProtoOrientation::new()).into());
+        remove_chip(id, result.chip_id);
+    }
+
+    #[test]
+    fn test_patch_device_and_reset_events() {
+        let _lock = lock_devices();
+        let result =
+            add_chip("events-guid-2", "events-device-2", ProtoChipKind::BLUETOOTH, "bt", "", "");
+        let id = result.device_id;
+        let (_, receiver) = events::subscribe();
+        patch_device(
+            Some(id),
+            r#"{"device": {"position": {"x": 1.0}, "chips": [{"name": "bt"}]}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            device_events(&receiver, id),
+            vec![
+                Event::PositionChanged {
+                    id,
+                    position: new_with_xyz(1.0, 0.0, 0.0),
+                    orientation: ProtoOrientation::new()
+                },
+                Event::ChipPatched { device_id: id, chip_id: result.chip_id },
+            ]
+        );
+        reset(id).unwrap();
+        assert_eq!(device_events(&receiver, id), vec![Event::Reset { id }]);
+        remove_chip(id, result.chip_id);
+    }
+
+    #[test]
+    fn test_next_event() {
+        let _lock = lock_devices();
+        let receiver = subscribe_events_cxx();
+        let result =
+            add_chip("events-guid-3", "events-device-3", ProtoChipKind::BLUETOOTH, "bt", "", "");
+        // The events of the chip are coalesced into one frontend Event.
+        let event = next_event(&receiver.receiver, Duration::ZERO).unwrap();
+        assert!(event.devices.iter().any(|device| device.name == "events-device-3"));
+        remove_chip(result.device_id, result.chip_id);
+        let json = receiver.next_event_json(1000);
+        assert!(json.starts_with('{') && !json.contains("events-device-3"));
+    }
+
+    fn new_devices(names: &[(&str, &str)]) -> Devices {
+        let mut devices = Devices::new();
+        for (i, (guid, name)) in names.iter().enumerate() {
+            let id = i as DeviceIdentifier;
+            devices.insert(Device::new(id, guid.to_string(), name.to_string()));
+        }
+        devices
+    }
+
+    #[test]
+    fn test_match_device() {
+        let devices = new_devices(&[
+            ("localhost:6520", "emulator-5554"),
+            ("localhost:6521", "emulator-5556"),
+            ("localhost:6522", "watch"),
+        ]);
+        // exact name, guid and unique substring
+        assert_eq!(devices.match_device("emulator-5556"), Ok(1));
+        assert_eq!(devices.match_device("localhost:6522"), Ok(2));
+        assert_eq!(devices.match_device("5554"), Ok(0));
+        assert_eq!(devices.match_device("wat"), Ok(2));
+        // ambiguous, unknown and empty names
+        assert_eq!(
+            devices.match_device("emulator"),
+            Err(RequestError::BadRequest(
+                "Multiple ambiguous matches were found with substring emulator".to_string()
+            ))
+        );
+        assert_eq!(
+            devices.match_device("phone"),
+            Err(RequestError::NotFound("No such device with name phone".to_string()))
+        );
+        assert!(devices.match_device("").is_err());
+    }
+
+    #[test]
+    fn test_device_indexes() {
+        let mut devices = new_devices(&[
+            ("localhost:6520", "emulator-5554"),
+            ("localhost:6521", "emulator-5554"),
+            ("localhost:6522", "watch"),
+        ]);
+        // Devices with the same name are only matched by their guid.
+        assert_eq!(
+            devices.match_device("emulator-5554"),
+            Err(RequestError::BadRequest(
+                "Multiple devices are named emulator-5554, with ids 0, 1".to_string()
+            ))
+        );
+        assert_eq!(devices.match_device("localhost:6521"), Ok(1));
+        assert!(devices.match_device("emulator").is_err());
+        assert!(devices.remove(0).is_some());
+        assert_eq!(devices.match_device("emulator-5554"), Ok(1));
+        assert!(devices.match_device("localhost:6520").is_err());
+        assert!(devices.remove(1).is_some());
+        assert!(!devices.names.contains_key("emulator-5554"));
+        assert_eq!(devices.guids.len(), 1);
+        assert!(devices.remove(1).is_none());
+        assert!(devices.get(2).is_ok() && devices.get(1).is_err());
+    }
+
+    #[test]
+    fn test_patch_device_by_name() {
+        let _lock = lock_devices();
+        let result =
+            add_chip("patch-guid-1", "patch-device-1", ProtoChipKind::BLUETOOTH, "bt", "", "");
+        let id = result.device_id;
+        patch_device(None, r#"{"device": {"name": "patch-device-1", "position": {"y": 2.0}}}"#)
+            .unwrap();
+        assert_eq!(
+            DEVICES.read().unwrap().devices.get(&id).unwrap().read().unwrap().position.y,
+            2.0
+        );
+        patch_device(None, r#"{"device": {"name": "patch-guid-1", "position": {"y": 3.0}}}"#)
+            .unwrap();
+        assert_eq!(
+            DEVICES.read().unwrap().devices.get(&id).unwrap().read().unwrap().position.y,
+            3.0
+        );
+        assert!(patch_device(None, r#"{"device": {"name": "no-such-device"}}"#).is_err());
+        assert!(patch_device(None, "not json").is_err());
+        remove_chip(id, result.chip_id);
+    }
+
+    #[test]
+    fn test_set_link_loss() {
+        let _lock = lock_devices();
+        let phone = add_chip("loss-guid-1", "loss-phone", ProtoChipKind::BLUETOOTH, "bt", "", "");
+        let watch = add_chip("loss-guid-2", "loss-watch", ProtoChipKind::BLUETOOTH, "bt", "", "");
+        let radio = ProtoPhyKind::BLUETOOTH_LOW_ENERGY;
+        set_link_loss(
+            r#"{"device_name": "loss-phone", "other_device": "loss-watch",
+                "radio": "BLUETOOTH_LOW_ENERGY", "link_loss": 0.5}"#,
+        )
+        .unwrap();
+        assert_eq!(link_loss::get_link_loss(watch.device_id, phone.device_id, radio), 0.5);
+        assert!(list_link_loss().contains(r#""deviceName": "loss-phone""#));
+        assert!(
+            set_link_loss(r#"{"device_name": "loss-phone", "other_device": "nobody"}"#).is_err()
+        );
+        assert!(set_link_loss(
+            r#"{"device_name": "loss-phone", "other_device": "loss-watch",
+                "radio": "WIFI", "link_loss": 2.0}"#
+        )
+        .is_err());
+        // The links of a device are dropped with the device.
+        remove_chip(phone.device_id, phone.chip_id);
+        assert_eq!(link_loss::get_link_loss(watch.device_id, phone.device_id, radio), 0.0);
+        remove_chip(watch.device_id, watch.chip_id);
+    }
+
+    #[test]
+    fn test_invisible_device_drops_packets() {
+        let _lock = lock_devices();
+        let phone = add_chip("cage-guid-1", "cage-phone", ProtoChipKind::BLUETOOTH, "bt", "", "");
+        let watch = add_chip("cage-guid-2", "cage-watch", ProtoChipKind::BLUETOOTH, "bt", "", "");
+        let (phone_id, watch_id) = (phone.device_id as u32, watch.device_id as u32);
+        let radio = ProtoPhyKind::BLUETOOTH_LOW_ENERGY.value();
+        assert!(!should_drop_packet(phone_id, watch_id, radio));
+        patch_device(Some(phone.device_id), r#"{"device": {"visible": "OFF"}}"#).unwrap();
+        assert!(should_drop_packet(phone_id, watch_id, radio));
+        assert!(should_drop_packet(watch_id, phone_id, radio));
+        // A patch without visibility leaves the device invisible.
+        patch_device(Some(phone.device_id), r#"{"device": {"position": {"x": 1.0}}}"#).unwrap();
+        assert!(should_drop_packet(phone_id, watch_id, radio));
+        patch_device(Some(phone.device_id), r#"{"device": {"visible": "ON"}}"#).unwrap();
+        assert!(!should_drop_packet(phone_id, watch_id, radio));
+        remove_chip(phone.device_id, phone.chip_id);
+        remove_chip(watch.device_id, watch.chip_id);
+    }
+
+    #[test]
+    fn test_invisible_device_drops_uwb_packets() {
+        let _lock = lock_devices();
+        let phone = add_chip("cage-guid-3", "cage-uwb-phone", ProtoChipKind::UWB, "uwb", "", "");
+        let watch = add_chip("cage-guid-4", "cage-uwb-watch", ProtoChipKind::UWB, "uwb", "", "");
+        let receivers = |sender: &AddChipResult| {
+            crate::uwb::facade::receivers(sender.facade_id as u32).unwrap()
+        };
+        assert!(receivers(&phone).contains(&(watch.facade_id as u32)));
+        patch_device(Some(watch.device_id), r#"{"device": {"visible": "OFF"}}"#).unwrap();
+        assert!(!receivers(&phone).contains(&(watch.facade_id as u32)));
+        assert!(!receivers(&watch).contains(&(phone.facade_id as u32)));
+        patch_device(Some(watch.device_id), r#"{"device": {"visible": "ON"}}"#).unwrap();
+        assert!(receivers(&phone).contains(&(watch.facade_id as u32)));
+        remove_chip(phone.device_id, phone.chip_id);
+        remove_chip(watch.device_id, watch.chip_id);
+    }
+
+    #[test]
+    fn test_uwb_chip() {
+        let _lock = lock_devices();
+        let result = add_chip("uwb-guid-1", "uwb-device-1", ProtoChipKind::UWB, "uwb", "", "");
+        let uwb_state = || {
+            let device = DEVICES
+                .read()
+                .unwrap()
+                .devices
+                .get(&result.device_id)
+                .unwrap()
+                .read()
+                .unwrap()
+                .get();
+            device.chips[0].uwb().state.enum_value_or_default()
+        };
+        assert_eq!(uwb_state(), ProtoState::ON);
+        // The patch sent by `netsim radio uwb down uwb-device-1`.
+        patch_device(
+            None,
+            r#"{"device": {"name": "uwb-device-1",
+                "chips": [{"kind": "UWB", "uwb": {"state": "OFF"}}]}}"#,
+        )
+        .unwrap();
+        assert_eq!(uwb_state(), ProtoState::OFF);
+        reset(result.device_id).unwrap();
+        assert_eq!(uwb_state(), ProtoState::ON);
+        remove_chip(result.device_id, result.chip_id);
+    }
+
+    #[test]
+    fn test_trajectory() {
+        let _lock = lock_devices();
+        let result = add_chip("walk-guid-1", "walk-watch", ProtoChipKind::BLUETOOTH, "bt", "", "");
+        let id = result.device_id;
+        let proto_trajectory = protobuf_json_mapping::parse_from_str(
+            r#"{"waypoints": [{"position": {"x": 0.0}}, {"position": {"x": 10.0}, "speed": 1.0}]}"#,
+        )
+        .unwrap();
+        let trajectory = Trajectory::from_proto(&proto_trajectory).unwrap().unwrap();
+        // Drive the ticks directly, set_trajectory would start the tick thread.
+        let start = Instant::now();
+        DEVICES.read().unwrap().trajectories.lock().unwrap().insert(id, (trajectory, start));
+        let (_, receiver) = events::subscribe();
+        tick_trajectories(start + Duration::from_secs(4));
+        assert_eq!(get_position(id).x, 4.0);
+        assert!(matches!(receiver.try_recv(), Ok(Event::PositionChanged { .. })));
+        tick_trajectories(start + Duration::from_secs(20));
+        assert_eq!(get_position(id).x, 10.0);
+        assert!(!DEVICES.read().unwrap().trajectories.lock().unwrap().contains_key(&id));
+
+        assert!(set_trajectory(r#"{"device_name": "no-such-device"}"#).is_err());
+        assert!(set_trajectory(
+            r#"{"device_name": "walk-watch", "trajectory": {"waypoints": [{}, {}]}}"#
+        )
+        .is_err());
+        remove_chip(id, result.chip_id);
+    }
+
+    #[test]
+    fn test_get_rssi() {
+        let _lock = lock_devices();
+        let phone = add_chip("rssi-guid-1", "rssi-phone", ProtoChipKind::UWB, "uwb", "", "");
+        let tag = add_chip("rssi-guid-2", "rssi-tag", ProtoChipKind::UWB, "uwb", "", "");
+        patch_device(Some(tag.device_id), r#"{"device": {"position": {"x": 2.0}}}"#).unwrap();
+        let isotropic_rssi = get_rssi(phone.device_id, tag.device_id, ProtoChipKind::UWB, 0);
+        assert_eq!(isotropic_rssi, Some(crate::ranging::distance_to_rssi(0, 2.0)));
+        // A patch antenna facing away from the tag lowers the RSSI.
+        patch_device(
+            Some(phone.device_id),
+            r#"{"device": {"orientation": {"yaw": 180.0},
+                "chips": [{"kind": "UWB", "antenna": "PATCH"}]}}"#,
+        )
+        .unwrap();
+        let rssi = get_rssi(phone.device_id, tag.device_id, ProtoChipKind::UWB, 0);
+        assert!(rssi < isotropic_rssi);
+        assert_eq!(get_rssi(phone.device_id, 1, ProtoChipKind::UWB, 0), None);
+        remove_chip(phone.device_id, phone.chip_id);
+        remove_chip(tag.device_id, tag.chip_id);
+    }
+
+    #[test]
+    fn test_distances() {
+        let _lock = lock_devices();
+        let phone =
+            add_chip("distance-guid-1", "distance-phone", ProtoChipKind::UWB, "uwb", "", "");
+        let phone_bt =
+            add_chip("distance-guid-1", "distance-phone", ProtoChipKind::BLUETOOTH, "bt", "", "");
+        let tag = add_chip("distance-guid-2", "distance-tag", ProtoChipKind::UWB, "uwb", "", "");
+        patch_device(Some(tag.device_id), r#"{"device": {"position": {"x": 3.0, "y": 4.0}}}"#)
+            .unwrap();
+        assert_eq!(get_distance(phone.device_id, tag.device_id), Ok(5.0));
+        assert!(get_distance(phone.device_id, 1).is_err());
+        let pair = || {
+            let response: GetDistancesResponse =
+                protobuf_json_mapping::parse_from_str(&get_distances()).unwrap();
+            response
+                .distances
+                .into_iter()
+                .find(|pair| pair.device_name == "distance-phone")
+                .filter(|pair| pair.other_device == "distance-tag")
+                .unwrap()
+        };
+        let distance = pair();
+        assert_eq!(distance.distance, 5.0);
+        assert_eq!(distance.unreachable, "");
+        // Only the radio both devices have.
+        assert_eq!(distance.rssi.len(), 1);
+        assert_eq!(distance.rssi[0].radio.enum_value_or_default(), ProtoPhyKind::UWB);
+        assert_eq!(distance.rssi[0].rssi, crate::ranging::distance_to_rssi(-14, 5.0) as i32);
+
+        patch_zone(r#"{"zone": {"name": "distance-zone", "attenuation": 10.0}}"#).unwrap();
+        assign_zone(r#"{"device_name": "distance-tag", "zone": "distance-zone"}"#).unwrap();
+        set_link_loss(
+            r#"{"device_name": "distance-phone", "other_device": "distance-tag",
+                "radio": "UWB", "link_loss": 0.5}"#,
+        )
+        .unwrap();
+        let attenuated = pair();
+        assert_eq!(attenuated.rssi[0].rssi, distance.rssi[0].rssi - 10);
+        assert_eq!(attenuated.rssi[0].link_loss, 0.5);
+        patch_device(Some(tag.device_id), r#"{"device": {"visible": "OFF"}}"#).unwrap();
+        assert_eq!(pair().unreachable, "distance-tag is invisible");
+        delete_zone("distance-zone").unwrap();
+        remove_chip(phone.device_id, phone_bt.chip_id);
+        remove_chip(phone.device_id, phone.chip_id);
+        remove_chip(tag.device_id, tag.chip_id);
+    }
+
+    #[test]
+    fn test_zones() {
+        let _lock = lock_devices();
+        let phone = add_chip("zone-guid-1", "zone-phone", ProtoChipKind::UWB, "uwb", "", "");
+        let tag = add_chip("zone-guid-2", "zone-tag", ProtoChipKind::UWB, "uwb", "", "");
+        let (phone_id, tag_id) = (phone.device_id as u32, tag.device_id as u32);
+        let radio = ProtoPhyKind::UWB.value();
+        let same_zone_rssi = get_rssi(phone.device_id, tag.device_id, ProtoChipKind::UWB, 0);
+
+        patch_zone(r#"{"zone": {"name": "zone-lab-a", "isolated": true}}"#).unwrap();
+        assert!(matches!(
+            assign_zone(r#"{"device_name": "zone-phone", "zone": "no-such-zone"}"#),
+            Err(RequestError::NotFound(_))
+        ));
+        let (_, receiver) = events::subscribe();
+        assign_zone(r#"{"device_name": "zone-phone", "zone": "zone-lab-a"}"#).unwrap();
+        assert_eq!(
+            device_events(&receiver, phone.device_id),
+            vec![Event::ZoneChanged { id: phone.device_id, zone: "zone-lab-a".to_string() }]
+        );
+        assert!(should_drop_packet(phone_id, tag_id, radio));
+        assert_eq!(get_rssi(phone.device_id, tag.device_id, ProtoChipKind::UWB, 0), None);
+        let tag_receives = || {
+            crate::uwb::facade::receivers(phone.facade_id as u32)
+                .unwrap()
+                .contains(&(tag.facade_id as u32))
+        };
+        assert!(!tag_receives());
+
+        // A zone that is not isolated attenuates the signal.
+        patch_zone(r#"{"zone": {"name": "zone-lab-a", "attenuation": 30.0}}"#).unwrap();
+        assert!(!should_drop_packet(phone_id, tag_id, radio));
+        assert!(tag_receives());
+        let rssi = get_rssi(phone.device_id, tag.device_id, ProtoChipKind::UWB, 0);
+        assert_eq!(rssi, same_zone_rssi.map(|rssi| rssi - 30));
+        assert!(list_zones().contains(r#""name": "zone-lab-a""#));
+        assert!(get_devices().contains(r#""zone": "zone-lab-a""#));
+
+        // The devices of a deleted zone move to the default zone.
+        delete_zone("zone-lab-a").unwrap();
+        let zone = DEVICES
+            .read()
+            .unwrap()
+            .devices
+            .get(&phone.device_id)
+            .unwrap()
+            .read()
+            .unwrap()
+            .zone
+            .clone();
+        assert_eq!(zone, DEFAULT_ZONE);
+        assert!(matches!(delete_zone(DEFAULT_ZONE), Err(RequestError::BadRequest(_))));
+        assert!(matches!(delete_zone("no-such-zone"), Err(RequestError::NotFound(_))));
+        assert!(
+            patch_device(Some(phone.device_id), r#"{"device": {"zone": "zone-lab-a"}}"#).is_err()
+        );
+        remove_chip(phone.device_id, phone.chip_id);
+        remove_chip(tag.device_id, tag.chip_id);
+    }
+
+    #[test]
+    fn test_reconnect() {
+        let _lock = lock_devices();
+        let result = add_chip("boot-guid-1", "boot-phone", ProtoChipKind::UWB, "uwb", "", "");
+        patch_device(
+            Some(result.device_id),
+            r#"{"device": {"position": {"x": 5.0},
+                "chips": [{"kind": "UWB", "capture": "ON", "uwb": {"state": "OFF"}}]}}"#,
+        )
+        .unwrap();
+        let before =
+            DEVICES.read().unwrap().devices.get(&result.device_id).unwrap().read().unwrap().get();
+
+        // The emulator reboots and reconnects with a new transport guid.
+        remove_chip(result.device_id, result.chip_id);
+        let other = add_chip("boot-guid-2", "boot-phone", ProtoChipKind::UWB, "uwb", "", "");
+        assert_ne!(other.device_id, result.device_id);
+        let mut after =
+            DEVICES.read().unwrap().devices.get(&other.device_id).unwrap().read().unwrap().get();
+        after.id = before.id;
+        after.chips[0].id = before.chips[0].id;
+        assert_eq!(after, before);
+        assert!(DEVICES.read().unwrap().pending_scene.is_empty());
+
+        set_reconnect_same_ids(true);
+        remove_chip(other.device_id, other.chip_id);
+        let again = add_chip("boot-guid-2", "boot-phone", ProtoChipKind::UWB, "uwb", "", "");
+        set_reconnect_same_ids(false);
+        assert_eq!((again.device_id, again.chip_id), (other.device_id, other.chip_id));
+        assert_eq!(get_position(again.device_id).x, 5.0);
+        remove_chip(again.device_id, again.chip_id);
+    }
+
+    // Send a request to handle_devices and return the response.
+    fn devices_request(method: &str, param: &str, body: &str) -> String {
+        let request = HttpRequest {
+            method: method.to_string(),
+            uri: format!("/v1/devices/{param}"),
+            headers: HttpHeaders::new(),
+            version: "1.1".to_string(),
+            body: body.as_bytes().to_vec(),
+        };
+        let mut stream = std::io::Cursor::new(Vec::new());
+        handle_devices(&request, param, &mut ServerResponseWriter::new(&mut stream));
+        String::from_utf8(stream.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn test_handle_devices() {
+        let _lock = lock_devices();
+        let result = add_chip("rest-guid-1", "rest-device-1", ProtoChipKind::UWB, "uwb", "", "");
+        let other = add_chip("rest-guid-1", "rest-device-1", ProtoChipKind::WIFI, "wifi", "", "");
+        let id = result.device_id;
+        assert!(devices_request("GET", "", "").contains(r#""name": "rest-device-1""#));
+        let response =
+            devices_request("PATCH", &id.to_string(), r#"{"device": {"position": {"x": 6.0}}}"#);
+        assert!(response.starts_with("HTTP/1.1 200"));
+        assert!(devices_request("GET", &id.to_string(), "").contains(r#""x": 6.0"#));
+        assert!(devices_request("POST", &format!("{id}/reset"), "").starts_with("HTTP/1.1 200"));
+        assert_eq!(get_position(id).x, 0.0);
+
+        let chip_path = format!("{id}/chips/{}", other.chip_id);
+        assert!(devices_request("DELETE", &chip_path, "").starts_with("HTTP/1.1 200"));
+        assert!(devices_request("DELETE", &chip_path, "").starts_with("HTTP/1.1 404"));
+        assert!(devices_request("GET", "nan", "").starts_with("HTTP/1.1 400"));
+        assert!(devices_request("GET", "999999", "").starts_with("HTTP/1.1 404"));
+        assert!(devices_request("PUT", &id.to_string(), "").starts_with("HTTP/1.1 404"));
+        remove_chip(id, result.chip_id);
+    }
+
+    #[test]
+    fn test_filtered_devices() {
+        let _lock = lock_devices();
+        let watch = add_chip("label-guid-1", "label-watch", ProtoChipKind::UWB, "uwb", "", "");
+        let phone = add_chip("label-guid-2", "label-phone", ProtoChipKind::WIFI, "wifi", "", "");
+        let labels = r#"{"device": {"labels": {"team": "wear", "role": "central"}}}"#;
+        patch_device(Some(watch.device_id), labels).unwrap();
+        let labels = r#"{"device": {"labels": {"team": "phone", "owner": "lab"}}}"#;
+        patch_device(Some(phone.device_id), labels).unwrap();
+        // An empty value removes the label, and labels are kept on reset.
+        patch_device(Some(phone.device_id), r#"{"device": {"labels": {"owner": ""}}}"#).unwrap();
+        reset(phone.device_id).unwrap();
+        let list = |query: &str, body: &str| {
+            let request = HttpRequest {
+                method: "GET".to_string(),
+                uri: format!("/v1/devices?{query}"),
+                headers: HttpHeaders::new(),
+                version: "1.1".to_string(),
+                body: body.as_bytes().to_vec(),
+            };
+            let mut stream = std::io::Cursor::new(Vec::new());
+            handle_devices(&request, "", &mut ServerResponseWriter::new(&mut stream));
+            let response = String::from_utf8(stream.into_inner()).unwrap();
+            let names = ["label-watch", "label-phone"];
+            names.into_iter().filter(|name| response.contains(name)).collect::<Vec<_>>()
+        };
+        assert_eq!(list("", ""), vec!["label-watch", "label-phone"]);
+        assert_eq!(list("label_selector=team%3Dwear", ""), vec!["label-watch"]);
+        assert_eq!(list("label_selector=team!%3Dwear,owner", ""), Vec::<&str>::new());
+        assert_eq!(list("name_pattern=label-*&chip_kind=wifi", ""), vec!["label-phone"]);
+        assert_eq!(
+            list("", r#"{"labelSelector": "role", "namePattern": "*watch"}"#),
+            vec!["label-watch"]
+        );
+        let device = get_device(phone.device_id).unwrap();
+        assert!(device.contains(r#""team": "phone""#) && !device.contains("owner"));
+        let request = HttpRequest {
+            method: "GET".to_string(),
+            uri: "/v1/devices?chip_kind=zigbee".to_string(),
+            headers: HttpHeaders::new(),
+            version: "1.1".to_string(),
+            body: Vec::new(),
+        };
+        let mut stream = std::io::Cursor::new(Vec::new());
+        handle_devices(&request, "", &mut ServerResponseWriter::new(&mut stream));
+        assert!(stream.into_inner().starts_with(b"HTTP/1.1 400"));
+        remove_chip(watch.device_id, watch.chip_id);
+        remove_chip(phone.device_id, phone.chip_id);
+    }
+
+    #[test]
+    fn test_virtual_device() {
+        let _lock = lock_devices();
+        let request: ProtoDevice = protobuf_json_mapping::parse_from_str(
+            r#"{"name": "virtual-anchor", "behavior": "ANCHOR", "position": {"x": 1.0},
+                "chips": [{"kind": "BLUETOOTH", "name": "ble"}]}"#,
+        )
+        .unwrap();
+        let start = Instant::now();
+        // Create the device without create_device, which starts the tick thread.
+        let device =
+            create_virtual_device(&mut DEVICES.write().unwrap(), &request, &mut Vec::new())
+                .unwrap();
+        assert_eq!(device.chips.len(), 2);
+        assert_eq!(device.position.x, 1.0);
+        let uwb_tx_count = || {
+            let device = get_device(device.id).unwrap();
+            let device: ProtoDevice = protobuf_json_mapping::parse_from_str(&device).unwrap();
+            device.chips.iter().find(|chip| chip.has_uwb()).unwrap().uwb().tx_count
+        };
+        // The anchor ranges on the first tick and then once per interval.
+        DEVICES.read().unwrap().behaviors.lock().unwrap().insert(device.id, start);
+        tick_behaviors(start);
+        tick_behaviors(start + Duration::from_millis(100));
+        assert_eq!(uwb_tx_count(), 1);
+        tick_behaviors(start + Duration::from_millis(200));
+        assert_eq!(uwb_tx_count(), 2);
+
+        let request = ProtoDevice { name: "virtual-anchor".to_string(), ..request };
+        assert!(create_virtual_device(&mut DEVICES.write().unwrap(), &request, &mut Vec::new())
+            .is_err());
+        let request = ProtoDevice { name: "virtual-idle".to_string(), ..Default::default() };
+        assert!(create_virtual_device(&mut DEVICES.write().unwrap(), &request, &mut Vec::new())
+            .is_err());
+        let transport = add_chip("virtual-guid", "not-virtual", ProtoChipKind::UWB, "uwb", "", "");
+        assert!(delete_device(transport.device_id).is_err());
+        remove_chip(transport.device_id, transport.chip_id);
+
+        delete_device(device.id).unwrap();
+        assert!(get_device(device.id).is_err());
+        assert!(!DEVICES.read().unwrap().behaviors.lock().unwrap().contains_key(&device.id));
+    }
+
+    fn get_position(id: DeviceIdentifier) -> ProtoPosition {
+        DEVICES.read().unwrap().devices.get(&id).unwrap().read().unwrap().position.clone()
+    }
+
+    #[test]
+    fn test_restore_scene() {
+        let _lock = lock_devices();
+        let result =
+            add_chip("scene-guid-1", "scene-device-1", ProtoChipKind::BLUETOOTH, "bt", "", "");
+        let id = result.device_id;
+        restore_scene(
+            r#"{"devices": [
+                {"name": "scene-device-1", "position": {"x": 1.0, "y": 2.0}},
+                {"name": "scene-device-2", "position": {"z": 3.0},
+                 "chips": [{"kind": "BLUETOOTH", "name": "bt"}]}
+            ]}"#,
+        )
+        .unwrap();
+        // A connected device is restored immediately.
+        assert_eq!(get_position(id), new_with_xyz(1.0, 2.0, 0.0));
+        // A device that connects later is restored when it connects.
+        let other =
+            add_chip("scene-guid-2", "scene-device-2", ProtoChipKind::BLUETOOTH, "bt", "", "");
+        assert_eq!(get_position(other.device_id), new_with_xyz(0.0, 0.0, 3.0));
+        assert!(DEVICES.read().unwrap().pending_scene.is_empty());
+        assert!(matches!(restore_scene("not json"), Err(RequestError::BadRequest(_))));
+        remove_chip(id, result.chip_id);
+        remove_chip(other.device_id, other.chip_id);
+    }
+
+    #[test]
+    fn test_save_and_load_scene() {
+        let _lock = lock_devices();
+        let result =
+            add_chip("scene-guid-3", "scene-device-3", ProtoChipKind::BLUETOOTH, "bt", "", "");
+        let id = result.device_id;
+        patch_device(Some(id), r#"{"device": {"position": {"x": 4.0}}}"#).unwrap();
+        let path = "netsim-test-scene.json";
+        save_scene(path).unwrap();
+        reset(id).unwrap();
+        assert_eq!(get_position(id), new_with_xyz(0.0, 0.0, 0.0));
+        load_scene(path).unwrap();
+        assert_eq!(get_position(id), new_with_xyz(4.0, 0.0, 0.0));
+        assert!(matches!(load_scene("nonexistent-scene.json"), Err(RequestError::NotFound(_))));
+        for path in ["", "/tmp/netsim-scene.json", "../netsim-scene.json", ".netsim"] {
+            assert!(matches!(save_scene(path), Err(RequestError::BadRequest(_))));
+            assert!(matches!(load_scene(path), Err(RequestError::BadRequest(_))));
+        }
+        remove_chip(id, result.chip_id);
+    }
+
+    const BENCH_THREADS: usize = 8;
+
+    // Run the benchmark workload from parallel threads: add, patch and read
+    // devices while the devices send UWB packets through the packet path,
+    // the capture and traffic of the request and the delivery to the peers
+    // the device model does not drop. With `global`, every operation holds
+    // that one lock, as with a single lock around the devices. Returns the
+    // number of packets and the elapsed time.
+    fn run_bench_workload(global: Option<Arc<Mutex<()>>>) -> (usize, Duration) {
+        const DEVICES_PER_THREAD: usize = 100;
+        const PACKETS_PER_DEVICE: usize = 10;
+        let packet = [0u8; 64];
+        let start = Instant::now();
+        // Each run has its own devices, not reconnections of the last run.
+        let run = if global.is_some() { "global" } else { "local" };
+        let workers: Vec<_> = (0..BENCH_THREADS)
+            .map(|thread_index| {
+                let global = global.clone();
+                thread::spawn(move || {
+                    let serialize = |operation: &mut dyn FnMut()| {
+                        let _guard = global.as_ref().map(|global| global.lock().unwrap());
+                        operation();
+                    };
+                    let mut added = Vec::new();
+                    for i in 0..DEVICES_PER_THREAD {
+                        let guid = format!("bench-guid-{run}-{thread_index}-{i}");
+                        let name = format!("bench-device-{run}-{thread_index}-{i}");
+                        serialize(&mut || {
+                            let result = add_chip(&guid, &name, ProtoChipKind::UWB, "uwb", "", "");
+                            added.push((result.device_id, result.chip_id, result.facade_id));
+                        });
+                        let (id, _, facade_id) = added[i / 2];
+                        let patch = format!(r#"{{"device": {{"position": {{"x": {i}.0}}}}}}"#);
+                        serialize(&mut || patch_device(Some(id), &patch).unwrap());
+                        serialize(&mut || {
+                            get_device(id).unwrap();
+                        });
+                        for _ in 0..PACKETS_PER_DEVICE {
+                            serialize(&mut || {
+                                handle_packet(
+                                    ProtoChipKind::UWB.value() as u32,
+                                    facade_id as u32,
+                                    &packet,
+                                    0,
+                                    PacketDirection::HostToController,
+                                );
+                                handle_uwb_request(facade_id as u32, &packet);
+                            });
+                        }
+                    }
+                    added
+                })
+            })
+            .collect();
+        let added: Vec<_> = workers.into_iter().flat_map(|worker| worker.join().unwrap()).collect();
+        let elapsed = start.elapsed();
+        for (device_id, chip_id, _) in &added {
+            remove_chip(*device_id, *chip_id);
+        }
+        (added.len() * PACKETS_PER_DEVICE, elapsed)
+    }
+
+    // Throughput of the per-device locks against the same workload through
+    // one global lock. Run with
+    // `cargo test -p netsim-cxx --lib --release -- --ignored --nocapture bench`.
+    #[test]
+    #[ignore]
+    fn bench_parallel_devices() {
+        let _lock = lock_devices();
+        let (packets, global_elapsed) = run_bench_workload(Some(Arc::new(Mutex::new(()))));
+        let (_, elapsed) = run_bench_workload(None);
+        let rate = |elapsed: Duration| packets as f64 / elapsed.as_secs_f64();
+        println!(
+            "{packets} packets from {BENCH_THREADS} threads: global lock {:.0} packets/s, \
+             per-device locks {:.0} packets/s, speedup {:.2}x on {} cpus",
+            rate(global_elapsed),
+            rate(elapsed),
+            global_elapsed.as_secs_f64() / elapsed.as_secs_f64(),
+            thread::available_parallelism().map_or(1, |cpus| cpus.get())
+        );
+    }
 }
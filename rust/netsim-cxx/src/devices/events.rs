@@ -0,0 +1,141 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A scene event bus for the devices module.
+//!
+//! The devices_handler publishes an Event whenever the scene changes
+//! and every subscriber receives a copy over its own channel. It backs
+//! the RegisterEvents stream in frontend.proto.
+
+use crate::devices::chip::ChipIdentifier;
+use crate::devices::device::DeviceIdentifier;
+use frontend_proto::common::ChipKind as ProtoChipKind;
+use frontend_proto::model::Orientation as ProtoOrientation;
+use frontend_proto::model::Position as ProtoPosition;
+use frontend_proto::model::State as ProtoState;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::RwLock;
+
+pub type SubscriberIdentifier = u32;
+
+// The event bus is a singleton shared by all devices.
+lazy_static! {
+    static ref EVENTS: RwLock<Events> = RwLock::new(Events::new());
+}
+
+/// Typed scene events published by the devices_handler.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Event {
+    DeviceAdded { id: DeviceIdentifier, name: String },
+    DeviceRemoved { id: DeviceIdentifier, name: String },
+    ChipAdded { device_id: DeviceIdentifier, chip_id: ChipIdentifier, kind: ProtoChipKind },
+    ChipRemoved { device_id: DeviceIdentifier, chip_id: ChipIdentifier },
+    ChipPatched { device_id: DeviceIdentifier, chip_id: ChipIdentifier },
+    PositionChanged { id: DeviceIdentifier, position: ProtoPosition, orientation: ProtoOrientation },
+    VisibilityChanged { id: DeviceIdentifier, visible: ProtoState },
+    ZoneChanged { id: DeviceIdentifier, zone: String },
+    Reset { id: DeviceIdentifier },
+}
+
+impl Event {
+    /// The identifier of the device the event refers to.
+    pub fn device_id(&self) -> DeviceIdentifier {
+        match self {
+            Event::DeviceAdded { id, .. }
+            | Event::DeviceRemoved { id, .. }
+            | Event::PositionChanged { id, .. }
+            | Event::VisibilityChanged { id, .. }
+            | Event::ZoneChanged { id, .. }
+            | Event::Reset { id } => *id,
+            Event::ChipAdded { device_id, .. }
+            | Event::ChipRemoved { device_id, .. }
+            | Event::ChipPatched { device_id, .. } => *device_id,
+        }
+    }
+}
+
+/// The subscriber registry.
+struct Events {
+    subscribers: HashMap<SubscriberIdentifier, Sender<Event>>,
+    next_id: SubscriberIdentifier,
+}
+
+impl Events {
+    fn new() -> Self {
+        Events { subscribers: HashMap::new(), next_id: 0 }
+    }
+
+    fn subscribe(&mut self) -> (SubscriberIdentifier, Receiver<Event>) {
+        let (tx, rx) = channel::<Event>();
+        self.next_id = self.next_id.wrapping_add(1);
+        self.subscribers.insert(self.next_id, tx);
+        (self.next_id, rx)
+    }
+
+    fn publish(&mut self, event: Event) {
+        // Subscribers that dropped their receiver are removed lazily.
+        self.subscribers.retain(|_, tx| tx.send(event.clone()).is_ok());
+    }
+}
+
+/// Register for scene events.
+///
+/// Returns the subscriber identifier used to unsubscribe and the
+/// receiving end of the channel the events are delivered on. Dropping
+/// the receiver is equivalent to unsubscribing.
+pub fn subscribe() -> (SubscriberIdentifier, Receiver<Event>) {
+    EVENTS.write().unwrap().subscribe()
+}
+
+/// Unregister a subscriber.
+pub fn unsubscribe(id: SubscriberIdentifier) {
+    EVENTS.write().unwrap().subscribers.remove(&id);
+}
+
+/// Publish an event to all subscribers.
+pub fn publish(event: Event) {
+    EVENTS.write().unwrap().publish(event);
+}
+
+/// Publish events to all subscribers, in order.
+pub fn publish_all(events: Vec<Event>) {
+    let mut bus = EVENTS.write().unwrap();
+    events.into_iter().for_each(|event| bus.publish(event));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publish_to_subscribers() {
+        let mut events = Events::new();
+        let (_, rx1) = events.subscribe();
+        let (_, rx2) = events.subscribe();
+        events.publish(Event::Reset { id: 7 });
+        assert_eq!(rx1.try_recv(), Ok(Event::Reset { id: 7 }));
+        assert_eq!(rx2.try_recv(), Ok(Event::Reset { id: 7 }));
+    }
+
+    #[test]
+    fn test_dropped_receiver_is_removed() {
+        let mut events = Events::new();
+        let (_, rx) = events.subscribe();
+        drop(rx);
+        events.publish(Event::Reset { id: 7 });
+        assert!(events.subscribers.is_empty());
+    }
+}
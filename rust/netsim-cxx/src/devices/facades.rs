@@ -12,53 +12,259 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! Chip facades and the registry of facades by chip kind.
+//!
+//! A chip facade is the library that emulates the radio of a chip kind.
+//! Each facade implements the `ChipFacade` trait and is registered for
+//! its ChipKind, so `Chip` dispatches to the radio without knowing the
+//! chip kind. Tests register fake facades the same way.
+
+use frontend_proto::common::ChipKind as ProtoChipKind;
 use frontend_proto::model::chip::Bluetooth as ProtoBluetoothChip;
+use frontend_proto::model::chip::Chip as ProtoChipRadio;
 use frontend_proto::model::chip::Radio as ProtoRadioChip;
+use lazy_static::lazy_static;
+use protobuf::Message;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
 
 use super::device::DeviceIdentifier;
+use crate::ffi;
+use crate::uwb::facade::UwbFacade;
 
 pub type FacadeIdentifier = i32;
 
-pub fn hci_get(facade_id: FacadeIdentifier) -> ProtoBluetoothChip {
-    println!("hci_get({})", facade_id);
-    ProtoBluetoothChip::new()
+lazy_static! {
+    static ref FACADES: RwLock<FacadeRegistry> = RwLock::new(FacadeRegistry::with_defaults());
+}
+
+/// The interface between a Chip and the radio emulation of its kind.
+pub trait ChipFacade: Send + Sync {
+    /// Add a radio for a device and return its facade identifier.
+    fn add(&self, device_id: DeviceIdentifier) -> FacadeIdentifier;
+    fn remove(&self, facade_id: FacadeIdentifier);
+    /// The proto radio of the chip, e.g. `ProtoChipRadio::Bt`.
+    fn get(&self, facade_id: FacadeIdentifier) -> ProtoChipRadio;
+    fn patch(&self, facade_id: FacadeIdentifier, patch: &ProtoChipRadio);
+    fn reset(&self, facade_id: FacadeIdentifier);
+    /// Send a packet from the chip, used by virtual devices. The default
+    /// ignores the packet.
+    fn transmit(&self, _facade_id: FacadeIdentifier, _packet: &[u8]) {}
+}
+
+/// The ChipKind of a proto radio.
+pub fn radio_kind(radio: &ProtoChipRadio) -> ProtoChipKind {
+    match radio {
+        ProtoChipRadio::Bt(_) => ProtoChipKind::BLUETOOTH,
+        ProtoChipRadio::Wifi(_) => ProtoChipKind::WIFI,
+        ProtoChipRadio::Uwb(_) => ProtoChipKind::UWB,
+        _ => ProtoChipKind::UNSPECIFIED,
+    }
 }
 
-pub fn wifi_get(facade_id: FacadeIdentifier) -> ProtoRadioChip {
-    println!("wifi_get({})", facade_id);
-    ProtoRadioChip::new()
+/// The chip facades by ChipKind.
+struct FacadeRegistry {
+    facades: HashMap<ProtoChipKind, Arc<dyn ChipFacade>>,
 }
 
-pub fn hci_remove(facade_id: FacadeIdentifier) {
-    println!("hci_remove({})", facade_id);
+impl FacadeRegistry {
+    fn new() -> Self {
+        FacadeRegistry { facades: HashMap::new() }
+    }
+
+    fn with_defaults() -> Self {
+        let mut registry = FacadeRegistry::new();
+        registry.register(ProtoChipKind::BLUETOOTH, bluetooth_facade());
+        registry.register(ProtoChipKind::WIFI, wifi_facade());
+        registry.register(ProtoChipKind::UWB, Arc::new(UwbFacade));
+        registry
+    }
+
+    /// Register the facade for a chip kind, returning the facade it
+    /// replaces.
+    fn register(
+        &mut self,
+        kind: ProtoChipKind,
+        facade: Arc<dyn ChipFacade>,
+    ) -> Option<Arc<dyn ChipFacade>> {
+        self.facades.insert(kind, facade)
+    }
+
+    fn get(&self, kind: ProtoChipKind) -> Option<Arc<dyn ChipFacade>> {
+        self.facades.get(&kind).cloned()
+    }
 }
 
-pub fn wifi_remove(facade_id: FacadeIdentifier) {
-    println!("wifi_remove({})", facade_id);
+/// Register the facade for a chip kind in the global registry. Chips
+/// that are already added keep the facade they were created with.
+pub fn register_facade(
+    kind: ProtoChipKind,
+    facade: Arc<dyn ChipFacade>,
+) -> Option<Arc<dyn ChipFacade>> {
+    FACADES.write().unwrap().register(kind, facade)
+}
+
+/// The facade registered for a chip kind.
+pub fn get_facade(kind: ProtoChipKind) -> Option<Arc<dyn ChipFacade>> {
+    FACADES.read().unwrap().get(kind)
+}
+
+/// The Bluetooth facade of the rootcanal library, see hci/bluetooth_facade.h.
+struct BluetoothFacade;
+
+impl ChipFacade for BluetoothFacade {
+    fn add(&self, device_id: DeviceIdentifier) -> FacadeIdentifier {
+        ffi::hci_add(device_id as u32) as FacadeIdentifier
+    }
+
+    fn remove(&self, facade_id: FacadeIdentifier) {
+        ffi::hci_remove(facade_id as u32);
+    }
+
+    fn get(&self, facade_id: FacadeIdentifier) -> ProtoChipRadio {
+        let proto_bytes = ffi::hci_get(facade_id as u32);
+        ProtoChipRadio::Bt(ProtoBluetoothChip::parse_from_bytes(&proto_bytes).unwrap_or_default())
+    }
+
+    fn patch(&self, facade_id: FacadeIdentifier, patch: &ProtoChipRadio) {
+        if let ProtoChipRadio::Bt(bt) = patch {
+            ffi::hci_patch(facade_id as u32, &bt.write_to_bytes().unwrap_or_default());
+        }
+    }
+
+    fn reset(&self, facade_id: FacadeIdentifier) {
+        ffi::hci_reset(facade_id as u32);
+    }
+
+    fn transmit(&self, facade_id: FacadeIdentifier, packet: &[u8]) {
+        ffi::hci_transmit(facade_id as u32, packet);
+    }
 }
 
-pub fn hci_add(_device_id: DeviceIdentifier) -> FacadeIdentifier {
-    println!("hci_add()");
-    0
+/// The WiFi facade, see wifi/wifi_facade.h.
+struct WifiFacade;
+
+impl ChipFacade for WifiFacade {
+    fn add(&self, device_id: DeviceIdentifier) -> FacadeIdentifier {
+        ffi::wifi_add(device_id as u32) as FacadeIdentifier
+    }
+
+    fn remove(&self, facade_id: FacadeIdentifier) {
+        ffi::wifi_remove(facade_id as u32);
+    }
+
+    fn get(&self, facade_id: FacadeIdentifier) -> ProtoChipRadio {
+        let proto_bytes = ffi::wifi_get(facade_id as u32);
+        ProtoChipRadio::Wifi(ProtoRadioChip::parse_from_bytes(&proto_bytes).unwrap_or_default())
+    }
+
+    fn patch(&self, facade_id: FacadeIdentifier, patch: &ProtoChipRadio) {
+        if let ProtoChipRadio::Wifi(radio) = patch {
+            ffi::wifi_patch(facade_id as u32, &radio.write_to_bytes().unwrap_or_default());
+        }
+    }
+
+    fn reset(&self, facade_id: FacadeIdentifier) {
+        ffi::wifi_reset(facade_id as u32);
+    }
+
+    fn transmit(&self, facade_id: FacadeIdentifier, packet: &[u8]) {
+        ffi::wifi_transmit(facade_id as u32, packet);
+    }
 }
 
-pub fn wifi_add(_device_id: DeviceIdentifier) -> FacadeIdentifier {
-    println!("wifi_add()");
-    0
+// The C++ facades are not linked into the unit tests, where the Bluetooth
+// and WiFi chips get in-memory radios instead.
+#[cfg(not(test))]
+fn bluetooth_facade() -> Arc<dyn ChipFacade> {
+    Arc::new(BluetoothFacade)
 }
 
-pub fn hci_patch(_facade_id: FacadeIdentifier, _patch: &ProtoBluetoothChip) {
-    println!("hci_patch()");
+#[cfg(not(test))]
+fn wifi_facade() -> Arc<dyn ChipFacade> {
+    Arc::new(WifiFacade)
 }
 
-pub fn wifi_patch(_facade_id: FacadeIdentifier, _patch: &ProtoRadioChip) {
-    println!("wifi_patch()");
+#[cfg(test)]
+fn bluetooth_facade() -> Arc<dyn ChipFacade> {
+    Arc::new(tests::MemoryFacade::new(ProtoChipRadio::Bt(ProtoBluetoothChip::new())))
 }
 
-pub fn hci_reset(_facade_id: FacadeIdentifier) {
-    println!("bt_reset()");
+#[cfg(test)]
+fn wifi_facade() -> Arc<dyn ChipFacade> {
+    Arc::new(tests::MemoryFacade::new(ProtoChipRadio::Wifi(ProtoRadioChip::new())))
 }
 
-pub fn wifi_reset(_facade_id: FacadeIdentifier) {
-    println!("wifi_reset()");
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicI32, Ordering};
+    use std::sync::Mutex;
+
+    /// A facade keeping the radio of each chip in memory.
+    pub struct MemoryFacade {
+        new_radio: ProtoChipRadio,
+        radios: Mutex<HashMap<FacadeIdentifier, ProtoChipRadio>>,
+        next_facade_id: AtomicI32,
+    }
+
+    impl MemoryFacade {
+        pub fn new(new_radio: ProtoChipRadio) -> Self {
+            MemoryFacade {
+                new_radio,
+                radios: Mutex::new(HashMap::new()),
+                next_facade_id: AtomicI32::new(0),
+            }
+        }
+    }
+
+    impl ChipFacade for MemoryFacade {
+        fn add(&self, _device_id: DeviceIdentifier) -> FacadeIdentifier {
+            let facade_id = self.next_facade_id.fetch_add(1, Ordering::SeqCst);
+            self.radios.lock().unwrap().insert(facade_id, self.new_radio.clone());
+            facade_id
+        }
+        fn remove(&self, facade_id: FacadeIdentifier) {
+            self.radios.lock().unwrap().remove(&facade_id);
+        }
+        fn get(&self, facade_id: FacadeIdentifier) -> ProtoChipRadio {
+            let radios = self.radios.lock().unwrap();
+            radios.get(&facade_id).unwrap_or(&self.new_radio).clone()
+        }
+        fn patch(&self, facade_id: FacadeIdentifier, patch: &ProtoChipRadio) {
+            if let Some(radio) = self.radios.lock().unwrap().get_mut(&facade_id) {
+                *radio = patch.clone();
+            }
+        }
+        fn reset(&self, facade_id: FacadeIdentifier) {
+            if let Some(radio) = self.radios.lock().unwrap().get_mut(&facade_id) {
+                *radio = self.new_radio.clone();
+            }
+        }
+    }
+
+    struct FakeFacade;
+
+    impl ChipFacade for FakeFacade {
+        fn add(&self, _device_id: DeviceIdentifier) -> FacadeIdentifier {
+            42
+        }
+        fn remove(&self, _facade_id: FacadeIdentifier) {}
+        fn get(&self, _facade_id: FacadeIdentifier) -> ProtoChipRadio {
+            ProtoChipRadio::Uwb(ProtoRadioChip::new())
+        }
+        fn patch(&self, _facade_id: FacadeIdentifier, _patch: &ProtoChipRadio) {}
+        fn reset(&self, _facade_id: FacadeIdentifier) {}
+    }
+
+    #[test]
+    fn test_registry() {
+        let mut registry = FacadeRegistry::with_defaults();
+        assert!(registry.get(ProtoChipKind::BLUETOOTH).is_some());
+        assert!(registry.get(ProtoChipKind::UNSPECIFIED).is_none());
+        assert!(registry.register(ProtoChipKind::UWB, Arc::new(FakeFacade)).is_some());
+        let facade = registry.get(ProtoChipKind::UWB).unwrap();
+        assert_eq!(facade.add(1), 42);
+        assert_eq!(radio_kind(&facade.get(42)), ProtoChipKind::UWB);
+    }
 }
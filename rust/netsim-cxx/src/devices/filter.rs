@@ -0,0 +1,182 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Filters of the device listing.
+//!
+//! A device is listed when it matches every filter that is set: the
+//! label selector, the name pattern and the chip kind. The label selector
+//! is a comma separated list of requirements, "key=value", "key!=value"
+//! or "key" for a label that is set. The name pattern is a glob where
+//! '*' matches any characters.
+
+use crate::devices::device::Device;
+use frontend_proto::common::ChipKind as ProtoChipKind;
+use frontend_proto::frontend::GetDevicesRequest;
+use protobuf::Enum;
+
+#[derive(Debug, PartialEq)]
+enum Requirement {
+    Equals(String, String),
+    NotEquals(String, String),
+    Exists(String),
+}
+
+impl Requirement {
+    fn parse(text: &str) -> Result<Self, String> {
+        let (key, requirement) = if let Some((key, value)) = text.split_once("!=") {
+            (key.trim(), Requirement::NotEquals(key.trim().to_string(), value.trim().to_string()))
+        } else if let Some((key, value)) = text.split_once('=') {
+            (key.trim(), Requirement::Equals(key.trim().to_string(), value.trim().to_string()))
+        } else {
+            (text.trim(), Requirement::Exists(text.trim().to_string()))
+        };
+        if key.is_empty() {
+            return Err(format!("Invalid label requirement '{text}'"));
+        }
+        Ok(requirement)
+    }
+
+    fn matches(&self, device: &Device) -> bool {
+        match self {
+            Requirement::Equals(key, value) => device.labels.get(key) == Some(value),
+            Requirement::NotEquals(key, value) => device.labels.get(key) != Some(value),
+            Requirement::Exists(key) => device.labels.contains_key(key),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct DeviceFilter {
+    requirements: Vec<Requirement>,
+    name_pattern: Option<String>,
+    chip_kind: Option<ProtoChipKind>,
+}
+
+impl DeviceFilter {
+    pub fn from_proto(request: &GetDevicesRequest) -> Result<Self, String> {
+        let requirements = request
+            .label_selector
+            .split(',')
+            .filter(|requirement| !requirement.trim().is_empty())
+            .map(Requirement::parse)
+            .collect::<Result<_, _>>()?;
+        let name_pattern = Some(request.name_pattern.clone()).filter(|pattern| !pattern.is_empty());
+        let chip_kind = match request.chip_kind.enum_value() {
+            Ok(ProtoChipKind::UNSPECIFIED) => None,
+            Ok(kind) => Some(kind),
+            Err(value) => return Err(format!("Invalid chip kind {value}")),
+        };
+        Ok(DeviceFilter { requirements, name_pattern, chip_kind })
+    }
+
+    pub fn matches(&self, device: &Device) -> bool {
+        self.requirements.iter().all(|requirement| requirement.matches(device))
+            && match &self.name_pattern {
+                Some(pattern) => glob_match(pattern, &device.name),
+                None => true,
+            }
+            && match self.chip_kind {
+                Some(kind) => device.chips.values().any(|chip| chip.kind == kind),
+                None => true,
+            }
+    }
+}
+
+/// Parse a chip kind name such as "bluetooth", ignoring case.
+pub fn parse_chip_kind(name: &str) -> Result<ProtoChipKind, String> {
+    ProtoChipKind::VALUES
+        .iter()
+        .find(|kind| format!("{kind:?}").eq_ignore_ascii_case(name))
+        .copied()
+        .ok_or(format!("Invalid chip kind {name}"))
+}
+
+// Match the text against a pattern where '*' matches any characters.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let mut rest = match text.strip_prefix(first) {
+        Some(rest) => rest,
+        None => return false,
+    };
+    let parts: Vec<&str> = parts.collect();
+    // The pattern has no '*' when there is a single part.
+    let (last, middle) = match parts.split_last() {
+        Some((last, middle)) => (last, middle),
+        None => return rest.is_empty(),
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device(name: &str, labels: &[(&str, &str)]) -> Device {
+        let mut device = Device::new(1, format!("guid-{name}"), name.to_string());
+        for (key, value) in labels {
+            device.labels.insert(key.to_string(), value.to_string());
+        }
+        device
+    }
+
+    fn filter(label_selector: &str, name_pattern: &str) -> DeviceFilter {
+        let request = GetDevicesRequest {
+            label_selector: label_selector.to_string(),
+            name_pattern: name_pattern.to_string(),
+            ..Default::default()
+        };
+        DeviceFilter::from_proto(&request).unwrap()
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("emulator-5554", "emulator-5554"));
+        assert!(!glob_match("emulator", "emulator-5554"));
+        assert!(glob_match("emu*", "emulator-5554"));
+        assert!(glob_match("*5554", "emulator-5554"));
+        assert!(glob_match("e*-*4", "emulator-5554"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("*5556", "emulator-5554"));
+        assert!(!glob_match("a*a", "a"));
+    }
+
+    #[test]
+    fn test_matches() {
+        let watch = device("watch-1", &[("team", "wear"), ("role", "central")]);
+        let phone = device("phone-1", &[("team", "phone")]);
+        assert!(filter("", "").matches(&watch));
+        assert!(filter("team=wear", "").matches(&watch));
+        assert!(!filter("team=wear", "").matches(&phone));
+        assert!(filter(" team != wear ", "").matches(&phone));
+        assert!(filter("team,role", "watch*").matches(&watch));
+        assert!(!filter("team,role", "").matches(&phone));
+        assert!(!filter("team=wear", "phone*").matches(&watch));
+        // No device has a chip of the kind.
+        let request =
+            GetDevicesRequest { chip_kind: ProtoChipKind::UWB.into(), ..Default::default() };
+        assert!(!DeviceFilter::from_proto(&request).unwrap().matches(&watch));
+        let request =
+            GetDevicesRequest { label_selector: "=wear".to_string(), ..Default::default() };
+        assert!(DeviceFilter::from_proto(&request).is_err());
+        assert_eq!(parse_chip_kind("wifi"), Ok(ProtoChipKind::WIFI));
+        assert!(parse_chip_kind("zigbee").is_err());
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A simplified link loss model.
+//!
+//! Each link between two devices on a radio has a fixed probability of
+//! dropping a packet, where a loss of 0 is a perfect medium. Links are
+//! symmetric and links that are not in the table are lossless. The loss
+//! is applied by the radio facades, through
+//! `devices_handler::should_drop_packet`, when a packet is delivered
+//! between chips.
+
+use crate::devices::device::DeviceIdentifier;
+use frontend_proto::model::PhyKind as ProtoPhyKind;
+use lazy_static::lazy_static;
+use protobuf::Enum;
+use rand::Rng;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+lazy_static! {
+    static ref LINK_LOSS: RwLock<LinkLoss> = RwLock::new(LinkLoss::new());
+}
+
+/// A link is identified by its two devices, lowest id first, and radio.
+type LinkKey = (DeviceIdentifier, DeviceIdentifier, ProtoPhyKind);
+
+fn link_key(id: DeviceIdentifier, other_id: DeviceIdentifier, radio: ProtoPhyKind) -> LinkKey {
+    (id.min(other_id), id.max(other_id), radio)
+}
+
+/// The loss table.
+struct LinkLoss {
+    links: HashMap<LinkKey, f32>,
+}
+
+impl LinkLoss {
+    fn new() -> Self {
+        LinkLoss { links: HashMap::new() }
+    }
+
+    fn set(
+        &mut self,
+        id: DeviceIdentifier,
+        other_id: DeviceIdentifier,
+        radio: ProtoPhyKind,
+        loss: f32,
+    ) -> Result<(), String> {
+        if id == other_id {
+            return Err(format!("Link loss requires two different devices, got {id} twice"));
+        }
+        if radio == ProtoPhyKind::NONE {
+            return Err("Link loss requires a radio".to_string());
+        }
+        if !(0.0..=1.0).contains(&loss) {
+            return Err(format!("Link loss {loss} is not between 0 and 1"));
+        }
+        let key = link_key(id, other_id, radio);
+        if loss == 0.0 {
+            self.links.remove(&key);
+        } else {
+            self.links.insert(key, loss);
+        }
+        Ok(())
+    }
+
+    fn get(&self, id: DeviceIdentifier, other_id: DeviceIdentifier, radio: ProtoPhyKind) -> f32 {
+        self.links.get(&link_key(id, other_id, radio)).copied().unwrap_or(0.0)
+    }
+}
+
+/// Set the loss of the link between two devices on a radio. A loss of 0
+/// removes the link from the table.
+pub fn set_link_loss(
+    id: DeviceIdentifier,
+    other_id: DeviceIdentifier,
+    radio: ProtoPhyKind,
+    loss: f32,
+) -> Result<(), String> {
+    LINK_LOSS.write().unwrap().set(id, other_id, radio, loss)
+}
+
+/// The loss of the link between two devices on a radio.
+pub fn get_link_loss(id: DeviceIdentifier, other_id: DeviceIdentifier, radio: ProtoPhyKind) -> f32 {
+    LINK_LOSS.read().unwrap().get(id, other_id, radio)
+}
+
+/// All the lossy links as (device, other device, radio, loss).
+pub fn list_link_loss() -> Vec<(DeviceIdentifier, DeviceIdentifier, ProtoPhyKind, f32)> {
+    let mut links: Vec<_> = LINK_LOSS
+        .read()
+        .unwrap()
+        .links
+        .iter()
+        .map(|(&(id, other_id, radio), &loss)| (id, other_id, radio, loss))
+        .collect();
+    links.sort_by_key(|&(id, other_id, radio, _)| (id, other_id, radio.value()));
+    links
+}
+
+/// Remove every link of a device, called when the device is removed.
+pub fn remove_device_links(id: DeviceIdentifier) {
+    LINK_LOSS.write().unwrap().links.retain(|&(a, b, _), _| a != id && b != id);
+}
+
+/// Remove all links.
+pub fn clear_link_loss() {
+    LINK_LOSS.write().unwrap().links.clear();
+}
+
+/// Decide whether a packet sent from one device to another on a radio is
+/// lost on the link.
+pub fn should_drop_packet(device_id: u32, other_device_id: u32, radio: i32) -> bool {
+    let radio = match ProtoPhyKind::from_i32(radio) {
+        Some(radio) => radio,
+        None => return false,
+    };
+    let loss =
+        get_link_loss(device_id as DeviceIdentifier, other_device_id as DeviceIdentifier, radio);
+    loss > 0.0 && rand::thread_rng().gen::<f32>() < loss
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_link_is_symmetric() {
+        let mut link_loss = LinkLoss::new();
+        link_loss.set(1, 2, ProtoPhyKind::BLUETOOTH_LOW_ENERGY, 0.5).unwrap();
+        assert_eq!(link_loss.get(2, 1, ProtoPhyKind::BLUETOOTH_LOW_ENERGY), 0.5);
+        assert_eq!(link_loss.get(1, 2, ProtoPhyKind::WIFI), 0.0);
+        assert_eq!(link_loss.get(1, 3, ProtoPhyKind::BLUETOOTH_LOW_ENERGY), 0.0);
+    }
+
+    #[test]
+    fn test_zero_loss_removes_link() {
+        let mut link_loss = LinkLoss::new();
+        link_loss.set(1, 2, ProtoPhyKind::WIFI, 0.25).unwrap();
+        link_loss.set(2, 1, ProtoPhyKind::WIFI, 0.0).unwrap();
+        assert!(link_loss.links.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_link_loss() {
+        let mut link_loss = LinkLoss::new();
+        assert!(link_loss.set(1, 1, ProtoPhyKind::WIFI, 0.5).is_err());
+        assert!(link_loss.set(1, 2, ProtoPhyKind::NONE, 0.5).is_err());
+        assert!(link_loss.set(1, 2, ProtoPhyKind::WIFI, 1.5).is_err());
+        assert!(link_loss.set(1, 2, ProtoPhyKind::WIFI, -0.1).is_err());
+        assert!(link_loss.links.is_empty());
+    }
+
+    #[test]
+    fn test_should_drop_packet() {
+        let radio = ProtoPhyKind::UWB;
+        set_link_loss(9001, 9002, radio, 1.0).unwrap();
+        assert!(should_drop_packet(9002, 9001, radio.value()));
+        assert!(!should_drop_packet(9001, 9003, radio.value()));
+        remove_device_links(9001);
+        assert!(!should_drop_packet(9001, 9002, radio.value()));
+    }
+}
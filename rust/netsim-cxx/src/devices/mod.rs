@@ -12,8 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod behavior;
 pub mod chip;
 pub mod device;
 pub mod devices_handler;
+pub mod events;
 pub mod facades;
+pub mod filter;
 pub mod id_factory;
+pub mod link_loss;
+pub mod reconnect;
+pub mod traffic;
+pub mod trajectory;
+pub mod zones;
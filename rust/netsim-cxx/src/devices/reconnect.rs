@@ -0,0 +1,153 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The last known state of departed devices.
+//!
+//! A device departs when its last chip is removed, for example while an
+//! emulator reboots. Its state is remembered for a grace period so that
+//! the device gets its position, radio states and capture settings back
+//! when it reconnects with the same guid or name. Optionally the device
+//! and its chips also get their identifiers back.
+
+use crate::devices::chip::ChipIdentifier;
+use crate::devices::device::DeviceIdentifier;
+use frontend_proto::common::ChipKind as ProtoChipKind;
+use frontend_proto::model::Device as ProtoDevice;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+const DEFAULT_GRACE_PERIOD: Duration = Duration::from_secs(60);
+
+/// The chip identifiers of a device by chip kind and name.
+pub type ChipIdentifiers = HashMap<(ProtoChipKind, String), ChipIdentifier>;
+
+/// A device that departed, with the snapshot of its state.
+pub struct DepartedDevice {
+    pub id: DeviceIdentifier,
+    pub guid: String,
+    pub snapshot: ProtoDevice,
+    pub chip_ids: ChipIdentifiers,
+    departed_at: Instant,
+}
+
+/// The departed devices that are still within the grace period.
+pub struct Departed {
+    devices: Vec<DepartedDevice>,
+    grace_period: Duration,
+    pub same_ids: bool,
+}
+
+impl Departed {
+    pub fn new() -> Self {
+        Departed { devices: Vec::new(), grace_period: DEFAULT_GRACE_PERIOD, same_ids: false }
+    }
+
+    /// Set the grace period, zero forgets departed devices immediately.
+    pub fn set_grace_period(&mut self, grace_period: Duration) {
+        self.grace_period = grace_period;
+        if grace_period.is_zero() {
+            self.devices.clear();
+        }
+    }
+
+    /// Remember a device that departed at `now`, replacing an earlier
+    /// departure with the same guid.
+    pub fn insert(
+        &mut self,
+        id: DeviceIdentifier,
+        guid: &str,
+        snapshot: ProtoDevice,
+        now: Instant,
+    ) {
+        if self.grace_period.is_zero() {
+            return;
+        }
+        self.expire(now);
+        self.devices.retain(|device| device.guid != guid);
+        let chip_ids = snapshot
+            .chips
+            .iter()
+            .map(|chip| ((chip.kind.enum_value_or_default(), chip.name.clone()), chip.id))
+            .collect();
+        self.devices.push(DepartedDevice {
+            id,
+            guid: guid.to_string(),
+            snapshot,
+            chip_ids,
+            departed_at: now,
+        });
+    }
+
+    /// Take the most recent departed device with the guid or, failing
+    /// that, the name.
+    pub fn take(&mut self, guid: &str, name: &str, now: Instant) -> Option<DepartedDevice> {
+        self.expire(now);
+        let position = self
+            .devices
+            .iter()
+            .rposition(|device| device.guid == guid)
+            .or_else(|| self.devices.iter().rposition(|device| device.snapshot.name == name))?;
+        Some(self.devices.remove(position))
+    }
+
+    fn expire(&mut self, now: Instant) {
+        let grace_period = self.grace_period;
+        self.devices
+            .retain(|device| now.saturating_duration_since(device.departed_at) < grace_period);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frontend_proto::model::Chip as ProtoChip;
+
+    fn snapshot(name: &str) -> ProtoDevice {
+        let chip = ProtoChip {
+            id: 2001,
+            kind: ProtoChipKind::BLUETOOTH.into(),
+            name: "bt".to_string(),
+            ..Default::default()
+        };
+        ProtoDevice { name: name.to_string(), chips: vec![chip], ..Default::default() }
+    }
+
+    #[test]
+    fn test_take_by_guid_or_name() {
+        let mut departed = Departed::new();
+        let now = Instant::now();
+        departed.insert(1001, "guid-1", snapshot("phone"), now);
+        departed.insert(1002, "guid-2", snapshot("watch"), now);
+        let device = departed.take("guid-1", "other-name", now).unwrap();
+        assert_eq!(device.id, 1001);
+        assert_eq!(device.chip_ids[&(ProtoChipKind::BLUETOOTH, "bt".to_string())], 2001);
+        // A device that reconnects from another guid is matched by name.
+        assert_eq!(departed.take("guid-3", "watch", now).unwrap().id, 1002);
+        assert!(departed.take("guid-1", "phone", now).is_none());
+    }
+
+    #[test]
+    fn test_grace_period() {
+        let mut departed = Departed::new();
+        let now = Instant::now();
+        departed.set_grace_period(Duration::from_secs(10));
+        departed.insert(1001, "guid-1", snapshot("phone"), now);
+        assert!(departed.take("guid-1", "phone", now + Duration::from_secs(10)).is_none());
+        departed.insert(1001, "guid-1", snapshot("phone"), now);
+        assert!(departed.take("guid-1", "phone", now + Duration::from_secs(9)).is_some());
+        departed.set_grace_period(Duration::ZERO);
+        departed.insert(1001, "guid-1", snapshot("phone"), now);
+        assert!(departed.take("guid-1", "phone", now).is_none());
+    }
+}
@@ -0,0 +1,279 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Traffic statistics of the chips.
+//!
+//! The statistics of a chip are kept by chip id from its creation to its
+//! removal. The packet path records every packet by chip kind and facade
+//! id, the key the packet hub knows a chip by, and takes only a read lock
+//! on the chips and the lock of the one chip. Each direction keeps
+//! lifetime packet and byte counters and one bucket per second for the
+//! last minute, from which the rates over the 1s, 10s and 60s windows
+//! are computed. The rates include the current, partial, second.
+
+use crate::devices::chip::ChipIdentifier;
+use crate::devices::facades::FacadeIdentifier;
+use frontend_proto::common::ChipKind as ProtoChipKind;
+use frontend_proto::model::traffic_stats::Direction as ProtoDirection;
+use frontend_proto::model::traffic_stats::Rate as ProtoRate;
+use frontend_proto::model::TrafficStats as ProtoTrafficStats;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+use std::time::Instant;
+
+const WINDOWS_SECS: [u64; 3] = [1, 10, 60];
+const BUCKETS: usize = 60;
+
+lazy_static! {
+    static ref TRAFFIC: RwLock<Traffic> = RwLock::new(Traffic::default());
+    // Buckets are numbered by the seconds since the start.
+    static ref START: Instant = Instant::now();
+}
+
+type FacadeKey = (ProtoChipKind, FacadeIdentifier);
+
+/// The traffic of the chips by chip id and by facade key.
+#[derive(Default)]
+struct Traffic {
+    chips: HashMap<ChipIdentifier, Arc<Mutex<ChipTraffic>>>,
+    facades: HashMap<FacadeKey, Arc<Mutex<ChipTraffic>>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+    /// From the host to the controller.
+    Tx,
+    /// From the controller to the host.
+    Rx,
+}
+
+#[derive(Clone, Copy, Default)]
+struct Bucket {
+    second: u64,
+    packets: u64,
+    bytes: u64,
+}
+
+#[derive(Clone)]
+struct DirectionTraffic {
+    packets: u64,
+    bytes: u64,
+    buckets: [Bucket; BUCKETS],
+}
+
+impl Default for DirectionTraffic {
+    fn default() -> Self {
+        DirectionTraffic { packets: 0, bytes: 0, buckets: [Bucket::default(); BUCKETS] }
+    }
+}
+
+impl DirectionTraffic {
+    fn record(&mut self, second: u64, bytes: usize) {
+        self.packets += 1;
+        self.bytes += bytes as u64;
+        let bucket = &mut self.buckets[second as usize % BUCKETS];
+        if bucket.second != second {
+            *bucket = Bucket { second, ..Default::default() };
+        }
+        bucket.packets += 1;
+        bucket.bytes += bytes as u64;
+    }
+
+    /// The packets and bytes in the window of seconds ending at `second`.
+    fn window(&self, second: u64, window_secs: u64) -> (u64, u64) {
+        self.buckets
+            .iter()
+            .filter(|bucket| bucket.second <= second && bucket.second + window_secs > second)
+            .fold((0, 0), |(packets, bytes), bucket| {
+                (packets + bucket.packets, bytes + bucket.bytes)
+            })
+    }
+}
+
+#[derive(Clone)]
+struct ChipTraffic {
+    facade_key: FacadeKey,
+    tx: DirectionTraffic,
+    rx: DirectionTraffic,
+}
+
+impl ChipTraffic {
+    fn new(facade_key: FacadeKey) -> Self {
+        ChipTraffic { facade_key, tx: Default::default(), rx: Default::default() }
+    }
+}
+
+fn second_of(now: Instant) -> u64 {
+    now.saturating_duration_since(*START).as_secs()
+}
+
+fn to_proto(traffic: &[&ChipTraffic], now: Instant) -> ProtoTrafficStats {
+    let second = second_of(now);
+    let direction = |get: fn(&ChipTraffic) -> &DirectionTraffic| {
+        let mut proto = ProtoDirection::new();
+        for chip in traffic {
+            proto.packets += get(chip).packets as i64;
+            proto.bytes += get(chip).bytes as i64;
+        }
+        for window_secs in WINDOWS_SECS {
+            let (packets, bytes) = traffic.iter().fold((0, 0), |(packets, bytes), chip| {
+                let (chip_packets, chip_bytes) = get(chip).window(second, window_secs);
+                (packets + chip_packets, bytes + chip_bytes)
+            });
+            proto.rates.push(ProtoRate {
+                window_secs: window_secs as i32,
+                packets_per_sec: packets as f32 / window_secs as f32,
+                bytes_per_sec: bytes as f32 / window_secs as f32,
+                ..Default::default()
+            });
+        }
+        proto
+    };
+    let mut proto = ProtoTrafficStats::new();
+    proto.tx = Some(direction(|chip| &chip.tx)).into();
+    proto.rx = Some(direction(|chip| &chip.rx)).into();
+    proto
+}
+
+/// Start counting the traffic of a chip, when it is added.
+pub fn add(chip_id: ChipIdentifier, kind: ProtoChipKind, facade_id: FacadeIdentifier) {
+    let chip = Arc::new(Mutex::new(ChipTraffic::new((kind, facade_id))));
+    let mut traffic = TRAFFIC.write().unwrap();
+    traffic.facades.insert((kind, facade_id), Arc::clone(&chip));
+    if let Some(previous) = traffic.chips.insert(chip_id, chip) {
+        let previous_key = previous.lock().unwrap().facade_key;
+        if previous_key != (kind, facade_id) {
+            traffic.facades.remove(&previous_key);
+        }
+    }
+}
+
+/// Forget the traffic of a chip, when it is removed.
+pub fn remove(chip_id: ChipIdentifier) {
+    let mut traffic = TRAFFIC.write().unwrap();
+    if let Some(chip) = traffic.chips.remove(&chip_id) {
+        let facade_key = chip.lock().unwrap().facade_key;
+        traffic.facades.remove(&facade_key);
+    }
+}
+
+/// Clear the traffic of a chip, when it is reset.
+pub fn reset(chip_id: ChipIdentifier) {
+    if let Some(chip) = TRAFFIC.read().unwrap().chips.get(&chip_id) {
+        let mut chip = chip.lock().unwrap();
+        *chip = ChipTraffic::new(chip.facade_key);
+    }
+}
+
+/// Record a packet of the chip with the facade at `now`. The packets of
+/// unknown chips are not counted.
+pub fn record(
+    kind: ProtoChipKind,
+    facade_id: FacadeIdentifier,
+    direction: Direction,
+    bytes: usize,
+    now: Instant,
+) {
+    let chip = match TRAFFIC.read().unwrap().facades.get(&(kind, facade_id)) {
+        Some(chip) => Arc::clone(chip),
+        None => return,
+    };
+    let mut chip = chip.lock().unwrap();
+    match direction {
+        Direction::Tx => chip.tx.record(second_of(now), bytes),
+        Direction::Rx => chip.rx.record(second_of(now), bytes),
+    }
+}
+
+/// The traffic of a chip at `now`.
+pub fn get(chip_id: ChipIdentifier, now: Instant) -> ProtoTrafficStats {
+    match TRAFFIC.read().unwrap().chips.get(&chip_id) {
+        Some(chip) => to_proto(&[&chip.lock().unwrap()], now),
+        None => to_proto(&[], now),
+    }
+}
+
+/// The traffic of all the chips of each kind at `now`, by kind.
+pub fn get_radios(now: Instant) -> Vec<(ProtoChipKind, ProtoTrafficStats)> {
+    let traffic = TRAFFIC.read().unwrap();
+    let mut by_kind: HashMap<ProtoChipKind, Vec<MutexGuard<ChipTraffic>>> = HashMap::new();
+    for chip in traffic.chips.values() {
+        let chip = chip.lock().unwrap();
+        by_kind.entry(chip.facade_key.0).or_default().push(chip);
+    }
+    let mut radios: Vec<_> = by_kind
+        .into_iter()
+        .map(|(kind, chips)| {
+            let chips: Vec<&ChipTraffic> = chips.iter().map(|chip| &**chip).collect();
+            (kind, to_proto(&chips, now))
+        })
+        .collect();
+    radios.sort_by_key(|(kind, _)| *kind as i32);
+    radios
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn rates(direction: &ProtoDirection) -> Vec<f32> {
+        direction.rates.iter().map(|rate| rate.packets_per_sec).collect()
+    }
+
+    #[test]
+    fn test_windows() {
+        let mut traffic = DirectionTraffic::default();
+        traffic.record(100, 10);
+        traffic.record(100, 10);
+        traffic.record(95, 30);
+        traffic.record(30, 50);
+        assert_eq!(traffic.window(100, 1), (2, 20));
+        assert_eq!(traffic.window(100, 10), (3, 50));
+        assert_eq!(traffic.window(100, 60), (3, 50));
+        assert_eq!(traffic.window(101, 1), (0, 0));
+        // A bucket is reused a minute later.
+        traffic.record(160, 1);
+        assert_eq!(traffic.window(160, 60), (1, 1));
+        assert_eq!((traffic.packets, traffic.bytes), (5, 101));
+    }
+
+    #[test]
+    fn test_chip_and_radio_traffic() {
+        let kind = ProtoChipKind::UWB;
+        let now = *START + Duration::from_secs(1000);
+        add(9001, kind, 9101);
+        add(9002, kind, 9102);
+        for _ in 0..20 {
+            record(kind, 9101, Direction::Tx, 100, now);
+        }
+        record(kind, 9101, Direction::Rx, 4, now - Duration::from_secs(5));
+        record(kind, 9102, Direction::Tx, 10, now);
+        let stats = get(9001, now);
+        assert_eq!((stats.tx.packets, stats.tx.bytes), (20, 2000));
+        assert_eq!(rates(&stats.tx), vec![20.0, 2.0, 20.0 / 60.0]);
+        assert_eq!(rates(&stats.rx), vec![0.0, 0.1, 1.0 / 60.0]);
+        let (_, radio) = get_radios(now).into_iter().find(|(k, _)| *k == kind).unwrap();
+        assert!(radio.tx.packets >= 21);
+        reset(9001);
+        assert_eq!(get(9001, now).tx.packets, 0);
+        remove(9001);
+        remove(9002);
+        // The packets of removed chips are not counted.
+        record(kind, 9101, Direction::Tx, 100, now);
+        assert_eq!(get(9001, now).tx.packets, 0);
+        assert!(!TRAFFIC.read().unwrap().facades.contains_key(&(kind, 9101)));
+    }
+}
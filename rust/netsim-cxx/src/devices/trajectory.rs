@@ -0,0 +1,192 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scripted device trajectories.
+//!
+//! A trajectory is a list of waypoints with arrival times. The device pose
+//! at any time since the start of the trajectory is interpolated linearly
+//! between the surrounding waypoints. A looping trajectory jumps back to
+//! the first waypoint after the last one, so a round trip lists the first
+//! waypoint again at the end.
+
+use frontend_proto::model::Orientation as ProtoOrientation;
+use frontend_proto::model::Position as ProtoPosition;
+use frontend_proto::model::Trajectory as ProtoTrajectory;
+use std::time::Duration;
+
+struct Waypoint {
+    // Seconds since the start of the trajectory.
+    time: f32,
+    position: ProtoPosition,
+    orientation: Option<ProtoOrientation>,
+}
+
+pub struct Trajectory {
+    waypoints: Vec<Waypoint>,
+    looping: bool,
+}
+
+/// The pose of a device on its trajectory.
+pub struct Pose {
+    pub position: ProtoPosition,
+    /// None when the trajectory leaves the orientation alone.
+    pub orientation: Option<ProtoOrientation>,
+    /// True once a trajectory that does not loop reaches its last waypoint.
+    pub is_finished: bool,
+}
+
+fn distance(a: &ProtoPosition, b: &ProtoPosition) -> f32 {
+    ((b.x - a.x).powf(2.0) + (b.y - a.y).powf(2.0) + (b.z - a.z).powf(2.0)).sqrt()
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+impl Trajectory {
+    /// Build a trajectory from the proto, computing the arrival time of
+    /// the waypoints given by speed.
+    ///
+    /// Returns None for a trajectory without waypoints.
+    pub fn from_proto(proto: &ProtoTrajectory) -> Result<Option<Self>, String> {
+        let mut waypoints: Vec<Waypoint> = Vec::new();
+        for (i, proto_waypoint) in proto.waypoints.iter().enumerate() {
+            let position = proto_waypoint.position.clone().unwrap_or_default();
+            let time = match waypoints.last() {
+                None => 0.0,
+                Some(_) if proto_waypoint.time > 0.0 => proto_waypoint.time,
+                Some(previous) if proto_waypoint.speed > 0.0 => {
+                    previous.time + distance(&previous.position, &position) / proto_waypoint.speed
+                }
+                Some(_) => return Err(format!("Waypoint {i} requires a time or a speed")),
+            };
+            if let Some(previous) = waypoints.last() {
+                if time < previous.time {
+                    return Err(format!("Waypoint {i} is reached before waypoint {}", i - 1));
+                }
+            }
+            waypoints.push(Waypoint {
+                time,
+                position,
+                orientation: proto_waypoint.orientation.clone().into_option(),
+            });
+        }
+        if waypoints.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Trajectory { waypoints, looping: proto.loop_ }))
+    }
+
+    fn duration(&self) -> f32 {
+        self.waypoints.last().map_or(0.0, |waypoint| waypoint.time)
+    }
+
+    /// The pose at the elapsed time since the start of the trajectory.
+    pub fn pose_at(&self, elapsed: Duration) -> Pose {
+        let duration = self.duration();
+        let mut time = elapsed.as_secs_f32();
+        let mut is_finished = false;
+        if time >= duration {
+            if self.looping && duration > 0.0 {
+                time %= duration;
+            } else {
+                time = duration;
+                is_finished = true;
+            }
+        }
+        // The segment ending at the first waypoint not reached yet, the
+        // first waypoint is always reached at time 0.
+        let (from, to) = match self.waypoints.iter().position(|waypoint| waypoint.time > time) {
+            Some(i) => (&self.waypoints[i - 1], &self.waypoints[i]),
+            None => {
+                let last = self.waypoints.last().unwrap();
+                return Pose {
+                    position: last.position.clone(),
+                    orientation: last.orientation.clone(),
+                    is_finished,
+                };
+            }
+        };
+        let t = (time - from.time) / (to.time - from.time);
+        let position = ProtoPosition {
+            x: lerp(from.position.x, to.position.x, t),
+            y: lerp(from.position.y, to.position.y, t),
+            z: lerp(from.position.z, to.position.z, t),
+            ..Default::default()
+        };
+        let orientation = match (&from.orientation, &to.orientation) {
+            (Some(a), Some(b)) => Some(ProtoOrientation {
+                yaw: lerp(a.yaw, b.yaw, t),
+                pitch: lerp(a.pitch, b.pitch, t),
+                roll: lerp(a.roll, b.roll, t),
+                ..Default::default()
+            }),
+            (orientation, None) | (None, orientation) => orientation.clone(),
+        };
+        Pose { position, orientation, is_finished }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protobuf_json_mapping::parse_from_str;
+
+    fn trajectory(json: &str) -> Result<Option<Trajectory>, String> {
+        Trajectory::from_proto(&parse_from_str::<ProtoTrajectory>(json).unwrap())
+    }
+
+    #[test]
+    fn test_speed_and_time() {
+        let trajectory = trajectory(
+            r#"{"waypoints": [{"position": {"x": 0.0}},
+                              {"position": {"x": 10.0}, "speed": 2.0},
+                              {"position": {"x": 10.0, "y": 4.0}, "time": 9.0}]}"#,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(trajectory.duration(), 9.0);
+        let pose = trajectory.pose_at(Duration::from_secs(1));
+        assert_eq!(pose.position.x, 2.0);
+        assert!(!pose.is_finished);
+        let pose = trajectory.pose_at(Duration::from_secs(7));
+        assert_eq!((pose.position.x, pose.position.y), (10.0, 2.0));
+        let pose = trajectory.pose_at(Duration::from_secs(20));
+        assert_eq!((pose.position.x, pose.position.y), (10.0, 4.0));
+        assert!(pose.is_finished);
+    }
+
+    #[test]
+    fn test_loop_and_orientation() {
+        let trajectory = trajectory(
+            r#"{"loop": true,
+                "waypoints": [{"position": {"x": 0.0}, "orientation": {"yaw": 0.0}},
+                              {"position": {"x": 4.0}, "orientation": {"yaw": 90.0},
+                               "time": 4.0}]}"#,
+        )
+        .unwrap()
+        .unwrap();
+        let pose = trajectory.pose_at(Duration::from_secs(5));
+        assert_eq!(pose.position.x, 1.0);
+        assert_eq!(pose.orientation.unwrap().yaw, 22.5);
+        assert!(!pose.is_finished);
+    }
+
+    #[test]
+    fn test_invalid_trajectory() {
+        assert!(trajectory(r#"{"waypoints": []}"#).unwrap().is_none());
+        assert!(trajectory(r#"{"waypoints": [{}, {"position": {"x": 1.0}}]}"#).is_err());
+        assert!(trajectory(r#"{"waypoints": [{}, {"time": 5.0}, {"time": 2.0}]}"#).is_err());
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Zones partition the scene into radio domains.
+//!
+//! Every device is in exactly one zone, the default zone unless it is
+//! assigned to another one. Radios reach the devices of their own zone
+//! as usual. A signal crossing zones is attenuated by the attenuation of
+//! both zones, and does not cross at all when either zone is isolated.
+
+use frontend_proto::model::Zone as ProtoZone;
+use std::collections::HashMap;
+
+/// The zone of the devices that are not assigned to a zone. It always
+/// exists and can be patched but not deleted.
+pub const DEFAULT_ZONE: &str = "default";
+
+/// The zone table, by zone name.
+pub struct Zones {
+    zones: HashMap<String, ProtoZone>,
+}
+
+impl Zones {
+    pub fn new() -> Self {
+        let mut zones = HashMap::new();
+        let default_zone = ProtoZone { name: DEFAULT_ZONE.to_string(), ..Default::default() };
+        zones.insert(DEFAULT_ZONE.to_string(), default_zone);
+        Zones { zones }
+    }
+
+    /// Create a zone, or replace the zone with the same name.
+    pub fn patch(&mut self, zone: &ProtoZone) -> Result<(), String> {
+        if zone.name.is_empty() {
+            return Err("Zone requires a name".to_string());
+        }
+        if !zone.attenuation.is_finite() || zone.attenuation < 0.0 {
+            return Err(format!("Zone attenuation {} dB is negative", zone.attenuation));
+        }
+        self.zones.insert(zone.name.clone(), zone.clone());
+        Ok(())
+    }
+
+    /// Delete a zone. The caller moves its devices to the default zone.
+    pub fn delete(&mut self, name: &str) -> Result<(), String> {
+        if name == DEFAULT_ZONE {
+            return Err("The default zone cannot be deleted".to_string());
+        }
+        self.zones.remove(name).map(|_| ()).ok_or(format!("No such zone {name}"))
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.zones.contains_key(name)
+    }
+
+    /// The zones sorted by name.
+    pub fn list(&self) -> Vec<ProtoZone> {
+        let mut zones: Vec<ProtoZone> = self.zones.values().cloned().collect();
+        zones.sort_by(|a, b| a.name.cmp(&b.name));
+        zones
+    }
+
+    /// The attenuation in dB of a signal from one zone to another, or
+    /// None when the signal does not reach the other zone.
+    pub fn attenuation(&self, zone: &str, other_zone: &str) -> Option<f32> {
+        if zone == other_zone {
+            return Some(0.0);
+        }
+        let (a, b) = match (self.zones.get(zone), self.zones.get(other_zone)) {
+            (Some(a), Some(b)) => (a, b),
+            // Zones of removed devices do not attenuate.
+            _ => return Some(0.0),
+        };
+        if a.isolated || b.isolated {
+            return None;
+        }
+        Some(a.attenuation + b.attenuation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zone(name: &str, attenuation: f32, isolated: bool) -> ProtoZone {
+        ProtoZone { name: name.to_string(), attenuation, isolated, ..Default::default() }
+    }
+
+    #[test]
+    fn test_attenuation() {
+        let mut zones = Zones::new();
+        zones.patch(&zone("lab-a", 20.0, false)).unwrap();
+        zones.patch(&zone("lab-b", 10.0, false)).unwrap();
+        zones.patch(&zone("cage", 0.0, true)).unwrap();
+        assert_eq!(zones.attenuation("lab-a", "lab-a"), Some(0.0));
+        assert_eq!(zones.attenuation("lab-a", "lab-b"), Some(30.0));
+        assert_eq!(zones.attenuation(DEFAULT_ZONE, "lab-b"), Some(10.0));
+        assert_eq!(zones.attenuation("cage", "cage"), Some(0.0));
+        assert_eq!(zones.attenuation("cage", DEFAULT_ZONE), None);
+        assert_eq!(zones.attenuation("lab-a", "cage"), None);
+    }
+
+    #[test]
+    fn test_patch_and_delete() {
+        let mut zones = Zones::new();
+        assert!(zones.patch(&zone("", 0.0, false)).is_err());
+        assert!(zones.patch(&zone("lab-a", -1.0, false)).is_err());
+        assert!(zones.patch(&zone("lab-a", f32::NAN, false)).is_err());
+        zones.patch(&zone("lab-a", 0.0, false)).unwrap();
+        zones.patch(&zone(DEFAULT_ZONE, 5.0, false)).unwrap();
+        zones.patch(&zone("lab-a", 5.0, false)).unwrap();
+        let names: Vec<String> = zones.list().into_iter().map(|zone| zone.name).collect();
+        assert_eq!(names, vec![DEFAULT_ZONE, "lab-a"]);
+        assert!(zones.delete(DEFAULT_ZONE).is_err());
+        zones.delete("lab-a").unwrap();
+        assert!(!zones.contains("lab-a"));
+        assert!(zones.delete("lab-a").is_err());
+    }
+}
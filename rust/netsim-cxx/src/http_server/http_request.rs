@@ -107,6 +107,44 @@ impl HttpRequest {
         }
         Ok(HttpRequest { method, uri, version, headers, body })
     }
+
+    /// The uri without the query string.
+    pub fn path(&self) -> &str {
+        self.uri.split_once('?').map_or(&self.uri, |(path, _)| path)
+    }
+
+    /// The percent-decoded value of the first query parameter with the key.
+    pub fn query_param(&self, key: &str) -> Option<String> {
+        let (_, query) = self.uri.split_once('?')?;
+        query.split('&').find_map(|param| {
+            let (param_key, value) = param.split_once('=').unwrap_or((param, ""));
+            (percent_decode(param_key) == key).then(|| percent_decode(value))
+        })
+    }
+}
+
+// Decode the %XX escapes and the '+' for a space of a query component.
+// Invalid escapes are kept as they are.
+fn percent_decode(component: &str) -> String {
+    let bytes = component.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len()
+                && bytes[i + 1].is_ascii_hexdigit()
+                && bytes[i + 2].is_ascii_hexdigit() =>
+            {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap();
+                decoded.push(u8::from_str_radix(hex, 16).unwrap());
+                i += 2;
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
 }
 
 // Parse the request line of an HTTP request, which contains the method, URI, and version
@@ -199,4 +237,21 @@ mod tests {
         assert!(http_request.headers.eq(&[("Host", "example.com")]));
         assert_eq!(http_request.body, b"");
     }
+
+    #[test]
+    fn test_query_param() {
+        let request = concat!(
+            "GET /v1/devices?label_selector=team%3Dwear%2Crole&name_pattern=emu+*&flag HTTP/1.1\r\n",
+            "Host: example.com\r\n\r\n"
+        );
+        let mut reader = BufReader::new(request.as_bytes());
+        let http_request = HttpRequest::parse::<&[u8]>(&mut reader).unwrap();
+        assert_eq!(http_request.path(), "/v1/devices");
+        assert_eq!(http_request.query_param("label_selector").unwrap(), "team=wear,role");
+        assert_eq!(http_request.query_param("name_pattern").unwrap(), "emu *");
+        assert_eq!(http_request.query_param("flag").unwrap(), "");
+        assert_eq!(http_request.query_param("chip_kind"), None);
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz%+1%41"), "%zz% 1A");
+    }
 }
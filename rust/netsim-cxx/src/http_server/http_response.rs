@@ -42,6 +42,18 @@ impl HttpResponse {
         }
     }
 
+    // A response without length, its body ends when the connection closes.
+    pub fn new_ok_streaming(content_type: &str) -> HttpResponse {
+        HttpResponse {
+            status_code: 200,
+            headers: HttpHeaders::new_with_headers(&[
+                ("Content-Type", content_type),
+                ("Connection", "close"),
+            ]),
+            body: Vec::new(),
+        }
+    }
+
     pub fn new_ok(content_type: &str, body: Vec<u8>) -> HttpResponse {
         HttpResponse {
             status_code: 200,
@@ -45,7 +45,7 @@ impl Router {
 
     pub fn handle_request(&self, request: &HttpRequest, writer: ResponseWritable) {
         for (route, handler) in &self.routes {
-            if let Some(param) = match_route(route, &request.uri) {
+            if let Some(param) = match_route(route, request.path()) {
                 handler(request, param, writer);
                 return;
             }
@@ -155,6 +155,18 @@ mod tests {
         let expected_bytes =
             b"HTTP/1.1 200\r\nContent-Type: application/json\r\nContent-Length: 12\r\n\r\nHello, 1920!";
         assert_eq!(written_bytes, expected_bytes);
+        // The query string is not part of the route parameter.
+        let request = HttpRequest {
+            method: "GET".to_string(),
+            uri: "/user/1920?verbose=1".to_string(),
+            version: "HTTP/1.1".to_string(),
+            headers: HttpHeaders::new(),
+            body: vec![],
+        };
+        let mut stream = Cursor::new(Vec::new());
+        let mut writer = ServerResponseWriter::new(&mut stream);
+        router.handle_request(&request, &mut writer);
+        assert_eq!(stream.get_ref(), expected_bytes);
     }
 
     #[test]
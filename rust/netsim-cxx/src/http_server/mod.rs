@@ -19,6 +19,10 @@ pub(crate) mod server_response;
 mod thread_pool;
 
 use crate::captures::handlers::*;
+use crate::devices::devices_handler::{
+    handle_devices, handle_distances, handle_link_loss, handle_scene, handle_trajectory,
+    handle_zones,
+};
 use crate::http_server::http_request::HttpRequest;
 use crate::http_server::http_router::Router;
 use crate::http_server::server_response::{
@@ -27,10 +31,8 @@ use crate::http_server::server_response::{
 use crate::version::VERSION;
 
 use crate::http_server::thread_pool::ThreadPool;
+use crate::lifecycle;
 
-use crate::ffi::get_devices;
-use crate::ffi::patch_device;
-use cxx::let_cxx_string;
 use std::collections::HashSet;
 use std::ffi::OsStr;
 use std::fs;
@@ -39,22 +41,32 @@ use std::net::TcpListener;
 use std::net::TcpStream;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::thread;
 
 const PATH_PREFIXES: [&str; 3] = ["js", "assets", "node_modules/tslib"];
+const HTTP_SERVER_ADDRESS: &str = "127.0.0.1:7681";
+
+// Set by the lifecycle manager to stop accepting connections.
+static SHUTDOWN: AtomicBool = AtomicBool::new(false);
 
 pub fn run_http_server() {
-    let listener = match TcpListener::bind("127.0.0.1:7681") {
+    let listener = match TcpListener::bind(HTTP_SERVER_ADDRESS) {
         Ok(listener) => listener,
         Err(e) => {
             eprintln!("netsimd: bind error in netsimd frontend http server. {}", e);
             return;
         }
     };
+    lifecycle::add_shutdown_callback(Box::new(shutdown_http_server));
     let pool = ThreadPool::new(4);
     println!("netsimd: Frontend http server is listening on http://localhost:7681");
     let valid_files = Arc::new(create_filename_hash_set());
     for stream in listener.incoming() {
+        if SHUTDOWN.load(Ordering::SeqCst) {
+            break;
+        }
         let stream = stream.unwrap();
         let valid_files = valid_files.clone();
         pool.execute(move || {
@@ -62,9 +74,19 @@ pub fn run_http_server() {
         });
     }
 
+    // Dropping the pool waits for the in-flight requests to complete.
     println!("netsimd: Shutting down frontend http server.");
 }
 
+/// Stop the http server. The blocked listener is woken up with a
+/// connection of its own and exits before handling it.
+pub fn shutdown_http_server() {
+    SHUTDOWN.store(true, Ordering::SeqCst);
+    if let Err(e) = TcpStream::connect(HTTP_SERVER_ADDRESS) {
+        eprintln!("netsimd: unable to wake frontend http server for shutdown. {}", e);
+    }
+}
+
 fn ui_path(suffix: &str) -> PathBuf {
     let mut path = std::env::current_exe().unwrap();
     path.pop();
@@ -155,43 +177,19 @@ fn handle_version(_request: &HttpRequest, _param: &str, writer: ResponseWritable
     writer.put_ok("text/plain", body.as_str(), &[]);
 }
 
-fn handle_devices(request: &HttpRequest, _param: &str, writer: ResponseWritable) {
-    if &request.method == "GET" {
-        let_cxx_string!(request = "");
-        let_cxx_string!(response = "");
-        let_cxx_string!(error_message = "");
-        let status = get_devices(&request, response.as_mut(), error_message.as_mut());
-        if status == 200 {
-            writer.put_ok("text/plain", response.to_string().as_str(), &[]);
-        } else {
-            let body = format!("404 Not found (netsim): {:?}", error_message.to_string());
-            writer.put_error(404, body.as_str());
-        }
-    } else if &request.method == "PATCH" {
-        let_cxx_string!(new_request = &request.body);
-        let_cxx_string!(response = "");
-        let_cxx_string!(error_message = "");
-        let status = patch_device(&new_request, response.as_mut(), error_message.as_mut());
-        if status == 200 {
-            writer.put_ok("text/plain", response.to_string().as_str(), &[]);
-        } else {
-            let body = format!("404 Not found (netsim): {:?}", error_message.to_string());
-            writer.put_error(404, body.as_str());
-        }
-    } else {
-        let body = format!(
-            "404 Not found (netsim): {:?} is not a valid method for this route",
-            request.method.to_string()
-        );
-        writer.put_error(404, body.as_str());
-    }
-}
-
-fn handle_connection(mut stream: TcpStream, valid_files: Arc<HashSet<String>>) {
+fn new_router(valid_files: Arc<HashSet<String>>) -> Router {
     let mut router = Router::new();
     router.add_route("/", Box::new(handle_index));
     router.add_route("/version", Box::new(handle_version));
     router.add_route("/v1/devices", Box::new(handle_devices));
+    router.add_route(r"/v1/devices/{id}", Box::new(handle_devices));
+    router.add_route("/v1/scene", Box::new(handle_scene));
+    router.add_route(r"/v1/scene/{action}", Box::new(handle_scene));
+    router.add_route("/v1/link_loss", Box::new(handle_link_loss));
+    router.add_route("/v1/trajectory", Box::new(handle_trajectory));
+    router.add_route("/v1/zones", Box::new(handle_zones));
+    router.add_route(r"/v1/zones/{name}", Box::new(handle_zones));
+    router.add_route("/v1/distances", Box::new(handle_distances));
     router.add_route(r"/pcap/{id}", Box::new(handle_pcap_file));
     router.add_route(r"/v1/captures", Box::new(handle_capture));
     router.add_route(r"/v1/captures/{id}", Box::new(handle_capture));
@@ -217,10 +215,27 @@ fn handle_connection(mut stream: TcpStream, valid_files: Arc<HashSet<String>>) {
             Box::new(handle_static_wrapper.clone()),
         )
     }
+    router
+}
 
+fn handle_connection(mut stream: TcpStream, valid_files: Arc<HashSet<String>>) {
     if let Ok(request) = HttpRequest::parse::<&TcpStream>(&mut BufReader::new(&stream)) {
-        let mut response_writer = ServerResponseWriter::new(&mut stream);
-        router.handle_request(&request, &mut response_writer);
+        let is_follow = is_follow_request(&request);
+        let respond = move || {
+            let peer = stream.try_clone().ok();
+            let mut response_writer = ServerResponseWriter::new(&mut stream).with_peer(peer);
+            new_router(valid_files).handle_request(&request, &mut response_writer);
+        };
+        // A followed capture streams until the client leaves, it gets a
+        // thread of its own so that it does not hold up the pool.
+        if is_follow {
+            if let Err(e) = thread::Builder::new().name("capture_follow".to_string()).spawn(respond)
+            {
+                eprintln!("netsimd: unable to spawn capture follow thread. {}", e);
+            }
+        } else {
+            respond();
+        }
     } else {
         let mut response_writer = ServerResponseWriter::new(&mut stream);
         let body = "404 not found (netsim): parse header failed";
@@ -22,7 +22,8 @@
 //!
 //! This library is intended solely for serving netsim clients.
 
-use std::io::Write;
+use std::io::{ErrorKind, Write};
+use std::net::TcpStream;
 
 use crate::http_server::http_response::HttpResponse;
 
@@ -38,16 +39,29 @@ pub trait ServerResponseWritable {
     fn put_ok(&mut self, mime_type: &str, body: &str, headers: StrHeaders);
     fn put_error(&mut self, error_code: u16, error_message: &str);
     fn put_ok_with_vec(&mut self, mime_type: &str, body: Vec<u8>, headers: StrHeaders);
+    // Starts a response of unknown length, followed by chunks until the
+    // client goes away.
+    fn put_ok_streaming(&mut self, mime_type: &str, headers: StrHeaders);
+    // Whether the client has gone away, known once a chunk fails or the
+    // peer has shut down the connection.
+    fn is_closed(&self) -> bool;
 }
 
 // A response writer that can contain a TCP stream or other writable.
 pub struct ServerResponseWriter<'a> {
     writer: &'a mut dyn Write,
+    closed: bool,
+    // The connection of the client, checked for a shutdown while idle.
+    peer: Option<TcpStream>,
 }
 
 impl<'a> ServerResponseWriter<'a> {
     pub fn new<W: Write>(writer: &mut W) -> ServerResponseWriter {
-        ServerResponseWriter { writer }
+        ServerResponseWriter { writer, closed: false, peer: None }
+    }
+    pub fn with_peer(mut self, peer: Option<TcpStream>) -> Self {
+        self.peer = peer;
+        self
     }
     pub fn put_response(&mut self, response: HttpResponse) {
         let mut buffer = format!("HTTP/1.1 {}\r\n", response.status_code).into_bytes();
@@ -71,10 +85,10 @@ impl ServerResponseWritable for ServerResponseWriter<'_> {
         self.put_response(response);
     }
     fn put_chunk(&mut self, chunk: &[u8]) {
-        if let Err(e) = self.writer.write_all(chunk) {
+        if let Err(e) = self.writer.write_all(chunk).and_then(|_| self.writer.flush()) {
             println!("netsim: handle_connection error {e}");
+            self.closed = true;
         };
-        self.writer.flush().unwrap();
     }
     fn put_ok_with_length(&mut self, mime_type: &str, length: usize, headers: StrHeaders) {
         let mut response = HttpResponse::new_ok_with_length(mime_type, length);
@@ -91,6 +105,27 @@ impl ServerResponseWritable for ServerResponseWriter<'_> {
         response.add_headers(headers);
         self.put_response(response);
     }
+    fn put_ok_streaming(&mut self, mime_type: &str, headers: StrHeaders) {
+        let mut response = HttpResponse::new_ok_streaming(mime_type);
+        response.add_headers(headers);
+        self.put_response(response);
+    }
+    fn is_closed(&self) -> bool {
+        self.closed || matches!(&self.peer, Some(peer) if peer_closed(peer))
+    }
+}
+
+// Whether the peer has shut down the connection, without blocking.
+fn peer_closed(peer: &TcpStream) -> bool {
+    if peer.set_nonblocking(true).is_err() {
+        return false;
+    }
+    let closed = match peer.peek(&mut [0u8; 1]) {
+        Ok(length) => length == 0,
+        Err(e) => e.kind() != ErrorKind::WouldBlock,
+    };
+    let _ = peer.set_nonblocking(false);
+    closed
 }
 
 #[cfg(test)]
@@ -119,4 +154,36 @@ mod tests {
             b"HTTP/1.1 200\r\nContent-Type: text/plain\r\nContent-Length: 11\r\n\r\nHello World";
         assert_eq!(written_bytes, expected_bytes);
     }
+
+    #[test]
+    fn test_put_ok_streaming() {
+        let mut stream = Cursor::new(Vec::new());
+        let mut writer = ServerResponseWriter::new(&mut stream);
+        writer.put_ok_streaming("text/plain", &[]);
+        writer.put_chunk(b"Hello");
+        writer.put_chunk(b" World");
+        assert!(!writer.is_closed());
+        let written_bytes = stream.get_ref();
+        let expected_bytes =
+            b"HTTP/1.1 200\r\nContent-Type: text/plain\r\nConnection: close\r\n\r\nHello World";
+        assert_eq!(written_bytes, expected_bytes);
+        // A write to a full buffer fails like a closed connection.
+        let mut buffer = [0u8; 4];
+        let mut stream = Cursor::new(&mut buffer[..]);
+        let mut writer = ServerResponseWriter::new(&mut stream);
+        writer.put_chunk(b"Hello");
+        assert!(writer.is_closed());
+    }
+
+    #[test]
+    fn test_peer_closed() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (mut stream, _) = listener.accept().unwrap();
+        let peer = stream.try_clone().ok();
+        let writer = ServerResponseWriter::new(&mut stream).with_peer(peer);
+        assert!(!writer.is_closed());
+        drop(client);
+        assert!(writer.is_closed());
+    }
 }
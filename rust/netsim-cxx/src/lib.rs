@@ -19,6 +19,7 @@
 mod captures;
 mod devices;
 mod http_server;
+mod lifecycle;
 mod ranging;
 mod transport;
 mod uwb;
@@ -28,8 +29,8 @@ use std::pin::Pin;
 
 use cxx::let_cxx_string;
 use ffi::CxxServerResponseWriter;
-use http_server::http_request::StrHeaders;
-use http_server::server_response::ServerResponseWritable;
+use http_server::http_request::{HttpHeaders, HttpRequest, StrHeaders};
+use http_server::server_response::{ResponseWritable, ServerResponseWritable};
 
 use crate::transport::fd::handle_response;
 use crate::transport::fd::run_fd_transport;
@@ -37,8 +38,14 @@ use crate::transport::fd::run_fd_transport;
 use crate::captures::handlers::{
     clear_pcap_files, handle_capture_cxx, handle_packet_request, handle_packet_response,
 };
+use crate::devices::devices_handler::{
+    add_chip_cxx, get_rssi_cxx, handle_devices, handle_distances, handle_link_loss, handle_scene,
+    handle_trajectory, handle_zones, remove_chip_cxx, set_reconnect_grace_secs,
+    set_reconnect_same_ids, should_drop_packet, subscribe_events_cxx, AddChipResultCxx,
+    EventReceiverCxx,
+};
 use crate::http_server::run_http_server;
-use crate::ranging::*;
+use crate::lifecycle::{set_idle_shutdown_secs, wait_for_idle_shutdown};
 use crate::uwb::facade::*;
 use crate::version::*;
 
@@ -53,10 +60,65 @@ mod ffi {
         #[cxx_name = "RunHttpServer"]
         fn run_http_server();
 
+        // Lifecycle
+
+        #[cxx_name = SetIdleShutdownSecs]
+        #[namespace = "netsim::lifecycle"]
+        fn set_idle_shutdown_secs(secs: u32);
+
+        #[cxx_name = WaitForIdleShutdown]
+        #[namespace = "netsim::lifecycle"]
+        fn wait_for_idle_shutdown();
+
+        // Device Resource
+
+        #[namespace = "netsim::device"]
+        type AddChipResultCxx;
+        fn get_device_id(self: &AddChipResultCxx) -> u32;
+        fn get_chip_id(self: &AddChipResultCxx) -> u32;
+        fn get_facade_id(self: &AddChipResultCxx) -> u32;
+
+        #[cxx_name = AddChipCxx]
+        #[namespace = "netsim::device"]
+        fn add_chip_cxx(
+            device_guid: &str,
+            device_name: &str,
+            chip_kind: u32,
+            chip_name: &str,
+            chip_manufacturer: &str,
+            chip_product_name: &str,
+        ) -> Box<AddChipResultCxx>;
+
+        #[cxx_name = RemoveChipCxx]
+        #[namespace = "netsim::device"]
+        fn remove_chip_cxx(device_id: u32, chip_id: u32);
+
+        // Scene events
+
+        #[namespace = "netsim::device"]
+        type EventReceiverCxx;
+        #[cxx_name = NextEventJson]
+        fn next_event_json(self: &EventReceiverCxx, timeout_ms: u32) -> String;
+
+        #[cxx_name = SubscribeEventsCxx]
+        #[namespace = "netsim::device"]
+        fn subscribe_events_cxx() -> Box<EventReceiverCxx>;
+
+        // Reconnecting devices
+
+        #[cxx_name = SetReconnectGraceSecs]
+        #[namespace = "netsim::device"]
+        fn set_reconnect_grace_secs(secs: u32);
+
+        #[cxx_name = SetReconnectSameIds]
+        #[namespace = "netsim::device"]
+        fn set_reconnect_same_ids(same_ids: bool);
+
         // Ranging
 
-        #[cxx_name = "DistanceToRssi"]
-        fn distance_to_rssi(tx_power: i8, distance: f32) -> i8;
+        #[cxx_name = GetRssi]
+        #[namespace = "netsim::device"]
+        fn get_rssi_cxx(device_id: u32, other_device_id: u32, chip_kind: u32, tx_power: i8) -> i8;
 
         // Version
 
@@ -73,6 +135,24 @@ mod ffi {
             body: String,
         );
 
+        // handle_frontend_cxx routes a request for an Http resource such as
+        // "devices" or "zones" to its Rust handler
+
+        #[cxx_name = "HandleFrontendCxx"]
+        fn handle_frontend_cxx(
+            responder: Pin<&mut CxxServerResponseWriter>,
+            resource: String,
+            method: String,
+            param: String,
+            body: String,
+        );
+
+        // Visibility and link loss applied by the radio facades on packet delivery.
+
+        #[cxx_name = ShouldDropPacket]
+        #[namespace = "netsim::device"]
+        fn should_drop_packet(device_id: u32, other_device_id: u32, radio: i32) -> bool;
+
         // Packet hub
 
         #[cxx_name = HandleResponse]
@@ -129,7 +209,7 @@ mod ffi {
 
         #[cxx_name = Add]
         #[namespace = "netsim::uwb::facade"]
-        pub fn uwb_add(_chip_id: u32) -> u32;
+        pub fn uwb_add(device_id: u32) -> u32;
 
         #[cxx_name = Start]
         #[namespace = "netsim::uwb::facade"]
@@ -142,53 +222,6 @@ mod ffi {
     }
 
     unsafe extern "C++" {
-        include!("controller/controller.h");
-
-        #[namespace = "netsim::scene_controller"]
-        type AddChipResult;
-        fn get_chip_id(self: &AddChipResult) -> u32;
-        fn get_device_id(self: &AddChipResult) -> u32;
-        fn get_facade_id(self: &AddChipResult) -> u32;
-
-        #[rust_name = "add_chip_cxx"]
-        #[namespace = "netsim::scene_controller"]
-        fn AddChipCxx(
-            guid: &CxxString,
-            device_name: &CxxString,
-            chip_kind: u32,
-            chip_name: &CxxString,
-            manufacturer: &CxxString,
-            product_name: &CxxString,
-        ) -> UniquePtr<AddChipResult>;
-
-        #[rust_name = "remove_chip"]
-        #[namespace = "netsim::scene_controller"]
-        fn RemoveChip(device_id: u32, chip_id: u32);
-
-        #[rust_name = "get_devices"]
-        #[namespace = "netsim::scene_controller"]
-        fn GetDevices(
-            request: &CxxString,
-            response: Pin<&mut CxxString>,
-            error_message: Pin<&mut CxxString>,
-        ) -> u32;
-
-        #[rust_name = "get_devices_bytes"]
-        #[namespace = "netsim::scene_controller"]
-        fn GetDevicesBytes(vec: &mut Vec<u8>) -> bool;
-
-        #[rust_name = "get_facade_id"]
-        #[namespace = "netsim::scene_controller"]
-        fn GetFacadeId(chip_id: i32) -> i32;
-
-        #[rust_name = "patch_device"]
-        #[namespace = "netsim::scene_controller"]
-        fn PatchDevice(
-            request: &CxxString,
-            response: Pin<&mut CxxString>,
-            error_message: Pin<&mut CxxString>,
-        ) -> u32;
-
         /// A C++ class which can be used to respond to a request.
         include!("frontend/server_response_writable.h");
 
@@ -207,6 +240,73 @@ mod ffi {
         #[namespace = "netsim::frontend"]
         fn put_error(self: &CxxServerResponseWriter, error_code: u32, error_message: &CxxString);
 
+        #[namespace = "netsim::frontend"]
+        fn is_closed(self: &CxxServerResponseWriter) -> bool;
+
+        // Packet hub, for the responses of the UWB facade.
+
+        include!("packet_hub/packet_hub.h");
+
+        #[rust_name = "handle_uwb_response"]
+        #[namespace = "netsim::packet_hub"]
+        fn HandleUwbResponseCxx(facade_id: u32, packet: &[u8]);
+
+        // Bluetooth Facade.
+
+        include!("hci/bluetooth_facade.h");
+
+        #[rust_name = "hci_add"]
+        #[namespace = "netsim::hci::facade"]
+        fn Add(simulation_device: u32) -> u32;
+
+        #[rust_name = "hci_remove"]
+        #[namespace = "netsim::hci::facade"]
+        fn Remove(facade_id: u32);
+
+        #[rust_name = "hci_reset"]
+        #[namespace = "netsim::hci::facade"]
+        fn Reset(facade_id: u32);
+
+        #[rust_name = "hci_get"]
+        #[namespace = "netsim::hci::facade"]
+        fn GetCxx(facade_id: u32) -> Vec<u8>;
+
+        #[rust_name = "hci_patch"]
+        #[namespace = "netsim::hci::facade"]
+        fn PatchCxx(facade_id: u32, proto_bytes: &[u8]);
+
+        #[rust_name = "hci_transmit"]
+        #[namespace = "netsim::hci::facade"]
+        fn TransmitCxx(facade_id: u32, packet: &[u8]);
+
+        // WiFi Facade.
+
+        include!("wifi/wifi_facade.h");
+
+        #[rust_name = "wifi_add"]
+        #[namespace = "netsim::wifi::facade"]
+        fn Add(simulation_device: u32) -> u32;
+
+        #[rust_name = "wifi_remove"]
+        #[namespace = "netsim::wifi::facade"]
+        fn Remove(facade_id: u32);
+
+        #[rust_name = "wifi_reset"]
+        #[namespace = "netsim::wifi::facade"]
+        fn Reset(facade_id: u32);
+
+        #[rust_name = "wifi_get"]
+        #[namespace = "netsim::wifi::facade"]
+        fn GetCxx(facade_id: u32) -> Vec<u8>;
+
+        #[rust_name = "wifi_patch"]
+        #[namespace = "netsim::wifi::facade"]
+        fn PatchCxx(facade_id: u32, proto_bytes: &[u8]);
+
+        #[rust_name = "wifi_transmit"]
+        #[namespace = "netsim::wifi::facade"]
+        fn TransmitCxx(facade_id: u32, packet: &[u8]);
+
         include!("packet_hub/packet_hub.h");
 
         #[rust_name = "handle_request_cxx"]
@@ -243,4 +343,59 @@ impl ServerResponseWritable for CxxServerResponseWriterWrapper<'_> {
     fn put_ok_with_vec(&mut self, _mime_type: &str, _body: Vec<u8>, _headers: StrHeaders) {
         todo!()
     }
+
+    fn put_ok_streaming(&mut self, mime_type: &str, _headers: StrHeaders) {
+        // The gRPC stream has no length.
+        let_cxx_string!(mime_type = mime_type);
+        self.writer.put_ok_with_length(&mime_type, 0);
+    }
+
+    fn is_closed(&self) -> bool {
+        self.writer.is_closed()
+    }
+}
+
+/// The Rust handlers of the Http resources that the gRPC frontend calls
+/// through `handle_frontend_cxx`.
+fn frontend_handler(resource: &str) -> Option<fn(&HttpRequest, &str, ResponseWritable)> {
+    match resource {
+        "devices" => Some(handle_devices),
+        "scene" => Some(handle_scene),
+        "link_loss" => Some(handle_link_loss),
+        "trajectory" => Some(handle_trajectory),
+        "zones" => Some(handle_zones),
+        "distances" => Some(handle_distances),
+        _ => None,
+    }
+}
+
+/// Call the handler of an Http resource for the gRPC frontend, with the
+/// request the Http frontend would have received.
+fn handle_frontend_cxx(
+    responder: Pin<&mut CxxServerResponseWriter>,
+    resource: String,
+    method: String,
+    param: String,
+    body: String,
+) {
+    let writer = &mut CxxServerResponseWriterWrapper { writer: responder };
+    let handler = match frontend_handler(&resource) {
+        Some(handler) => handler,
+        None => {
+            writer.put_error(404, "Not found.");
+            return;
+        }
+    };
+    let uri = match param.as_str() {
+        "" => format!("/v1/{resource}"),
+        param => format!("/v1/{resource}/{param}"),
+    };
+    let request = HttpRequest {
+        method,
+        uri,
+        headers: HttpHeaders::new(),
+        version: "1.1".to_string(),
+        body: body.into_bytes(),
+    };
+    handler(&request, &param, writer);
 }
@@ -0,0 +1,269 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Lifecycle manager for idle shutdown of netsimd.
+//!
+//! netsimd becomes idle when the last device detaches. Once it has been
+//! idle for the idle timeout the registered shutdown callbacks are invoked to
+//! stop the frontend http server and the fd transports, and
+//! `wait_for_idle_shutdown` returns so the C++ server can exit.
+//!
+//! The idle timeout is set from the netsimd command line or ini file and
+//! a timeout of zero disables idle shutdown.
+
+use lazy_static::lazy_static;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Default idle time before netsimd shuts down.
+pub const DEFAULT_IDLE_SHUTDOWN_SECS: u64 = 300;
+
+/// Interval between idle checks in `wait_for_idle_shutdown`.
+const IDLE_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+lazy_static! {
+    static ref LIFECYCLE: Mutex<LifecycleManager> =
+        Mutex::new(LifecycleManager::new(Box::new(SystemClock)));
+}
+
+/// Source of the current time, injectable for unit tests.
+pub trait Clock: Send {
+    fn now(&self) -> Instant;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+pub type ShutdownCallback = Box<dyn FnOnce() + Send>;
+
+pub struct LifecycleManager {
+    clock: Box<dyn Clock>,
+    // None when idle shutdown is disabled.
+    idle_timeout: Option<Duration>,
+    idle_since: Option<Instant>,
+    shutdown_callbacks: Vec<ShutdownCallback>,
+    is_shut_down: bool,
+}
+
+impl LifecycleManager {
+    /// Create a manager that is not idle. netsimd only starts idling when
+    /// the last device detaches, so it never shuts down before a device
+    /// has attached.
+    pub fn new(clock: Box<dyn Clock>) -> Self {
+        LifecycleManager {
+            clock,
+            idle_timeout: Some(Duration::from_secs(DEFAULT_IDLE_SHUTDOWN_SECS)),
+            idle_since: None,
+            shutdown_callbacks: Vec::new(),
+            is_shut_down: false,
+        }
+    }
+
+    pub fn set_idle_timeout(&mut self, idle_timeout: Option<Duration>) {
+        self.idle_timeout = idle_timeout;
+    }
+
+    /// Called when a device attaches.
+    pub fn set_active(&mut self) {
+        self.idle_since = None;
+    }
+
+    /// Called when the last device detaches.
+    pub fn set_idle(&mut self) {
+        if self.idle_since.is_none() {
+            self.idle_since = Some(self.clock.now());
+        }
+    }
+
+    /// The time remaining until idle shutdown, or None if netsimd is
+    /// active or idle shutdown is disabled.
+    pub fn time_until_shutdown(&self) -> Option<Duration> {
+        let idle_timeout = self.idle_timeout?;
+        let idle_since = self.idle_since?;
+        let idle_for = self.clock.now().saturating_duration_since(idle_since);
+        Some(idle_timeout.saturating_sub(idle_for))
+    }
+
+    pub fn add_shutdown_callback(&mut self, callback: ShutdownCallback) {
+        self.shutdown_callbacks.push(callback);
+    }
+
+    /// Check the idle timeout and return the shutdown callbacks to invoke
+    /// if it has expired. The callbacks are returned only once.
+    fn poll(&mut self) -> Option<Vec<ShutdownCallback>> {
+        if self.is_shut_down || self.time_until_shutdown() != Some(Duration::ZERO) {
+            return None;
+        }
+        self.is_shut_down = true;
+        Some(std::mem::take(&mut self.shutdown_callbacks))
+    }
+}
+
+/// Set the idle timeout in seconds. Zero disables idle shutdown.
+pub fn set_idle_shutdown_secs(secs: u32) {
+    let idle_timeout = match secs {
+        0 => None,
+        secs => Some(Duration::from_secs(secs.into())),
+    };
+    LIFECYCLE.lock().unwrap().set_idle_timeout(idle_timeout);
+}
+
+pub fn set_active() {
+    LIFECYCLE.lock().unwrap().set_active();
+}
+
+pub fn set_idle() {
+    LIFECYCLE.lock().unwrap().set_idle();
+}
+
+/// Seconds remaining until idle shutdown, if netsimd is idle.
+pub fn get_secs_until_idle_shutdown() -> Option<u64> {
+    LIFECYCLE.lock().unwrap().time_until_shutdown().map(|remaining| remaining.as_secs())
+}
+
+pub fn add_shutdown_callback(callback: ShutdownCallback) {
+    LIFECYCLE.lock().unwrap().add_shutdown_callback(callback);
+}
+
+/// Block until netsimd has been idle for the idle timeout, then invoke
+/// the shutdown callbacks.
+pub fn wait_for_idle_shutdown() {
+    loop {
+        thread::sleep(IDLE_CHECK_INTERVAL);
+        // Invoke the callbacks without holding the lock.
+        let callbacks = LIFECYCLE.lock().unwrap().poll();
+        if let Some(callbacks) = callbacks {
+            println!("netsimd: shutting down after being idle");
+            for callback in callbacks {
+                callback();
+            }
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    // A clock that only moves when the test advances it.
+    #[derive(Clone)]
+    struct FakeClock {
+        now: Arc<Mutex<Instant>>,
+    }
+
+    impl FakeClock {
+        fn new() -> Self {
+            FakeClock { now: Arc::new(Mutex::new(Instant::now())) }
+        }
+        fn advance(&self, duration: Duration) {
+            *self.now.lock().unwrap() += duration;
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> Instant {
+            *self.now.lock().unwrap()
+        }
+    }
+
+    fn new_manager(secs: u64) -> (LifecycleManager, FakeClock, Arc<AtomicUsize>) {
+        let clock = FakeClock::new();
+        let mut manager = LifecycleManager::new(Box::new(clock.clone()));
+        manager.set_idle_timeout(Some(Duration::from_secs(secs)));
+        let count = Arc::new(AtomicUsize::new(0));
+        let callback_count = count.clone();
+        manager.add_shutdown_callback(Box::new(move || {
+            callback_count.fetch_add(1, Ordering::SeqCst);
+        }));
+        (manager, clock, count)
+    }
+
+    fn run_poll(manager: &mut LifecycleManager) {
+        for callback in manager.poll().unwrap_or_default() {
+            callback();
+        }
+    }
+
+    #[test]
+    fn test_remaining_time() {
+        let (mut manager, clock, _) = new_manager(120);
+        manager.set_active();
+        manager.set_idle();
+        assert_eq!(manager.time_until_shutdown(), Some(Duration::from_secs(120)));
+        clock.advance(Duration::from_secs(20));
+        assert_eq!(manager.time_until_shutdown(), Some(Duration::from_secs(100)));
+        manager.set_active();
+        assert_eq!(manager.time_until_shutdown(), None);
+        manager.set_idle();
+        clock.advance(Duration::from_secs(200));
+        assert_eq!(manager.time_until_shutdown(), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_shutdown_callbacks_invoked_once() {
+        let (mut manager, clock, count) = new_manager(120);
+        manager.set_active();
+        manager.set_idle();
+        clock.advance(Duration::from_secs(119));
+        run_poll(&mut manager);
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+        clock.advance(Duration::from_secs(1));
+        run_poll(&mut manager);
+        run_poll(&mut manager);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_no_shutdown_before_first_device() {
+        let (mut manager, clock, count) = new_manager(120);
+        assert_eq!(manager.time_until_shutdown(), None);
+        clock.advance(Duration::from_secs(1000));
+        run_poll(&mut manager);
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_active_prevents_shutdown() {
+        let (mut manager, clock, count) = new_manager(120);
+        manager.set_active();
+        clock.advance(Duration::from_secs(1000));
+        run_poll(&mut manager);
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+        // The idle time starts over when the last device detaches.
+        manager.set_idle();
+        clock.advance(Duration::from_secs(60));
+        run_poll(&mut manager);
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_disabled() {
+        let (mut manager, clock, count) = new_manager(120);
+        manager.set_idle();
+        manager.set_idle_timeout(None);
+        clock.advance(Duration::from_secs(1000));
+        assert_eq!(manager.time_until_shutdown(), None);
+        run_poll(&mut manager);
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+    }
+}
@@ -16,10 +16,24 @@
 ///
 /// The Free Space Path Loss (FSPL) model is considered as the standard
 /// under the ideal scenario.
+use frontend_proto::model::chip::AntennaPattern;
+use frontend_proto::model::Orientation as ProtoOrientation;
+use frontend_proto::model::PhyKind as ProtoPhyKind;
+use frontend_proto::model::Position as ProtoPosition;
 
 /// (dBm) PATH_LOSS at 1m for isotropic antenna transmitting BLE.
 const PATH_LOSS_AT_1M: f32 = 40.20;
 
+/// (dBi) Peak gain of a half-wave dipole.
+const DIPOLE_GAIN: f32 = 2.15;
+
+/// (dBi) Peak gain of a patch antenna and of its back lobe.
+const PATCH_GAIN: f32 = 7.0;
+const PATCH_BACK_LOBE_GAIN: f32 = -13.0;
+
+/// (dB) Depth of the pattern nulls below the peak gain.
+const NULL_DEPTH: f32 = 20.0;
+
 /// Convert distance to RSSI using the free space path loss equation.
 /// See [Free-space_path_loss][1].
 ///
@@ -38,10 +52,103 @@ pub fn distance_to_rssi(tx_power: i8, distance: f32) -> i8 {
     if distance == 0.0 {
         tx_power
     } else {
-        (tx_power as f32 - 20.0 * distance.log10() - PATH_LOSS_AT_1M) as i8
+        path_loss_rssi(tx_power, distance) as i8
+    }
+}
+
+/// (dBm) A typical transmit power of a radio, for RSSI estimates when
+/// the actual transmit power is not known.
+pub fn reference_tx_power(radio: ProtoPhyKind) -> i8 {
+    match radio {
+        ProtoPhyKind::BLUETOOTH_LOW_ENERGY => 0,
+        // Class 2 devices.
+        ProtoPhyKind::BLUETOOTH_CLASSIC => 4,
+        ProtoPhyKind::WIFI | ProtoPhyKind::WIFI_RTT => 20,
+        // The -41.3 dBm/MHz limit over a 500 MHz channel.
+        ProtoPhyKind::UWB => -14,
+        ProtoPhyKind::NONE => 0,
     }
 }
 
+fn path_loss_rssi(tx_power: i8, distance: f32) -> f32 {
+    tx_power as f32 - 20.0 * distance.log10() - PATH_LOSS_AT_1M
+}
+
+/// An antenna placed and oriented by its device.
+pub struct Antenna<'a> {
+    pub position: &'a ProtoPosition,
+    pub orientation: &'a ProtoOrientation,
+    pub pattern: AntennaPattern,
+}
+
+/// Rotate a vector of the device frame into the scene frame.
+///
+/// The orientation angles are in degrees, applied as roll about the x
+/// axis, then pitch about the y axis, then yaw about the z axis. The
+/// device forward axis is x and its up axis is z.
+fn rotate(orientation: &ProtoOrientation, v: [f32; 3]) -> [f32; 3] {
+    let (sin_r, cos_r) = orientation.roll.to_radians().sin_cos();
+    let (sin_p, cos_p) = orientation.pitch.to_radians().sin_cos();
+    let (sin_y, cos_y) = orientation.yaw.to_radians().sin_cos();
+    let [x, y, z] = v;
+    let (y, z) = (y * cos_r - z * sin_r, y * sin_r + z * cos_r);
+    let (x, z) = (x * cos_p + z * sin_p, -x * sin_p + z * cos_p);
+    let (x, y) = (x * cos_y - y * sin_y, x * sin_y + y * cos_y);
+    [x, y, z]
+}
+
+/// The cosine of the angle between a device axis and a direction.
+fn cos_angle(axis: [f32; 3], direction: [f32; 3]) -> f32 {
+    let norm = direction.iter().map(|c| c * c).sum::<f32>().sqrt();
+    axis.iter().zip(direction.iter()).map(|(a, d)| a * d).sum::<f32>() / norm
+}
+
+/// The gain (dBi) of an antenna in a direction of the scene frame.
+pub fn antenna_gain(
+    pattern: AntennaPattern,
+    orientation: &ProtoOrientation,
+    direction: [f32; 3],
+) -> f32 {
+    if direction == [0.0, 0.0, 0.0] {
+        return 0.0;
+    }
+    let null_floor = 10f32.powf(-NULL_DEPTH / 10.0);
+    match pattern {
+        AntennaPattern::DIPOLE => {
+            // The power pattern of a dipole is sin^2 of the angle to its axis.
+            let cos = cos_angle(rotate(orientation, [0.0, 0.0, 1.0]), direction);
+            DIPOLE_GAIN + 10.0 * (1.0 - cos * cos).max(null_floor).log10()
+        }
+        AntennaPattern::PATCH => {
+            let cos = cos_angle(rotate(orientation, [1.0, 0.0, 0.0]), direction);
+            if cos > 0.0 {
+                PATCH_GAIN + 10.0 * (cos * cos).max(null_floor).log10()
+            } else {
+                PATCH_BACK_LOBE_GAIN
+            }
+        }
+        _ => 0.0,
+    }
+}
+
+/// Compute the RSSI (dBm) between two antennas from the free space path
+/// loss and the gain of both antennas toward each other.
+pub fn antenna_rssi(tx_power: i8, tx: &Antenna, rx: &Antenna) -> i8 {
+    let direction = [
+        rx.position.x - tx.position.x,
+        rx.position.y - tx.position.y,
+        rx.position.z - tx.position.z,
+    ];
+    let distance = direction.iter().map(|c| c * c).sum::<f32>().sqrt();
+    if distance == 0.0 {
+        return tx_power;
+    }
+    let reverse = direction.map(|c| -c);
+    (path_loss_rssi(tx_power, distance)
+        + antenna_gain(tx.pattern, tx.orientation, direction)
+        + antenna_gain(rx.pattern, rx.orientation, reverse)) as i8
+}
+
 mod tests {
     #[test]
     fn zero_distance() {
@@ -54,4 +161,37 @@ mod tests {
         let rssi_at_1 = super::distance_to_rssi(0, 1.0);
         assert!(rssi_at_1 < -35 && rssi_at_1 > -55);
     }
+
+    #[test]
+    fn antenna_gain() {
+        use super::*;
+        let level = ProtoOrientation::new();
+        let yaw_180 = ProtoOrientation { yaw: 180.0, ..Default::default() };
+        let pitch_90 = ProtoOrientation { pitch: 90.0, ..Default::default() };
+        let forward = [1.0, 0.0, 0.0];
+        assert_eq!(antenna_gain(AntennaPattern::ISOTROPIC, &yaw_180, forward), 0.0);
+        // A patch antenna is strongest forward and weak behind.
+        assert_eq!(antenna_gain(AntennaPattern::PATCH, &level, forward), PATCH_GAIN);
+        assert_eq!(antenna_gain(AntennaPattern::PATCH, &yaw_180, forward), PATCH_BACK_LOBE_GAIN);
+        // A vertical dipole is strongest sideways and has a null along its axis.
+        assert!((antenna_gain(AntennaPattern::DIPOLE, &level, forward) - DIPOLE_GAIN).abs() < 1e-3);
+        assert!(antenna_gain(AntennaPattern::DIPOLE, &pitch_90, forward) < -17.0);
+    }
+
+    #[test]
+    fn antenna_rssi() {
+        use super::*;
+        let (a, b) = (ProtoPosition::new(), ProtoPosition { x: 2.0, ..Default::default() });
+        let level = ProtoOrientation::new();
+        let yaw_180 = ProtoOrientation { yaw: 180.0, ..Default::default() };
+        let isotropic =
+            Antenna { position: &b, orientation: &level, pattern: AntennaPattern::ISOTROPIC };
+        let facing = Antenna { position: &a, orientation: &level, pattern: AntennaPattern::PATCH };
+        let away = Antenna { position: &a, orientation: &yaw_180, pattern: AntennaPattern::PATCH };
+        let rssi = distance_to_rssi(0, 2.0);
+        assert_eq!(antenna_rssi(0, &facing, &isotropic), rssi + PATCH_GAIN as i8);
+        assert!(antenna_rssi(0, &away, &isotropic) < rssi);
+        // The link budget is symmetric.
+        assert_eq!(antenna_rssi(0, &isotropic, &facing), antenna_rssi(0, &facing, &isotropic));
+    }
 }
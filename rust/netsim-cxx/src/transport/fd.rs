@@ -17,14 +17,18 @@
 /// packet transports read requests and write response packets over gRPC or Fds.
 use super::h4;
 use super::uci;
-use crate::ffi::{add_chip_cxx, handle_request_cxx};
-use cxx::let_cxx_string;
+use crate::devices::devices_handler::{add_chip, remove_chip};
+use crate::ffi::handle_request_cxx;
+use crate::lifecycle;
+use frontend_proto::common::ChipKind as ProtoChipKind;
 use lazy_static::lazy_static;
+use protobuf::Enum;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{IoSlice, Write};
 use std::os::fd::FromRawFd;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::RwLock;
 use std::thread::JoinHandle;
 use std::{fmt, thread};
@@ -72,6 +76,9 @@ lazy_static! {
     static ref TRANSPORTS: RwLock<HashMap<String, File>> = RwLock::new(HashMap::new());
 }
 
+// Set by the lifecycle manager to stop forwarding packets.
+static SHUTDOWN: AtomicBool = AtomicBool::new(false);
+
 fn key(kind: u32, facade_id: u32) -> String {
     format!("{}/{}", kind, facade_id)
 }
@@ -96,7 +103,14 @@ pub fn handle_response(kind: u32, facade_id: u32, packet: &cxx::CxxVector<u8>, p
 
 /// read from the raw fd and pass to the packet hub.
 ///
-fn fd_reader(fd_rx: i32, kind: ChipKindEnum, facade_id: u32) -> JoinHandle<()> {
+/// The chip is removed from its device once the fd closes.
+fn fd_reader(
+    fd_rx: i32,
+    kind: ChipKindEnum,
+    device_id: i32,
+    chip_id: i32,
+    facade_id: u32,
+) -> JoinHandle<()> {
     thread::Builder::new()
         .name(format!("fd_reader_{}", fd_rx))
         .spawn(move || {
@@ -107,7 +121,7 @@ fn fd_reader(fd_rx: i32, kind: ChipKindEnum, facade_id: u32) -> JoinHandle<()> {
                 kind, facade_id, fd_rx
             );
 
-            loop {
+            while !SHUTDOWN.load(Ordering::SeqCst) {
                 match kind {
                     ChipKindEnum::UWB => match uci::read_uci_packet(&mut rx) {
                         Err(e) => {
@@ -115,7 +129,7 @@ fn fd_reader(fd_rx: i32, kind: ChipKindEnum, facade_id: u32) -> JoinHandle<()> {
                                 "netsimd: error reading uci control packet fd {} {:?}",
                                 fd_rx, e
                             );
-                            return;
+                            break;
                         }
                         Ok(uci::Packet { payload }) => {
                             handle_request_cxx(kind as u32, facade_id, &payload, 0);
@@ -130,15 +144,17 @@ fn fd_reader(fd_rx: i32, kind: ChipKindEnum, facade_id: u32) -> JoinHandle<()> {
                                 "netsimd: error reading hci control packet fd {} {:?}",
                                 fd_rx, e
                             );
-                            return;
+                            break;
                         }
                     },
                     _ => {
                         println!("netsimd: unknown control packet kind: {:?}", kind);
-                        return;
+                        break;
                     }
                 };
             }
+            TRANSPORTS.write().unwrap().remove(&key(kind as u32, facade_id));
+            remove_chip(device_id, chip_id);
         })
         .unwrap()
 }
@@ -156,6 +172,7 @@ pub fn run_fd_transport(startup_json: &String) {
         }
         Ok(startup_info) => startup_info,
     };
+    lifecycle::add_shutdown_callback(Box::new(shutdown_fd_transport));
     // See https://tokio.rs/tokio/topics/bridging
     // This code is synchronous hosting asynchronous until main is converted to rust.
     thread::Builder::new()
@@ -165,27 +182,34 @@ pub fn run_fd_transport(startup_json: &String) {
             let mut handles = Vec::with_capacity(chip_count);
             for device in startup_info.devices {
                 for chip in device.chips {
-                    let_cxx_string!(guid = chip.fd_in.to_string());
-                    let_cxx_string!(device_name = device.name.clone());
-                    let_cxx_string!(name = chip.id.unwrap_or_default());
-                    let_cxx_string!(manufacturer = chip.manufacturer.unwrap_or_default());
-                    let_cxx_string!(product_name = chip.product_name.unwrap_or_default());
-                    let result = add_chip_cxx(
-                        &guid,
-                        &device_name,
-                        chip.kind as u32,
-                        &name,
-                        &manufacturer,
-                        &product_name,
+                    let chip_kind = ProtoChipKind::from_i32(chip.kind as i32).unwrap_or_default();
+                    let result = add_chip(
+                        &chip.fd_in.to_string(),
+                        &device.name,
+                        chip_kind,
+                        &chip.id.unwrap_or_default(),
+                        &chip.manufacturer.unwrap_or_default(),
+                        &chip.product_name.unwrap_or_default(),
                     );
-                    let key = key(chip.kind as u32, result.get_facade_id());
+                    if result.device_id == 0 {
+                        println!("netsimd: unable to add {} chip of {}", chip.kind, device.name);
+                        continue;
+                    }
+                    let facade_id = result.facade_id as u32;
+                    let key = key(chip.kind as u32, facade_id);
 
                     // Cf writes to fd_out and reads from fd_in
                     let file_in = unsafe { File::from_raw_fd(chip.fd_in as i32) };
 
                     TRANSPORTS.write().unwrap().insert(key, file_in);
                     // TODO: switch to runtime.spawn once FIFOs are available in Tokio
-                    handles.push(fd_reader(chip.fd_out as i32, chip.kind, result.get_facade_id()));
+                    handles.push(fd_reader(
+                        chip.fd_out as i32,
+                        chip.kind,
+                        result.device_id,
+                        result.chip_id,
+                        facade_id,
+                    ));
                 }
             }
             // Wait for all of them to complete.
@@ -201,6 +225,13 @@ pub fn run_fd_transport(startup_json: &String) {
         .unwrap();
 }
 
+/// Stop the fd transport. Closes the output fds and the readers exit
+/// once their pending read completes.
+pub fn shutdown_fd_transport() {
+    SHUTDOWN.store(true, Ordering::SeqCst);
+    TRANSPORTS.write().unwrap().clear();
+}
+
 mod tests {
     #[allow(unused)]
     use super::StartupInfo;
@@ -14,15 +14,19 @@
 
 use std::io::{Error, Read};
 
-/// This module implements control packet parsing for UWB.
+/// This module implements control and data packet parsing for UWB.
 ///
 /// UWB Command Interface Specification, UCI Generic Specification
 /// Version 1.1
 ///
 /// 2.3.2 Format of Control Packets
+/// 2.3.3 Format of Data Packets
 
 const UCI_HEADER_SIZE: usize = 4;
 const UCI_PAYLOAD_LENGTH_FIELD: usize = 3;
+// The message type is in the 3 most significant bits of the first octet.
+const UCI_MT_SHIFT: u8 = 5;
+const UCI_MT_DATA: u8 = 0;
 
 #[derive(Debug)]
 pub struct Packet {
@@ -38,9 +42,32 @@ pub fn read_uci_packet<R: Read>(reader: &mut R) -> Result<Packet, PacketError> {
     // Read the UCI header
     let mut buffer = vec![0; UCI_HEADER_SIZE];
     reader.read_exact(&mut buffer[0..]).map_err(PacketError::IoError)?;
-    // Extract the control packet payload length and read
-    let length = buffer[UCI_PAYLOAD_LENGTH_FIELD] as usize + UCI_HEADER_SIZE;
+    // Extract the payload length and read. Data packets have a 16 bit
+    // little endian length in the last two octets of the header.
+    let payload_length = match buffer[0] >> UCI_MT_SHIFT {
+        UCI_MT_DATA => u16::from_le_bytes([buffer[2], buffer[3]]) as usize,
+        _ => buffer[UCI_PAYLOAD_LENGTH_FIELD] as usize,
+    };
+    let length = payload_length + UCI_HEADER_SIZE;
     buffer.resize(length, 0);
     reader.read_exact(&mut buffer[UCI_HEADER_SIZE..]).map_err(PacketError::IoError)?;
     Ok(Packet { payload: buffer })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_uci_packet() {
+        // A control packet, CORE_GET_DEVICE_INFO_CMD, followed by a data
+        // packet with a 258 byte payload.
+        let mut bytes = vec![0x20, 0x02, 0x00, 0x00];
+        bytes.extend([0x01, 0x00, 0x02, 0x01]);
+        bytes.extend([0xaa; 258]);
+        let mut reader = bytes.as_slice();
+        assert_eq!(read_uci_packet(&mut reader).unwrap().payload, [0x20, 0x02, 0x00, 0x00]);
+        assert_eq!(read_uci_packet(&mut reader).unwrap().payload.len(), 4 + 258);
+        assert!(read_uci_packet(&mut reader).is_err());
+    }
+}
@@ -12,30 +12,189 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-pub fn handle_uwb_request(_facade_id: u32, _packet: &[u8]) {
-    println!("netsim: handle_uwb_request");
+//! The UWB chip facade.
+//!
+//! Keeps the radio state of every UWB chip. The C++ packet hub reaches
+//! it through the cxx functions below and the Rust device model through
+//! the `UwbFacade` ChipFacade.
+
+use crate::devices::device::DeviceIdentifier;
+use crate::devices::devices_handler::should_drop_packet;
+use crate::devices::facades::{ChipFacade, FacadeIdentifier};
+use frontend_proto::model::chip::Chip as ProtoChipRadio;
+use frontend_proto::model::chip::Radio as ProtoRadioChip;
+use frontend_proto::model::PhyKind as ProtoPhyKind;
+use frontend_proto::model::State as ProtoState;
+use lazy_static::lazy_static;
+use protobuf::{Enum, Message};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::RwLock;
+
+lazy_static! {
+    static ref UWB_CHIPS: RwLock<UwbChips> = RwLock::new(UwbChips::new());
+}
+
+/// A UWB chip and the device it belongs to. The packet counts are kept
+/// apart from the radio so that packets only take the read lock.
+struct UwbChip {
+    device_id: u32,
+    radio: ProtoRadioChip,
+    tx_count: AtomicI32,
+    rx_count: AtomicI32,
+}
+
+impl UwbChip {
+    fn new(device_id: u32) -> Self {
+        UwbChip {
+            device_id,
+            radio: new_radio(),
+            tx_count: AtomicI32::new(0),
+            rx_count: AtomicI32::new(0),
+        }
+    }
 }
 
-pub fn uwb_reset(_facade_id: u32) {
-    println!("netsim: uwb_reset");
+/// The UWB chips by facade id.
+struct UwbChips {
+    chips: HashMap<u32, UwbChip>,
+    next_facade_id: u32,
 }
-pub fn uwb_remove(_facade_id: u32) {
-    println!("netsim: uwb_remove");
+
+impl UwbChips {
+    fn new() -> Self {
+        UwbChips { chips: HashMap::new(), next_facade_id: 0 }
+    }
+}
+
+fn new_radio() -> ProtoRadioChip {
+    let mut radio = ProtoRadioChip::new();
+    radio.state = ProtoState::ON.into();
+    radio
+}
+
+fn is_on(radio: &ProtoRadioChip) -> bool {
+    radio.state.enum_value_or_default() == ProtoState::ON
 }
 
-pub fn uwb_patch(_facade_id: u32, _proto_bytes: &[u8]) {
-    println!("netsim: uwb_patch");
+/// The UWB chips a packet from a chip is delivered to: the other chips
+/// that are on, unless the device model drops the packet on the way.
+/// None when the chip is unknown or off.
+pub(crate) fn receivers(facade_id: u32) -> Option<Vec<u32>> {
+    // The device model takes UWB_CHIPS under its own locks, so the peers
+    // are checked without holding it.
+    let (device_id, peers) = {
+        let chips = UWB_CHIPS.read().unwrap();
+        let chip = chips.chips.get(&facade_id).filter(|chip| is_on(&chip.radio))?;
+        let peers: Vec<(u32, u32)> = chips
+            .chips
+            .iter()
+            .filter(|(id, peer)| **id != facade_id && is_on(&peer.radio))
+            .map(|(id, peer)| (*id, peer.device_id))
+            .collect();
+        (chip.device_id, peers)
+    };
+    let receivers = peers
+        .into_iter()
+        .filter(|(_, peer_device_id)| {
+            !should_drop_packet(device_id, *peer_device_id, ProtoPhyKind::UWB.value())
+        })
+        .map(|(id, _)| id)
+        .collect();
+    Some(receivers)
 }
 
-pub fn uwb_get(_facade_id: u32) -> Vec<u8> {
-    println!("netsim: uwb_get");
-    Vec::<u8>::new()
+pub fn handle_uwb_request(facade_id: u32, packet: &[u8]) {
+    let receivers = match receivers(facade_id) {
+        Some(receivers) => receivers,
+        None => {
+            if !UWB_CHIPS.read().unwrap().chips.contains_key(&facade_id) {
+                println!("netsim: handle_uwb_request unknown facade_id {facade_id}");
+            }
+            return;
+        }
+    };
+    {
+        let chips = UWB_CHIPS.read().unwrap();
+        if let Some(chip) = chips.chips.get(&facade_id) {
+            chip.tx_count.fetch_add(1, Ordering::Relaxed);
+        }
+        for id in &receivers {
+            if let Some(peer) = chips.chips.get(id) {
+                peer.rx_count.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+    // TODO: process the UCI packet once a UWB controller is available.
+    for id in receivers {
+        handle_uwb_response(id, packet);
+    }
 }
 
-// Returns facade_id
-pub fn uwb_add(_chip_id: u32) -> u32 {
-    println!("netsim: uwb_get");
-    1
+// Send a packet to the host of a chip through the packet hub, which also
+// captures it. The packet hub is not linked into the unit tests.
+#[cfg(not(test))]
+fn handle_uwb_response(facade_id: u32, packet: &[u8]) {
+    crate::ffi::handle_uwb_response(facade_id, packet);
+}
+
+#[cfg(test)]
+fn handle_uwb_response(_facade_id: u32, _packet: &[u8]) {}
+
+pub fn uwb_reset(facade_id: u32) {
+    if let Some(chip) = UWB_CHIPS.write().unwrap().chips.get_mut(&facade_id) {
+        *chip = UwbChip::new(chip.device_id);
+    }
+}
+
+pub fn uwb_remove(facade_id: u32) {
+    UWB_CHIPS.write().unwrap().chips.remove(&facade_id);
+}
+
+/// Patch the radio state with a serialized model::Chip::Radio.
+pub fn uwb_patch(facade_id: u32, proto_bytes: &[u8]) {
+    match ProtoRadioChip::parse_from_bytes(proto_bytes) {
+        Ok(patch) => patch_radio(facade_id, &patch),
+        Err(e) => eprintln!("netsim: uwb_patch unable to parse radio: {e}"),
+    }
+}
+
+fn patch_radio(facade_id: u32, patch: &ProtoRadioChip) {
+    if let Some(chip) = UWB_CHIPS.write().unwrap().chips.get_mut(&facade_id) {
+        let state = patch.state.enum_value_or_default();
+        if state != ProtoState::UNKNOWN {
+            chip.radio.state = state.into();
+        }
+        if patch.range != 0.0 {
+            chip.radio.range = patch.range;
+        }
+    }
+}
+
+/// The serialized model::Chip::Radio of the chip.
+pub fn uwb_get(facade_id: u32) -> Vec<u8> {
+    get_radio(facade_id).write_to_bytes().unwrap_or_default()
+}
+
+fn get_radio(facade_id: u32) -> ProtoRadioChip {
+    let chips = UWB_CHIPS.read().unwrap();
+    let chip = match chips.chips.get(&facade_id) {
+        Some(chip) => chip,
+        None => return ProtoRadioChip::default(),
+    };
+    let mut radio = chip.radio.clone();
+    radio.tx_count = chip.tx_count.load(Ordering::Relaxed);
+    radio.rx_count = chip.rx_count.load(Ordering::Relaxed);
+    radio
+}
+
+/// Add a UWB chip to the device and return its facade_id.
+pub fn uwb_add(device_id: u32) -> u32 {
+    let mut chips = UWB_CHIPS.write().unwrap();
+    let facade_id = chips.next_facade_id;
+    chips.next_facade_id += 1;
+    chips.chips.insert(facade_id, UwbChip::new(device_id));
+    facade_id
 }
 
 pub fn uwb_start() {
@@ -45,3 +204,77 @@ pub fn uwb_start() {
 pub fn uwb_stop() {
     println!("netsim: uwb_stop");
 }
+
+/// The UWB facade of the Rust device model.
+pub struct UwbFacade;
+
+impl ChipFacade for UwbFacade {
+    fn add(&self, device_id: DeviceIdentifier) -> FacadeIdentifier {
+        uwb_add(device_id as u32) as FacadeIdentifier
+    }
+
+    fn remove(&self, facade_id: FacadeIdentifier) {
+        uwb_remove(facade_id as u32);
+    }
+
+    fn get(&self, facade_id: FacadeIdentifier) -> ProtoChipRadio {
+        ProtoChipRadio::Uwb(get_radio(facade_id as u32))
+    }
+
+    fn patch(&self, facade_id: FacadeIdentifier, patch: &ProtoChipRadio) {
+        if let ProtoChipRadio::Uwb(radio) = patch {
+            patch_radio(facade_id as u32, radio);
+        }
+    }
+
+    fn reset(&self, facade_id: FacadeIdentifier) {
+        uwb_reset(facade_id as u32);
+    }
+
+    fn transmit(&self, facade_id: FacadeIdentifier, packet: &[u8]) {
+        handle_uwb_request(facade_id as u32, packet);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uwb_radio_state() {
+        let facade_id = uwb_add(1);
+        let radio = ProtoRadioChip::parse_from_bytes(&uwb_get(facade_id)).unwrap();
+        assert_eq!(radio.state.enum_value_or_default(), ProtoState::ON);
+
+        let mut patch = ProtoRadioChip::new();
+        patch.state = ProtoState::OFF.into();
+        uwb_patch(facade_id, &patch.write_to_bytes().unwrap());
+        assert_eq!(get_radio(facade_id).state.enum_value_or_default(), ProtoState::OFF);
+        // Packets are not sent while the radio is off.
+        handle_uwb_request(facade_id, &[]);
+        assert_eq!(get_radio(facade_id).tx_count, 0);
+
+        uwb_reset(facade_id);
+        handle_uwb_request(facade_id, &[]);
+        assert_eq!(get_radio(facade_id).state.enum_value_or_default(), ProtoState::ON);
+        assert_eq!(get_radio(facade_id).tx_count, 1);
+
+        uwb_remove(facade_id);
+        assert!(uwb_get(facade_id).is_empty());
+    }
+
+    #[test]
+    fn test_uwb_link_loss() {
+        use crate::devices::link_loss::{remove_device_links, set_link_loss};
+        let (phone, watch, tag) = (uwb_add(9101), uwb_add(9102), uwb_add(9103));
+        set_link_loss(9101, 9102, ProtoPhyKind::UWB, 1.0).unwrap();
+        let receivers = receivers(phone).unwrap();
+        assert!(!receivers.contains(&watch));
+        assert!(receivers.contains(&tag));
+        assert!(!receivers.contains(&phone));
+        remove_device_links(9101);
+        for facade_id in [phone, watch, tag] {
+            uwb_remove(facade_id);
+        }
+    }
+}
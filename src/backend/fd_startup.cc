@@ -24,10 +24,10 @@
 #include <thread>
 
 #include "common.pb.h"
-#include "controller/scene_controller.h"
 #include "hci/bluetooth_facade.h"
 #include "model/hci/h4_parser.h"
 #include "model/hci/hci_transport.h"
+#include "netsim-cxx/src/lib.rs.h"
 #include "startup.pb.h"
 #include "util/log.h"
 
@@ -213,8 +213,8 @@ class FdStartupImpl : public FdStartup {
               std::make_shared<FdHciForwarder>(name, fd_in, fd_out);
           auto guid =
               name + ":" + std::to_string(fd_in) + ":" + std::to_string(fd_out);
-          netsim::controller::SceneController::Singleton().AddChip(
-              guid, name, common::ChipKind::BLUETOOTH);
+          netsim::device::AddChipCxx(guid, name, common::ChipKind::BLUETOOTH,
+                                     "", "", "");
 
           BtsLog("FdHciForwarder is not supported");
         }
@@ -23,10 +23,10 @@
 #include <unordered_map>
 
 #include "common.pb.h"
-#include "controller/controller.h"
 #include "google/protobuf/empty.pb.h"
 #include "grpcpp/server_context.h"
 #include "grpcpp/support/status.h"
+#include "netsim-cxx/src/lib.rs.h"
 #include "packet_hub/packet_hub.h"
 #include "packet_streamer.grpc.pb.h"
 #include "packet_streamer.pb.h"
@@ -77,8 +77,17 @@ class ServiceImpl final : public packet::PacketStreamer::Service {
     auto manufacturer = request.initial_info().chip().manufacturer();
     auto product_name = request.initial_info().chip().product_name();
     // Add a new chip to the device
-    auto [device_id, chip_id, facade_id] = scene_controller::AddChip(
-        peer, device_name, chip_kind, chip_name, manufacturer, product_name);
+    auto result = netsim::device::AddChipCxx(peer, device_name, chip_kind,
+                                             chip_name, manufacturer,
+                                             product_name);
+    if (result->get_device_id() == 0) {
+      BtsLog("grpc_server: unable to add chip to %s", device_name.c_str());
+      return ::grpc::Status(::grpc::StatusCode::INVALID_ARGUMENT,
+                            "Unable to add the chip.");
+    }
+    auto device_id = result->get_device_id();
+    auto chip_id = result->get_chip_id();
+    auto facade_id = result->get_facade_id();
 
     BtsLog("grpc_server: adding chip %d with facade %d to %s", chip_id,
            facade_id, device_name.c_str());
@@ -90,7 +99,7 @@ class ServiceImpl final : public packet::PacketStreamer::Service {
     facade_to_stream.erase(ChipFacade(chip_kind, facade_id));
 
     // Remove the chip from the device
-    scene_controller::RemoveChip(device_id, chip_id);
+    netsim::device::RemoveChipCxx(device_id, chip_id);
 
     BtsLog("grpc_server: removing chip %d from %s", chip_id,
            device_name.c_str());
@@ -128,18 +137,16 @@ class ServiceImpl final : public packet::PacketStreamer::Service {
         auto packet =
             ToSharedVec(request.mutable_hci_packet()->mutable_packet());
         packet_hub::HandleRequest(chip_kind, facade_id, *packet, packet_type);
-      } else if (chip_kind == common::ChipKind::WIFI) {
+      } else if (chip_kind == common::ChipKind::WIFI ||
+                 chip_kind == common::ChipKind::UWB) {
         if (!request.has_packet()) {
           BtsLog("grpc_server: unknown packet type from %d", facade_id);
           continue;
         }
         auto packet = ToSharedVec(request.mutable_packet());
-        packet_hub::HandleRequest(chip_kind, facade_id,
-
-                                  *packet,
+        packet_hub::HandleRequest(chip_kind, facade_id, *packet,
                                   packet::HCIPacket::HCI_PACKET_UNSPECIFIED);
       } else {
-        // TODO: add UWB here
         BtsLog("grpc_server: unknown chip kind");
       }
     }
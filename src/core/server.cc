@@ -14,16 +14,13 @@
 
 #include "core/server.h"
 
-#include <chrono>
 #include <memory>
-#include <optional>
 #include <string>
 #include <thread>
 
 #ifdef NETSIM_ANDROID_EMULATOR
 #include "backend/grpc_server.h"
 #endif
-#include "controller/controller.h"
 #include "frontend/frontend_server.h"
 #include "grpcpp/security/server_credentials.h"
 #include "grpcpp/server.h"
@@ -42,8 +39,6 @@
 namespace netsim::server {
 
 namespace {
-constexpr std::chrono::seconds InactivityCheckInterval(5);
-
 std::unique_ptr<grpc::Server> RunGrpcServer(int netsim_grpc_port) {
   grpc::ServerBuilder builder;
   int selected_port;
@@ -88,16 +83,12 @@ void Run() {
     std::thread(RunHttpServer).detach();
   }
 
-  while (true) {
-    std::this_thread::sleep_for(InactivityCheckInterval);
-    if (auto seconds_to_shutdown = netsim::scene_controller::GetShutdownTime();
-        seconds_to_shutdown.has_value() &&
-        seconds_to_shutdown.value() < std::chrono::seconds(0)) {
-      grpc_server->Shutdown();
-      BtsLog("Netsim has been shutdown due to inactivity.");
-      break;
-    }
-  }
+  // Blocks until netsim has been idle for the idle shutdown timeout. The
+  // lifecycle manager stops the http server and fd transports before
+  // returning.
+  netsim::lifecycle::WaitForIdleShutdown();
+  grpc_server->Shutdown();
+  BtsLog("Netsim has been shutdown due to inactivity.");
 }
 
 }  // namespace netsim::server
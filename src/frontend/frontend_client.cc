@@ -90,11 +90,30 @@ class FrontendClientImpl : public FrontendClient {
     return make_result(status, response);
   }
 
-  // Gets the list of device information
-  std::unique_ptr<ClientResult> GetDevices() const override {
+  // Gets the list of device information, optionally filtered
+  std::unique_ptr<ClientResult> GetDevices(
+      rust::Vec<::rust::u8> const &request_byte_vec) const override {
     frontend::GetDevicesResponse response;
     grpc::ClientContext context_;
-    auto status = stub_->GetDevices(&context_, {}, &response);
+    frontend::GetDevicesRequest request;
+    if (!request.ParseFromArray(request_byte_vec.data(),
+                                request_byte_vec.size())) {
+      return make_result(
+          grpc::Status(
+              grpc::StatusCode::INVALID_ARGUMENT,
+              "Error parsing GetDevices request protobuf. request size:" +
+                  std::to_string(request_byte_vec.size())),
+          response);
+    };
+    auto status = stub_->GetDevices(&context_, request, &response);
+    return make_result(status, response);
+  }
+
+  // Gets the distance and the estimated RSSI of every pair of devices
+  std::unique_ptr<ClientResult> GetDistances() const override {
+    frontend::GetDistancesResponse response;
+    grpc::ClientContext context_;
+    auto status = stub_->GetDistances(&context_, {}, &response);
     return make_result(status, response);
   }
 
@@ -194,7 +213,9 @@ class FrontendClientImpl : public FrontendClient {
       case frontend::GrpcMethod::PatchDevice:
         return PatchDevice(request_byte_vec);
       case frontend::GrpcMethod::GetDevices:
-        return GetDevices();
+        return GetDevices(request_byte_vec);
+      case frontend::GrpcMethod::GetDistances:
+        return GetDistances();
       case frontend::GrpcMethod::Reset:
         return Reset();
       case frontend::GrpcMethod::ListCapture:
@@ -16,12 +16,12 @@
 
 #include <google/protobuf/util/json_util.h>
 
+#include <cstdint>
 #include <iostream>
 #include <memory>
 #include <string>
 #include <utility>
 
-#include "controller/scene_controller.h"
 #include "frontend.grpc.pb.h"
 #include "frontend.pb.h"
 #include "google/protobuf/empty.pb.h"
@@ -38,14 +38,30 @@ namespace {
 class CxxServerResponseWritable : public frontend::CxxServerResponseWriter {
  public:
   CxxServerResponseWritable()
-      : grpc_writer_(nullptr), err(""), is_ok(false), body(""), length(0){};
+      : grpc_writer_(nullptr),
+        context_(nullptr),
+        err(""),
+        is_ok(false),
+        body(""),
+        length(0),
+        closed(false),
+        error_code(0){};
   CxxServerResponseWritable(
-      grpc::ServerWriter<netsim::frontend::GetCaptureResponse> *grpc_writer)
-      : grpc_writer_(grpc_writer), err(""), is_ok(false), body(""), length(0){};
+      grpc::ServerWriter<netsim::frontend::GetCaptureResponse> *grpc_writer,
+      grpc::ServerContext *context)
+      : grpc_writer_(grpc_writer),
+        context_(context),
+        err(""),
+        is_ok(false),
+        body(""),
+        length(0),
+        closed(false),
+        error_code(0){};
 
   void put_error(unsigned int error_code,
                  const std::string &response) const override {
     err = std::to_string(error_code) + ": " + response;
+    this->error_code = error_code;
     is_ok = false;
   }
 
@@ -59,6 +75,7 @@ class CxxServerResponseWritable : public frontend::CxxServerResponseWriter {
     netsim::frontend::GetCaptureResponse response;
     response.set_capture_stream(std::string(chunk.begin(), chunk.end()));
     is_ok = grpc_writer_->Write(response);
+    closed = !is_ok;
   }
 
   void put_ok(const std::string &mime_type,
@@ -67,14 +84,37 @@ class CxxServerResponseWritable : public frontend::CxxServerResponseWriter {
     is_ok = true;
   }
 
+  // A followed capture stops once a write fails or the client cancels.
+  bool is_closed() const override {
+    return closed || (context_ != nullptr && context_->IsCancelled());
+  }
+
+  // The gRPC status of the Http error code of the response.
+  grpc::Status ErrorStatus() const {
+    switch (error_code) {
+      case 400:
+        return grpc::Status(grpc::StatusCode::INVALID_ARGUMENT, err);
+      case 404:
+        return grpc::Status(grpc::StatusCode::NOT_FOUND, err);
+      default:
+        return grpc::Status(grpc::StatusCode::UNKNOWN, err);
+    }
+  }
+
   mutable grpc::ServerWriter<netsim::frontend::GetCaptureResponse>
       *grpc_writer_;
+  grpc::ServerContext *context_;
   mutable std::string err;
   mutable bool is_ok;
   mutable std::string body;
   mutable std::size_t length;
+  mutable bool closed;
+  mutable unsigned int error_code;
 };
 
+// The interval at which RegisterEvents checks for a cancelled stream.
+constexpr uint32_t kEventPollMillis = 1000;
+
 class FrontendServer final : public frontend::FrontendService::Service {
  public:
   grpc::Status GetVersion(grpc::ServerContext *context,
@@ -84,45 +124,236 @@ class FrontendServer final : public frontend::FrontendService::Service {
     return grpc::Status::OK;
   }
 
+  grpc::Status RegisterEvents(grpc::ServerContext *context,
+                              const google::protobuf::Empty *empty,
+                              grpc::ServerWriter<frontend::Event> *writer) {
+    auto receiver = netsim::device::SubscribeEventsCxx();
+    // Poll so that a cancelled stream is noticed without a scene change.
+    while (!context->IsCancelled()) {
+      auto json = receiver->NextEventJson(kEventPollMillis);
+      if (json.empty()) continue;
+      frontend::Event event;
+      google::protobuf::util::JsonParseOptions options;
+      options.ignore_unknown_fields = true;
+      google::protobuf::util::JsonStringToMessage(std::string(json), &event,
+                                                  options);
+      if (!writer->Write(event)) break;
+    }
+    return grpc::Status::OK;
+  }
+
   grpc::Status GetDevices(grpc::ServerContext *context,
-                          const google::protobuf::Empty *empty,
+                          const frontend::GetDevicesRequest *request,
                           frontend::GetDevicesResponse *reply) {
-    const auto scene = netsim::controller::SceneController::Singleton().Get();
-    for (const auto &device : scene.devices())
-      reply->add_devices()->CopyFrom(device);
-    return grpc::Status::OK;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleFrontendCxx(writer, "devices", "GET", "", body);
+    if (writer.is_ok) {
+      // The devices of the scene, without its zones and radios.
+      google::protobuf::util::JsonParseOptions options;
+      options.ignore_unknown_fields = true;
+      google::protobuf::util::JsonStringToMessage(writer.body, reply, options);
+      return grpc::Status::OK;
+    }
+    return writer.ErrorStatus();
   }
 
   grpc::Status PatchDevice(grpc::ServerContext *context,
                            const frontend::PatchDeviceRequest *request,
                            google::protobuf::Empty *response) {
-    auto status = netsim::controller::SceneController::Singleton().PatchDevice(
-        request->device());
-    if (!status)
-      return grpc::Status(grpc::StatusCode::NOT_FOUND,
-                          "device " + request->device().name() + " not found.");
-    return grpc::Status::OK;
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleFrontendCxx(writer, "devices", "PATCH", "", body);
+    if (writer.is_ok) {
+      return grpc::Status::OK;
+    }
+    return writer.ErrorStatus();
   }
 
   grpc::Status SetPacketCapture(
       grpc::ServerContext *context,
       const frontend::SetPacketCaptureRequest *request,
       google::protobuf::Empty *empty) {
-    model::Device device;
-    model::Chip chip;
+    frontend::PatchDeviceRequest patch;
+    auto *device = patch.mutable_device();
+    device->set_name(request->device_serial());
     // Turn on bt packet capture
-    chip.set_capture(request->capture() ? model::State::ON : model::State::OFF);
-    chip.mutable_bt();
-    device.mutable_chips()->Add()->CopyFrom(chip);
-    controller::SceneController::Singleton().PatchDevice(device);
-    return grpc::Status::OK;
+    auto *chip = device->add_chips();
+    chip->set_capture(request->capture() ? model::State::ON
+                                         : model::State::OFF);
+    chip->mutable_bt();
+    return PatchDevice(context, &patch, empty);
+  }
+
+  grpc::Status SetTrajectory(grpc::ServerContext *context,
+                             const frontend::SetTrajectoryRequest *request,
+                             google::protobuf::Empty *response) {
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleFrontendCxx(writer, "trajectory", "PUT", "", body);
+    if (writer.is_ok) {
+      return grpc::Status::OK;
+    }
+    return writer.ErrorStatus();
   }
 
   grpc::Status Reset(grpc::ServerContext *context,
                      const google::protobuf::Empty *request,
                      google::protobuf::Empty *empty) {
-    netsim::controller::SceneController::Singleton().Reset();
-    return grpc::Status::OK;
+    CxxServerResponseWritable writer;
+    HandleFrontendCxx(writer, "devices", "POST", "reset", "");
+    if (writer.is_ok) {
+      return grpc::Status::OK;
+    }
+    return writer.ErrorStatus();
+  }
+
+  grpc::Status CreateDevice(grpc::ServerContext *context,
+                            const frontend::CreateDeviceRequest *request,
+                            frontend::CreateDeviceResponse *reply) {
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleFrontendCxx(writer, "devices", "POST", "", body);
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return writer.ErrorStatus();
+  }
+
+  grpc::Status DeleteDevice(grpc::ServerContext *context,
+                            const frontend::DeleteDeviceRequest *request,
+                            google::protobuf::Empty *response) {
+    CxxServerResponseWritable writer;
+    HandleFrontendCxx(writer, "devices", "DELETE",
+                      std::to_string(request->id()), "");
+    if (writer.is_ok) {
+      return grpc::Status::OK;
+    }
+    return writer.ErrorStatus();
+  }
+
+  grpc::Status SaveScene(grpc::ServerContext *context,
+                         const frontend::SceneFileRequest *request,
+                         google::protobuf::Empty *response) {
+    CxxServerResponseWritable writer;
+    HandleFrontendCxx(writer, "scene", "POST", "save", request->file_name());
+    if (writer.is_ok) {
+      return grpc::Status::OK;
+    }
+    return writer.ErrorStatus();
+  }
+
+  grpc::Status LoadScene(grpc::ServerContext *context,
+                         const frontend::SceneFileRequest *request,
+                         google::protobuf::Empty *response) {
+    CxxServerResponseWritable writer;
+    HandleFrontendCxx(writer, "scene", "POST", "load", request->file_name());
+    if (writer.is_ok) {
+      return grpc::Status::OK;
+    }
+    return writer.ErrorStatus();
+  }
+
+  grpc::Status SetLinkLoss(grpc::ServerContext *context,
+                           const frontend::SetLinkLossRequest *request,
+                           google::protobuf::Empty *response) {
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleFrontendCxx(writer, "link_loss", "PUT", "", body);
+    if (writer.is_ok) {
+      return grpc::Status::OK;
+    }
+    return writer.ErrorStatus();
+  }
+
+  grpc::Status ListLinkLoss(grpc::ServerContext *context,
+                            const google::protobuf::Empty *empty,
+                            frontend::ListLinkLossResponse *reply) {
+    CxxServerResponseWritable writer;
+    HandleFrontendCxx(writer, "link_loss", "GET", "", "");
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return writer.ErrorStatus();
+  }
+
+  grpc::Status ClearLinkLoss(grpc::ServerContext *context,
+                             const google::protobuf::Empty *request,
+                             google::protobuf::Empty *response) {
+    CxxServerResponseWritable writer;
+    HandleFrontendCxx(writer, "link_loss", "DELETE", "", "");
+    if (writer.is_ok) {
+      return grpc::Status::OK;
+    }
+    return writer.ErrorStatus();
+  }
+
+  grpc::Status PatchZone(grpc::ServerContext *context,
+                         const frontend::PatchZoneRequest *request,
+                         google::protobuf::Empty *response) {
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleFrontendCxx(writer, "zones", "PUT", "", body);
+    if (writer.is_ok) {
+      return grpc::Status::OK;
+    }
+    return writer.ErrorStatus();
+  }
+
+  grpc::Status ListZones(grpc::ServerContext *context,
+                         const google::protobuf::Empty *empty,
+                         frontend::ListZonesResponse *reply) {
+    CxxServerResponseWritable writer;
+    HandleFrontendCxx(writer, "zones", "GET", "", "");
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return writer.ErrorStatus();
+  }
+
+  grpc::Status DeleteZone(grpc::ServerContext *context,
+                          const frontend::DeleteZoneRequest *request,
+                          google::protobuf::Empty *response) {
+    CxxServerResponseWritable writer;
+    HandleFrontendCxx(writer, "zones", "DELETE", request->name(), "");
+    if (writer.is_ok) {
+      return grpc::Status::OK;
+    }
+    return writer.ErrorStatus();
+  }
+
+  grpc::Status AssignZone(grpc::ServerContext *context,
+                          const frontend::AssignZoneRequest *request,
+                          google::protobuf::Empty *response) {
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    CxxServerResponseWritable writer;
+    HandleFrontendCxx(writer, "zones", "PUT", "assign", body);
+    if (writer.is_ok) {
+      return grpc::Status::OK;
+    }
+    return writer.ErrorStatus();
+  }
+
+  grpc::Status GetDistances(grpc::ServerContext *context,
+                            const google::protobuf::Empty *empty,
+                            frontend::GetDistancesResponse *reply) {
+    CxxServerResponseWritable writer;
+    HandleFrontendCxx(writer, "distances", "GET", "", "");
+    if (writer.is_ok) {
+      google::protobuf::util::JsonStringToMessage(writer.body, reply);
+      return grpc::Status::OK;
+    }
+    return writer.ErrorStatus();
   }
 
   grpc::Status ListCapture(grpc::ServerContext *context,
@@ -134,30 +365,33 @@ class FrontendServer final : public frontend::FrontendService::Service {
       google::protobuf::util::JsonStringToMessage(writer.body, reply);
       return grpc::Status::OK;
     }
-    return grpc::Status(grpc::StatusCode::UNKNOWN, writer.err);
+    return writer.ErrorStatus();
   }
 
   grpc::Status PatchCapture(grpc::ServerContext *context,
                             const frontend::PatchCaptureRequest *request,
                             google::protobuf::Empty *response) {
     CxxServerResponseWritable writer;
-    HandleCaptureCxx(writer, "PATCH", std::to_string(request->id()),
-                     std::to_string(request->patch().state()));
+    std::string patch;
+    google::protobuf::util::MessageToJsonString(request->patch(), &patch);
+    HandleCaptureCxx(writer, "PATCH", std::to_string(request->id()), patch);
     if (writer.is_ok) {
       return grpc::Status::OK;
     }
-    return grpc::Status(grpc::StatusCode::UNKNOWN, writer.err);
+    return writer.ErrorStatus();
   }
   grpc::Status GetCapture(
       grpc::ServerContext *context,
       const netsim::frontend::GetCaptureRequest *request,
       grpc::ServerWriter<netsim::frontend::GetCaptureResponse> *grpc_writer) {
-    CxxServerResponseWritable writer(grpc_writer);
-    HandleCaptureCxx(writer, "GET", std::to_string(request->id()), "");
+    CxxServerResponseWritable writer(grpc_writer, context);
+    std::string body;
+    google::protobuf::util::MessageToJsonString(*request, &body);
+    HandleCaptureCxx(writer, "GET", std::to_string(request->id()), body);
     if (writer.is_ok) {
       return grpc::Status::OK;
     }
-    return grpc::Status(grpc::StatusCode::UNKNOWN, writer.err);
+    return writer.ErrorStatus();
   }
 };
 }  // namespace
@@ -16,11 +16,10 @@
 
 #include "frontend/frontend_server.cc"
 
+#include <optional>
 #include <string>
 
 #include "common.pb.h"
-#include "controller/device.h"
-#include "controller/scene_controller.h"
 #include "frontend.pb.h"
 #include "grpcpp/server_context.h"
 #include "grpcpp/support/status.h"
@@ -28,7 +27,6 @@
 #include "model.pb.h"
 
 namespace netsim {
-namespace controller {
 
 class FrontendServerTest : public ::testing::Test {
  protected:
@@ -37,8 +35,17 @@ class FrontendServerTest : public ::testing::Test {
   // An instance of the service under test.
   FrontendServer service_;
 
-  std::shared_ptr<Device> match(const std::string &name) {
-    return SceneController::Singleton().MatchDevice(name);
+  // The device with the name, as returned by GetDevices.
+  std::optional<model::Device> match(const std::string &name) {
+    frontend::GetDevicesRequest request;
+    frontend::GetDevicesResponse response;
+    if (!service_.GetDevices(&context_, &request, &response).ok()) {
+      return std::nullopt;
+    }
+    for (const auto &device : response.devices()) {
+      if (device.name() == name) return device;
+    }
+    return std::nullopt;
   }
 };
 
@@ -52,8 +59,8 @@ TEST_F(FrontendServerTest, VerifyVersion) {
 #ifdef NETSIM_ANDROID_EMULATOR
 TEST_F(FrontendServerTest, PatchDevicePosition) {
   auto name = "test-device-name-for-set-position";
-  auto [device_id, _1, _2] =
-      scene_controller::AddChip("guid-fs-1", name, common::ChipKind::BLUETOOTH);
+  netsim::device::AddChipCxx("guid-fs-1", name, common::ChipKind::BLUETOOTH,
+                             "", "", "");
 
   google::protobuf::Empty response;
   frontend::PatchDeviceRequest request;
@@ -63,30 +70,22 @@ TEST_F(FrontendServerTest, PatchDevicePosition) {
   request.mutable_device()->mutable_position()->set_z(3.3);
   grpc::Status status = service_.PatchDevice(&context_, &request, &response);
   ASSERT_TRUE(status.ok());
-  const auto &scene = netsim::controller::SceneController::Singleton().Get();
-  // NOTE: Singleton won't be reset between tests. Need to either deprecate
-  // Singleton pattern or provide reset().
-  bool found = false;
-  for (const auto &device : scene.devices()) {
-    if (device.name() == request.device().name()) {
-      EXPECT_EQ(device.position().x(), request.device().position().x());
-      EXPECT_EQ(device.position().y(), request.device().position().y());
-      EXPECT_EQ(device.position().z(), request.device().position().z());
-      found = true;
-      break;
-    }
-  }
-  EXPECT_TRUE(found);
+  auto device = match(name);
+  ASSERT_TRUE(device.has_value());
+  EXPECT_EQ(device->position().x(), request.device().position().x());
+  EXPECT_EQ(device->position().y(), request.device().position().y());
+  EXPECT_EQ(device->position().z(), request.device().position().z());
 }
 
 TEST_F(FrontendServerTest, PatchDevice) {
   auto name = "name-for-update";
-  auto [device_id, chip_id, _] =
-      scene_controller::AddChip("guid-fs-2", name, common::ChipKind::BLUETOOTH);
+  auto result = netsim::device::AddChipCxx(
+      "guid-fs-2", name, common::ChipKind::BLUETOOTH, "", "", "");
+  auto chip_id = result->get_chip_id();
 
   model::Device model;
   model.set_name(name);
-  model.set_visible(false);
+  model.set_visible(model::State::OFF);
   auto chip = model.mutable_chips()->Add();
   chip->mutable_bt()->mutable_classic()->set_state(model::State::OFF);
   chip->set_id(chip_id);
@@ -99,8 +98,8 @@ TEST_F(FrontendServerTest, PatchDevice) {
   grpc::Status status = service_.PatchDevice(&context_, &request, &response);
   ASSERT_TRUE(status.ok());
   auto optional_device = match(name);
-  ASSERT_TRUE(optional_device != nullptr);
-  model = optional_device->Get();
+  ASSERT_TRUE(optional_device.has_value());
+  model = *optional_device;
   ASSERT_TRUE(model.name() == name);
   ASSERT_TRUE(model.chips().size() == 1);
   ASSERT_TRUE(model.chips().Get(0).chip_case() == model::Chip::ChipCase::kBt);
@@ -117,5 +116,4 @@ TEST_F(FrontendServerTest, PatchDeviceNotFound) {
   EXPECT_EQ(status.error_code(), grpc::StatusCode::NOT_FOUND);
 }
 
-}  // namespace controller
 }  // namespace netsim
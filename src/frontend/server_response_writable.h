@@ -36,6 +36,8 @@ class CxxServerResponseWriter {
   virtual void put_chunk(rust::Slice<const uint8_t> chunk) const = 0;
   virtual void put_ok(const std::string &mime_type,
                       const std::string &body) const = 0;
+  // Whether the client has gone away, known once a chunk fails.
+  virtual bool is_closed() const = 0;
 };
 
 }  // namespace frontend
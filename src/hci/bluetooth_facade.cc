@@ -20,16 +20,22 @@
 #include <chrono>
 #include <cstdint>
 #include <iostream>
+#include <iterator>
 #include <memory>
 #include <unordered_map>
 #include <utility>
+#include <vector>
 
+#include "common.pb.h"
+#include "hci/address.h"
 #include "hci/hci_packet_transport.h"
 #include "model/hci/hci_sniffer.h"
 #include "model/setup/async_manager.h"
 #include "model/setup/test_command_handler.h"
 #include "model/setup/test_model.h"
 #include "netsim-cxx/src/lib.rs.h"
+#include "packet/bit_inserter.h"
+#include "packets/link_layer_packets.h"
 #include "util/filesystem.h"
 #include "util/log.h"
 
@@ -38,6 +44,7 @@ using netsim::model::State;
 namespace netsim::hci::facade {
 
 int8_t SimComputeRssi(int send_id, int recv_id, int8_t tx_power);
+bool SimDropPacket(int send_id, int recv_id, rootcanal::Phy::Type phy_type);
 void IncrTx(uint32_t send_id, rootcanal::Phy::Type phy_type);
 void IncrRx(uint32_t receive_id, rootcanal::Phy::Type phy_type);
 
@@ -60,12 +67,14 @@ class SimPhyLayer : public PhyLayer {
     return SimComputeRssi(sender_id, receiver_id, tx_power);
   }
 
-  // Overrides Send in PhyLayerFactory to add Rx/Tx statistics.
+  // Overrides Send in PhyLayerFactory to add Rx/Tx statistics and to
+  // apply the link loss model.
   void Send(std::vector<uint8_t> const &packet, int8_t tx_power,
             PhyDevice::Identifier sender_id) override {
     IncrTx(sender_id, type);
     for (const auto &device : phy_devices_) {
-      if (sender_id != device->id) {
+      if (sender_id != device->id &&
+          !SimDropPacket(sender_id, device->id, type)) {
         IncrRx(device->id, type);
         device->Receive(packet, type,
                         ComputeRssi(sender_id, device->id, tx_power));
@@ -78,10 +87,27 @@ class SimTestModel : public rootcanal::TestModel {
   // for constructor inheritance
   using rootcanal::TestModel::TestModel;
 
+ public:
+  // Sends a link layer packet from a device on the low energy phy.
+  void SendLowEnergy(std::vector<uint8_t> const &packet, int8_t tx_power,
+                     PhyDevice::Identifier sender_id) {
+    if (low_energy_phy_ != nullptr) {
+      low_energy_phy_->Send(packet, tx_power, sender_id);
+    }
+  }
+
+ protected:
   std::unique_ptr<rootcanal::PhyLayer> CreatePhyLayer(
       PhyLayer::Identifier id, rootcanal::Phy::Type type) override {
-    return std::make_unique<SimPhyLayer>(id, type);
+    auto phy = std::make_unique<SimPhyLayer>(id, type);
+    if (type == rootcanal::Phy::Type::LOW_ENERGY) {
+      low_energy_phy_ = phy.get();
+    }
+    return phy;
   }
+
+ private:
+  PhyLayer *low_energy_phy_ = nullptr;
 };
 
 size_t phy_low_energy_index_;
@@ -89,6 +115,8 @@ size_t phy_classic_index_;
 
 bool mStarted = false;
 std::shared_ptr<rootcanal::AsyncManager> mAsyncManager;
+// The user of the async manager for the packets sent by netsim.
+rootcanal::AsyncUserId mTransmitUserId;
 std::unique_ptr<SimTestModel> gTestModel;
 rootcanal::ControllerProperties controller_properties_;
 
@@ -101,6 +129,7 @@ void Start() {
   if (mStarted) return;
 
   mAsyncManager = std::make_shared<rootcanal::AsyncManager>();
+  mTransmitUserId = mAsyncManager->GetNextUserId();
 
   gTestModel = std::make_unique<SimTestModel>(
       std::bind(&rootcanal::AsyncManager::GetNextUserId, mAsyncManager),
@@ -225,6 +254,70 @@ void Remove(uint32_t id) {
   // rootcanal will call HciPacketTransport::Close().
 }
 
+rust::Vec<uint8_t> GetCxx(uint32_t id) {
+  auto bt = Get(id);
+  std::vector<uint8_t> message_vec(bt.ByteSizeLong());
+  bt.SerializeToArray(message_vec.data(), message_vec.size());
+  rust::Vec<uint8_t> proto_bytes;
+  proto_bytes.reserve(message_vec.size());
+  std::copy(message_vec.begin(), message_vec.end(),
+            std::back_inserter(proto_bytes));
+  return proto_bytes;
+}
+
+void PatchCxx(uint32_t id, rust::Slice<const uint8_t> proto_bytes) {
+  model::Chip::Bluetooth bt;
+  if (!bt.ParseFromArray(proto_bytes.data(), proto_bytes.size())) {
+    BtsLog("PatchCxx unable to parse the patch of %d", id);
+    return;
+  }
+  Patch(id, bt);
+}
+
+// The transmit power of the advertisements sent by netsim.
+const int8_t kAdvertisingTxPower = 0;
+
+// The header, advertiser address and advertising data of a legacy
+// advertising PDU.
+const size_t kPduHeaderLength = 2;
+const size_t kPduAddressLength = 6;
+
+void TransmitCxx(uint32_t id, rust::Slice<const uint8_t> packet) {
+  if (id_to_chip_info_.find(id) == id_to_chip_info_.end()) {
+    BtsLog("Transmit from an unknown id %d", id);
+    return;
+  }
+  if (id_to_chip_info_[id]->model->low_energy().state() != model::State::ON) {
+    return;
+  }
+  if (packet.size() < kPduHeaderLength + kPduAddressLength) {
+    BtsLog("Transmit of a short advertising PDU from %d", id);
+    return;
+  }
+  // Only ADV_NONCONN_IND PDUs are sent by netsim, see
+  // rust/netsim-cxx/src/devices/behavior.rs.
+  auto address_type = (packet[0] & 0x40) != 0
+                          ? ::model::packets::AddressType::RANDOM
+                          : ::model::packets::AddressType::PUBLIC;
+  auto address_begin = packet.begin() + kPduHeaderLength;
+  bluetooth::hci::Address source;
+  std::copy(address_begin, address_begin + kPduAddressLength,
+            source.address.begin());
+  std::vector<uint8_t> advertising_data(address_begin + kPduAddressLength,
+                                        packet.end());
+  auto builder = ::model::packets::LeLegacyAdvertisingPduBuilder::Create(
+      source, bluetooth::hci::Address::kEmpty, address_type,
+      ::model::packets::LegacyAdvertisingType::ADV_NONCONN_IND,
+      advertising_data);
+  auto bytes = std::make_shared<std::vector<uint8_t>>();
+  bluetooth::packet::BitInserter inserter(*bytes);
+  builder->Serialize(inserter);
+  // The phy is only used from the async manager thread.
+  mAsyncManager->ExecAsync(mTransmitUserId, 0ms, [id, bytes]() {
+    gTestModel->SendLowEnergy(*bytes, kAdvertisingTxPower, id);
+  });
+}
+
 // Rename AddChip(model::Chip, device, transport)
 
 uint32_t Add(uint32_t simulation_device) {
@@ -302,8 +395,20 @@ int8_t SimComputeRssi(int send_id, int recv_id, int8_t tx_power) {
   }
   auto a = id_to_chip_info_[send_id]->simulation_device;
   auto b = id_to_chip_info_[recv_id]->simulation_device;
-  auto distance = scene_controller::GetDistance(a, b);
-  return netsim::DistanceToRssi(tx_power, distance);
+  return netsim::device::GetRssi(a, b, common::ChipKind::BLUETOOTH, tx_power);
+}
+
+bool SimDropPacket(int send_id, int recv_id, rootcanal::Phy::Type phy_type) {
+  if (id_to_chip_info_.find(send_id) == id_to_chip_info_.end() ||
+      id_to_chip_info_.find(recv_id) == id_to_chip_info_.end()) {
+    return false;
+  }
+  auto a = id_to_chip_info_[send_id]->simulation_device;
+  auto b = id_to_chip_info_[recv_id]->simulation_device;
+  auto radio = phy_type == rootcanal::Phy::Type::LOW_ENERGY
+                   ? model::PhyKind::BLUETOOTH_LOW_ENERGY
+                   : model::PhyKind::BLUETOOTH_CLASSIC;
+  return netsim::device::ShouldDropPacket(a, b, radio);
 }
 
 }  // namespace netsim::hci::facade
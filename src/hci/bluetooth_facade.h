@@ -19,6 +19,7 @@
 #include <string>
 
 #include "model.pb.h"
+#include "rust/cxx.h"
 
 /** Manages the bluetooth chip emulation provided by the root canal library.
  *
@@ -35,6 +36,14 @@ void SetPacketCapture(uint32_t id, bool isOn, std::string device_name);
 model::Chip::Bluetooth Get(uint32_t);
 uint32_t Add(uint32_t simulation_device);
 
+// The chip as a serialized model::Chip::Bluetooth, for the Rust device
+// model.
+rust::Vec<uint8_t> GetCxx(uint32_t);
+void PatchCxx(uint32_t, rust::Slice<const uint8_t> proto_bytes);
+
+// Sends a legacy advertising PDU from the chip, for virtual devices.
+void TransmitCxx(uint32_t, rust::Slice<const uint8_t> packet);
+
 void Start();
 void Stop();
 
@@ -26,6 +26,15 @@
 #include <cstdio>
 #endif
 
+#include <algorithm>
+#include <cctype>
+#include <cstdint>
+#include <iostream>
+#include <limits>
+#include <optional>
+#include <stdexcept>
+#include <string>
+
 #ifndef NETSIM_ANDROID_EMULATOR
 #include "backend/fd_startup.h"
 #endif
@@ -33,6 +42,8 @@
 #include "frontend/frontend_client_stub.h"
 #include "hci/bluetooth_facade.h"
 #include "netsim-cxx/src/lib.rs.h"
+#include "util/ini_file.h"
+#include "util/os_utils.h"
 
 // Wireless network simulator for android (and other) emulated devices.
 
@@ -56,6 +67,25 @@ void ArgError(char *argv[], int c) {
   std::cerr << "Try `" << argv[0] << " --help' for more information.\n";
 }
 
+// Parses a number of seconds. A value that is not a number or is out of
+// range is logged and ignored, leaving the default in place.
+std::optional<uint32_t> ParseSecs(const std::string &name,
+                                  const std::string &value) {
+  auto is_digit = [](unsigned char c) { return std::isdigit(c) != 0; };
+  if (!value.empty() && std::all_of(value.begin(), value.end(), is_digit)) {
+    try {
+      auto secs = std::stoull(value);
+      if (secs <= std::numeric_limits<uint32_t>::max()) {
+        return static_cast<uint32_t>(secs);
+      }
+    } catch (const std::out_of_range &) {
+    }
+  }
+  std::cerr << "netsimd: invalid " << name << " '" << value
+            << "', using the default\n";
+  return std::nullopt;
+}
+
 int main(int argc, char *argv[]) {
 #if defined(__linux__)
   signal(SIGSEGV, SignalHandler);
@@ -64,6 +94,10 @@ int main(int argc, char *argv[]) {
   const option kLongOptions[] = {
       {"rootcanal_default_commands_file", required_argument, 0, 'c'},
       {"rootcanal_controller_properties_file", required_argument, 0, 'p'},
+      {"idle_shutdown_secs", required_argument, 0, 'i'},
+      {"reconnect_grace_secs", required_argument, 0, 'r'},
+      {"reconnect_same_ids", no_argument, 0, 'n'},
+      {0, 0, 0, 0},
   };
 
   bool debug = false;
@@ -71,6 +105,14 @@ int main(int argc, char *argv[]) {
   std::string fd_startup_str;
   std::string rootcanal_default_commands_file;
   std::string rootcanal_controller_properties_file;
+  // Idle shutdown timeout, zero disables idle shutdown. The flag takes
+  // precedence over the "idle_shutdown.secs" key of the netsim ini file.
+  std::optional<std::string> idle_shutdown_secs;
+  // How long the state of a disconnected device is kept for its
+  // reconnection, zero disables restoring reconnecting devices.
+  std::optional<std::string> reconnect_grace_secs;
+  // Reconnecting devices get their previous device and chip ids.
+  bool reconnect_same_ids = false;
 
   int c;
 
@@ -98,12 +140,46 @@ int main(int argc, char *argv[]) {
         rootcanal_controller_properties_file = std::string(optarg);
         break;
 
+      case 'i':
+        idle_shutdown_secs = std::string(optarg);
+        break;
+
+      case 'r':
+        reconnect_grace_secs = std::string(optarg);
+        break;
+
+      case 'n':
+        reconnect_same_ids = true;
+        break;
+
       default:
         ArgError(argv, c);
         return (-2);
     }
   }
 
+  std::string idle_shutdown_secs_name = "--idle_shutdown_secs";
+  if (!idle_shutdown_secs.has_value()) {
+    netsim::IniFile iniFile(netsim::osutils::GetNetsimIniFilepath());
+    iniFile.Read();
+    idle_shutdown_secs = iniFile.Get("idle_shutdown.secs");
+    idle_shutdown_secs_name = "idle_shutdown.secs";
+  }
+  if (idle_shutdown_secs.has_value()) {
+    if (auto secs =
+            ParseSecs(idle_shutdown_secs_name, idle_shutdown_secs.value())) {
+      netsim::lifecycle::SetIdleShutdownSecs(secs.value());
+    }
+  }
+
+  if (reconnect_grace_secs.has_value()) {
+    if (auto secs =
+            ParseSecs("--reconnect_grace_secs", reconnect_grace_secs.value())) {
+      netsim::device::SetReconnectGraceSecs(secs.value());
+    }
+  }
+  netsim::device::SetReconnectSameIds(reconnect_same_ids);
+
   // Daemon mode -- start radio managers
   if (!fd_startup_str.empty() || grpc_startup) {
     netsim::hci::facade::Start();
@@ -21,6 +21,7 @@
 #include "hci/hci_packet_hub.h"
 #include "hci_packet.pb.h"
 #include "netsim-cxx/src/lib.rs.h"
+#include "uwb/uwb_packet_hub.h"
 #include "wifi/wifi_packet_hub.h"
 
 namespace netsim {
@@ -38,6 +39,8 @@ void HandleRequest(ChipKind kind, uint32_t facade_id,
     netsim::hci::handle_bt_request(facade_id, packet_type, shared_packet);
   } else if (kind == ChipKind::WIFI) {
     netsim::wifi::HandleWifiRequest(facade_id, shared_packet);
+  } else if (kind == ChipKind::UWB) {
+    netsim::uwb::HandleUwbRequest(facade_id, shared_packet);
   }
   netsim::pcap::HandleRequest(kind, facade_id, packet, packet_type);
 }
@@ -72,5 +75,22 @@ void HandleWifiResponse(uint32_t facade_id,
                                packet::HCIPacket::HCI_PACKET_UNSPECIFIED);
 }
 
+// forward from facade to transport via packet_hub
+void HandleUwbResponse(uint32_t facade_id,
+                       const std::shared_ptr<std::vector<uint8_t>> &packet) {
+  netsim::backend::HandleResponse(ChipKind::UWB, facade_id, *packet,
+                                  packet::HCIPacket::HCI_PACKET_UNSPECIFIED);
+  netsim::fd::HandleResponse(ChipKind::UWB, facade_id, *packet,
+                             packet::HCIPacket::HCI_PACKET_UNSPECIFIED);
+  netsim::pcap::HandleResponse(ChipKind::UWB, facade_id, *packet,
+                               packet::HCIPacket::HCI_PACKET_UNSPECIFIED);
+}
+
+void HandleUwbResponseCxx(uint32_t facade_id,
+                          rust::Slice<const uint8_t> packet) {
+  HandleUwbResponse(facade_id, std::make_shared<std::vector<uint8_t>>(
+                                   packet.begin(), packet.end()));
+}
+
 }  // namespace packet_hub
 }  // namespace netsim
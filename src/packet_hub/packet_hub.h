@@ -52,5 +52,11 @@ void HandleBtResponse(uint32_t facade_id,
 void HandleWifiResponse(uint32_t facade_id,
                         const std::shared_ptr<std::vector<uint8_t>> &packet);
 
+void HandleUwbResponse(uint32_t facade_id,
+                       const std::shared_ptr<std::vector<uint8_t>> &packet);
+
+void HandleUwbResponseCxx(uint32_t facade_id,
+                          rust::Slice<const uint8_t> packet);
+
 }  // namespace packet_hub
 }  // namespace netsim
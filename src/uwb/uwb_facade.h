@@ -34,6 +34,6 @@ void Reset(::std::uint32_t _facade_id) noexcept;
 
 void Remove(::std::uint32_t _facade_id) noexcept;
 
-::std::uint32_t Add(::std::uint32_t _chip_id) noexcept;
+::std::uint32_t Add(::std::uint32_t device_id) noexcept;
 
 }  // namespace netsim::uwb::facade
\ No newline at end of file
@@ -14,7 +14,13 @@
 
 #include "wifi/wifi_facade.h"
 
+#include <iterator>
+#include <vector>
+
+#include "netsim-cxx/src/lib.rs.h"
+#include "packet_hub/packet_hub.h"
 #include "util/log.h"
+#include "wifi/wifi_packet_hub.h"
 
 namespace netsim::wifi {
 namespace {
@@ -91,6 +97,39 @@ model::Chip::Radio Get(uint32_t id) {
   return radio;
 }
 
+rust::Vec<uint8_t> GetCxx(uint32_t id) {
+  auto radio = Get(id);
+  std::vector<uint8_t> message_vec(radio.ByteSizeLong());
+  radio.SerializeToArray(message_vec.data(), message_vec.size());
+  rust::Vec<uint8_t> proto_bytes;
+  proto_bytes.reserve(message_vec.size());
+  std::copy(message_vec.begin(), message_vec.end(),
+            std::back_inserter(proto_bytes));
+  return proto_bytes;
+}
+
+void PatchCxx(uint32_t id, rust::Slice<const uint8_t> proto_bytes) {
+  model::Chip::Radio radio;
+  if (!radio.ParseFromArray(proto_bytes.data(), proto_bytes.size())) {
+    BtsLog("wifi::facade::PatchCxx unable to parse the patch of %d", id);
+    return;
+  }
+  Patch(id, radio);
+}
+
+void TransmitCxx(uint32_t id, rust::Slice<const uint8_t> packet) {
+  auto it = id_to_chip_info_.find(id);
+  if (it == id_to_chip_info_.end()) {
+    BtsLog("Transmit from an unknown id %d", id);
+    return;
+  }
+  if (it->second->model->state() != model::State::ON) {
+    return;
+  }
+  HandleWifiRequest(id, std::make_shared<std::vector<uint8_t>>(packet.begin(),
+                                                               packet.end()));
+}
+
 uint32_t Add(uint32_t simulation_device) {
   BtsLog("wifi::facade::Add(%d)", simulation_device);
   static uint32_t global_chip_id = kGlobalChipStartIndex;
@@ -111,9 +150,26 @@ void Stop() { BtsLog("wifi::facade::Stop()"); }
 void HandleWifiRequest(uint32_t facade_id,
                        const std::shared_ptr<std::vector<uint8_t>> &packet) {
   BtsLog("netsim::wifi::HandleWifiRequest()");
+  auto sender = id_to_chip_info_.find(facade_id);
+  if (sender == id_to_chip_info_.end()) {
+    BtsLog("HandleWifiRequest from an unknown id %d", facade_id);
+    return;
+  }
   netsim::wifi::IncrTx(facade_id);
 
-  // TODO: Broadcast the packet to other emulators.
+  // Broadcast the packet to the other emulators. The device model drops
+  // the packets of invisible devices and of lossy links.
+  auto sender_device = sender->second->simulation_device;
+  for (const auto &[id, chip_info] : id_to_chip_info_) {
+    if (id == facade_id || chip_info->model->state() != model::State::ON ||
+        netsim::device::ShouldDropPacket(sender_device,
+                                         chip_info->simulation_device,
+                                         model::PhyKind::WIFI)) {
+      continue;
+    }
+    netsim::wifi::IncrRx(id);
+    packet_hub::HandleWifiResponse(id, packet);
+  }
   // TODO: Send the packet to the WiFi service.
 }
 }  // namespace netsim::wifi
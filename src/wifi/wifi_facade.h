@@ -19,6 +19,7 @@
 #include <string>
 
 #include "model.pb.h"
+#include "rust/cxx.h"
 
 /** Manages the WiFi chip emulation provided by the WiFi service library.
  *
@@ -34,6 +35,13 @@ void Patch(uint32_t, const model::Chip::Radio &);
 model::Chip::Radio Get(uint32_t);
 uint32_t Add(uint32_t simulation_device);
 
+// The chip as a serialized model::Chip::Radio, for the Rust device model.
+rust::Vec<uint8_t> GetCxx(uint32_t);
+void PatchCxx(uint32_t, rust::Slice<const uint8_t> proto_bytes);
+
+// Sends an 802.11 frame from the chip, for virtual devices.
+void TransmitCxx(uint32_t, rust::Slice<const uint8_t> packet);
+
 void Start();
 void Stop();
 
@@ -71,7 +71,7 @@ export interface Device {
   id: number;
   /** settable at creation */
   name: string;
-  visible: boolean;
+  visible: State;
   position: Position|undefined;
   orientation:|Orientation|undefined;
   /** Device can have multiple chips of the same kind. */